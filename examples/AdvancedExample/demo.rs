@@ -8,6 +8,7 @@ use rust_page_system::{
         page_system::PageData,
         renderer::RendererConfig,
         state::AppState,
+        widgets::WidgetValue,
         window::{WindowConfig, create_window, get_monitor_refresh_rate}
     }
 };
@@ -48,6 +49,11 @@ fn main()
     let mut window_modules = create_window(window_config);
     //bool is reffered to the rollback pages system, with "Mouse side buttons" or ("Alt" + "Arrows Keys") | (true = Page Rollback On), (false = Page Rollback Off)
     let mut input_handler = InputHandler::new(true);
+    // D-pad/stick move between buttons, A activates, B and the shoulders go through the page history
+    if let Err(err) = input_handler.enable_gamepads(&window_modules.sdl_init)
+    {
+        eprintln!("{}", err)
+    }
     let mut app_state = AppState::new(PageId::Page1, window_modules.canvas.window().size(), window_modules.stretch_mode_status);
     let mut page_data = PageData::new(&app_state);
 
@@ -56,6 +62,13 @@ fn main()
 
 
     populate_page_data(&mut page_data);
+    // Called with the new value every time a checkbox, toggle, radio or slider changes
+    input_handler.on_widget_change(|app_state, button_id, value, page_data| {
+        if *button_id == ButtonId::SliderVolumeSubPage
+        {
+            page_data.set_widget_value(app_state.current_page, ButtonId::ProgressVolumeSubPage, WidgetValue::Number(value.number() / 100.));
+        }
+    });
 
     // Wrap the button_action function in a mutable closure so it can capture
     // additional context if needed. Passing a closure here allows the
@@ -66,7 +79,10 @@ fn main()
     {
         //using (900 / your_refresh_rate) to a very crispy experience
         std::thread::sleep(Duration::from_millis(900 / get_monitor_refresh_rate()));
-        input_handler.handle_input(&mut window_modules.event_pump, &mut window_modules.clipboard_system, &mut page_data, &mut app_state, &mut button_action_closure);
+        if input_handler.handle_input(&mut window_modules.event_pump, &mut window_modules.clipboard_system, &mut page_data, &mut app_state, &mut button_action_closure)
+        {
+            break;
+        }
         app_state.update_window_size(renderer.canvas.window().size().0, renderer.canvas.window().size().1);
         page_data.create_current_page(&mut app_state);
         renderer.render(&page_data, &mut app_state, &input_handler);
@@ -1,10 +1,17 @@
 use crate::ui::style::{BACKGROUND_COLOR, BLACK_COLOR, ORANGE_COLOR, PINK_COLOR, PURPLE_COLOR, RED_COLOR, SUBTEXT_COLOR, TEXT_COLOR};
 use rust_page_system::{
     GetOrCreate, PersistentElements,
-    misc::center_elements::get_center,
+    misc::{
+        center_elements::get_center,
+        layout::{Justify, LayoutNode, Padding, Size}
+    },
     system::{
-        page_system::{Button, Page},
+        input_rules::InputRules,
+        page_system::{Button, ButtonStyle, Page, TextBox},
         scene_transition::{SlidePos, TransitionType},
+        scroll::ScrollRegion,
+        text_layout::VerticalAlign,
+        widgets::{Widget, WidgetKind},
         window::WINDOW_DEFAULT_SCALE
     }
 };
@@ -34,20 +41,29 @@ pub enum ButtonId
     ButtonRedInputStartPage1,
     ButtonPurpleInputStartPage2,
     ButtonSubPage,
-    ButtonBack
+    ButtonBack,
+    /// Not a button, keys the scroll offset of the sub page list
+    ScrollListSubPage,
+    CheckboxSoundSubPage,
+    ToggleFullscreenSubPage,
+    RadioEasySubPage,
+    RadioHardSubPage,
+    SliderVolumeSubPage,
+    ProgressVolumeSubPage
 }
 
 pub fn persistent_elements1() -> PersistentElements<PageId, ButtonId>
 {
-    //===================== variables =========================
-    let padding_x = 200;
-    let window_center = get_center((200, 75), WINDOW_DEFAULT_SCALE);
+    //===================== layout =========================
+    // Top bar: both page buttons centered in a row, 200px apart
+    let top_bar = LayoutNode { height: Size::Fixed(100), padding: Padding::symmetric(0, 10), gap: 200, justify: Justify::Center, ..LayoutNode::row(vec![LayoutNode::leaf(ButtonId::ButtonPage1, Size::Fixed(200), Size::Fixed(75)), LayoutNode::leaf(ButtonId::ButtonPage2, Size::Fixed(200), Size::Fixed(75))]) };
+    let layout = LayoutNode::column(vec![top_bar]).resolve(Rect::new(0, 0, WINDOW_DEFAULT_SCALE.0, WINDOW_DEFAULT_SCALE.1));
 
     //===================== rects =========================
     let all_rects = vec![(BLACK_COLOR, (Rect::new(0, 0, WINDOW_DEFAULT_SCALE.0, 100), 0))];
 
     //===================== buttons =========================
-    let all_buttons = vec![Button { enabled: true, color: PINK_COLOR, rect: Rect::new(window_center.pos_x - padding_x, 10, window_center.w, window_center.h), radius: 5, id: ButtonId::ButtonPage1, has_transition: Some(TransitionType::Slide(0., SlidePos::Right, 5000)) }, Button { enabled: true, color: PINK_COLOR, rect: Rect::new(window_center.pos_x + padding_x, 10, window_center.w, window_center.h), radius: 5, id: ButtonId::ButtonPage2, has_transition: Some(TransitionType::Slide(0., SlidePos::Left, 5000)) }];
+    let all_buttons = vec![Button { radius: 5, has_transition: Some(TransitionType::Slide(0., SlidePos::Right, 5000)), ..Button::new(ButtonId::ButtonPage1, PINK_COLOR, layout.rect(&ButtonId::ButtonPage1).unwrap()) }, Button { radius: 5, has_transition: Some(TransitionType::Slide(0., SlidePos::Left, 5000)), ..Button::new(ButtonId::ButtonPage2, PINK_COLOR, layout.rect(&ButtonId::ButtonPage2).unwrap()) }];
 
     //===================== texts =========================
     let all_text = vec![
@@ -63,7 +79,7 @@ pub fn persistent_elements1() -> PersistentElements<PageId, ButtonId>
     let all_images = vec![((10, 10), (50, 50), "image_example/example_1.bmp".to_string())];
 
     //===================== page creation =========================
-    PersistentElements { id: PageId::Persistent1, background_color: None, rects: Some(all_rects), buttons: Some(all_buttons), texts: Some(all_text), images: Some(all_images) }
+    PersistentElements { id: PageId::Persistent1, background_color: None, rects: Some(all_rects), buttons: Some(all_buttons), texts: Some(all_text), text_boxes: None, images: Some(all_images) }
 }
 
 pub fn persistent_elements2() -> PersistentElements<PageId, ButtonId>
@@ -76,7 +92,7 @@ pub fn persistent_elements2() -> PersistentElements<PageId, ButtonId>
     let all_text = vec![(17.0, (650, all_rects[0].1.0.y + 45), "This Rectangle Is A Persistent Elements, Just Like The Top Bar".to_string(), TEXT_COLOR)];
 
     //===================== page creation =========================
-    PersistentElements { id: PageId::Persistent2, background_color: None, rects: Some(all_rects), buttons: None, texts: Some(all_text), images: None }
+    PersistentElements { id: PageId::Persistent2, background_color: None, rects: Some(all_rects), buttons: None, texts: Some(all_text), text_boxes: None, images: None }
 }
 
 pub fn page_1(user_input: &mut Vec<String>) -> Page<PageId, ButtonId>
@@ -91,10 +107,14 @@ pub fn page_1(user_input: &mut Vec<String>) -> Page<PageId, ButtonId>
     let all_rects = vec![(RED_COLOR, (Rect::new(red_rect_data.pos_x, red_rect_data.pos_y + (orange_rect_data.h as i32 + padding_y), red_rect_data.w, red_rect_data.h), 100)), (ORANGE_COLOR, (Rect::new(orange_rect_data.pos_x, orange_rect_data.pos_y, orange_rect_data.w, orange_rect_data.h), 0))];
 
     //===================== buttons =========================
-    let all_buttons = vec![Button { enabled: true, color: PURPLE_COLOR, rect: Rect::new(purple_button_data.pos_x, purple_button_data.pos_y - (orange_rect_data.h as i32 - padding_y), purple_button_data.w, purple_button_data.h), radius: 5, id: ButtonId::ButtonPurpleInputStartPage1, has_transition: None }, Button { enabled: true, color: RED_COLOR, rect: Rect::new(purple_button_data.pos_x, all_rects[0].1.0.y + all_rects[0].1.0.h + padding_y, purple_button_data.w, purple_button_data.h), radius: 20, id: ButtonId::ButtonRedInputStartPage1, has_transition: None }];
+    let all_buttons = vec![Button { radius: 5, ..Button::new(ButtonId::ButtonPurpleInputStartPage1, PURPLE_COLOR, Rect::new(purple_button_data.pos_x, purple_button_data.pos_y - (orange_rect_data.h as i32 - padding_y), purple_button_data.w, purple_button_data.h)) }, Button { radius: 20, ..Button::new(ButtonId::ButtonRedInputStartPage1, RED_COLOR, Rect::new(purple_button_data.pos_x, all_rects[0].1.0.y + all_rects[0].1.0.h + padding_y, purple_button_data.w, purple_button_data.h)) }];
 
     //===================== texts =========================
-    let all_text = vec![(18.0, (all_rects[1].1.0.x + 165, all_rects[1].1.0.y + 86), "Random Orange Rectangle, Because I Can :)".to_string(), SUBTEXT_COLOR), (18.0, (all_buttons[0].rect.x + 75, all_buttons[0].rect.y - 25), "Click the Button To Start Getting Input".to_string(), SUBTEXT_COLOR), (25.0, (all_buttons[0].rect.x + 15, all_buttons[0].rect.y + 35), user_input.get_or_create(0), BLACK_COLOR), (25.0, (all_buttons[1].rect.x + 15, all_buttons[1].rect.y + 35), user_input.get_or_create(1), BLACK_COLOR)];
+    let all_text = vec![(18.0, (all_rects[1].1.0.x + 165, all_rects[1].1.0.y + 86), "Random Orange Rectangle, Because I Can :)".to_string(), SUBTEXT_COLOR), (18.0, (all_buttons[0].rect.x + 75, all_buttons[0].rect.y - 25), "Click the Button To Start Getting Input".to_string(), SUBTEXT_COLOR)];
+
+    //===================== text boxes =========================
+    // Inputs are drawn with text boxes linked to their button, the purple one scrolls sideways and the red one wraps, both clipped to the button
+    let all_text_boxes = vec![TextBox { vertical_align: VerticalAlign::Middle, ..TextBox::new(&user_input.get_or_create(0), 25.0, BLACK_COLOR, Rect::new(all_buttons[0].rect.x + 15, all_buttons[0].rect.y, all_buttons[0].rect.width() - 30, all_buttons[0].rect.height())).with_clip_rect(all_buttons[0].rect).with_input(ButtonId::ButtonPurpleInputStartPage1) }, TextBox::new(&user_input.get_or_create(1), 25.0, BLACK_COLOR, Rect::new(all_buttons[1].rect.x + 15, all_buttons[1].rect.y + 5, all_buttons[1].rect.width() - 30, all_buttons[1].rect.height() - 10)).bounded().with_input(ButtonId::ButtonRedInputStartPage1)];
 
     //===================== page creation =========================
     Page {
@@ -102,12 +122,14 @@ pub fn page_1(user_input: &mut Vec<String>) -> Page<PageId, ButtonId>
         // Wrap persistent element constructors in reference-counted closures. This allows
         // additional state to be captured if desired and supports cloning.
         has_persistent_elements: Some(vec![(PageId::Persistent1, Rc::new(persistent_elements1))]),
-        id: PageId::Page1,
+        // The red input is a multi-line text area, Return inserts a new line instead of submitting
+        has_text_area: Some(vec![(PageId::Page1, ButtonId::ButtonRedInputStartPage1)]),
         background_color: Some(BACKGROUND_COLOR),
         rects: Some(all_rects),
         buttons: Some(all_buttons),
         texts: Some(all_text),
-        images: None
+        text_boxes: Some(all_text_boxes),
+        ..Page::new(PageId::Page1)
     }
 }
 
@@ -117,22 +139,42 @@ pub fn page_2(user_input: &mut Vec<String>) -> Page<PageId, ButtonId>
     let get_input_button_data = get_center((500, 100), WINDOW_DEFAULT_SCALE);
 
     //===================== buttons =========================
-    let all_buttons = vec![Button { enabled: true, color: PURPLE_COLOR, rect: Rect::new(100, 150, 235, 40), radius: 20, id: ButtonId::ButtonSubPage, has_transition: Some(TransitionType::Fade(0.)) }, Button { enabled: true, color: PURPLE_COLOR, rect: Rect::new(get_input_button_data.pos_x, get_input_button_data.pos_y, get_input_button_data.w as u32, get_input_button_data.h as u32), radius: 20, id: ButtonId::ButtonPurpleInputStartPage2, has_transition: None }];
+    let all_buttons = vec![Button { radius: 20, has_transition: Some(TransitionType::Fade(0.)), ..Button::new(ButtonId::ButtonSubPage, PURPLE_COLOR, Rect::new(100, 150, 235, 40)) }, Button { radius: 20, ..Button::new(ButtonId::ButtonPurpleInputStartPage2, PURPLE_COLOR, Rect::new(get_input_button_data.pos_x, get_input_button_data.pos_y, get_input_button_data.w as u32, get_input_button_data.h as u32)) }];
 
     //===================== texts =========================
-    let all_text = vec![(18.0, (all_buttons[0].rect.x + 10, all_buttons[0].rect.y + 7), "Go To subpage_page2".to_string(), TEXT_COLOR), (18.0, (all_buttons[1].rect.x + 10, all_buttons[1].rect.y + 7), user_input.get_or_create(2), TEXT_COLOR)];
+    let all_text = vec![(18.0, (all_buttons[0].rect.x + 10, all_buttons[0].rect.y + 7), "Go To subpage_page2".to_string(), TEXT_COLOR)];
+
+    //===================== text boxes =========================
+    // Linked to the input so it gets the caret and the placeholder
+    let all_text_boxes = vec![TextBox::new(&user_input.get_or_create(2), 18.0, TEXT_COLOR, Rect::new(all_buttons[1].rect.x + 10, all_buttons[1].rect.y + 7, all_buttons[1].rect.width() - 20, all_buttons[1].rect.height() - 14)).bounded().with_input(ButtonId::ButtonPurpleInputStartPage2)];
 
     //===================== page creation =========================
-    Page { has_userinput: Some(vec![(PageId::Page2, ButtonId::ButtonPurpleInputStartPage2)]), has_persistent_elements: Some(vec![(PageId::Persistent1, Rc::new(persistent_elements1)), (PageId::Persistent2, Rc::new(persistent_elements2))]), id: PageId::Page2, background_color: Some(BACKGROUND_COLOR), rects: None, buttons: Some(all_buttons), texts: Some(all_text), images: None }
+    // The purple input is a password field, the page gets bullets while vec_user_input keeps what was typed, "Password" is shown while it is empty
+    Page { has_userinput: Some(vec![(PageId::Page2, ButtonId::ButtonPurpleInputStartPage2)]), has_persistent_elements: Some(vec![(PageId::Persistent1, Rc::new(persistent_elements1)), (PageId::Persistent2, Rc::new(persistent_elements2))]), has_input_rules: Some(vec![(PageId::Page2, ButtonId::ButtonPurpleInputStartPage2, InputRules { max_length: Some(16), placeholder: Some("Password".to_string()), ..InputRules::password() })]), background_color: Some(BACKGROUND_COLOR), buttons: Some(all_buttons), texts: Some(all_text), text_boxes: Some(all_text_boxes), ..Page::new(PageId::Page2) }
 }
 
 pub fn subpage_page2() -> Page<PageId, ButtonId>
 {
     //===================== buttons =========================
-    let all_buttons = vec![Button { enabled: true, color: PINK_COLOR, rect: Rect::new(20, 20, 50, 40), radius: 0, id: ButtonId::ButtonBack, has_transition: Some(TransitionType::Fade(0.)) }];
+    // The back button gets a white border and turns orange while it is held down
+    let back_button_style = ButtonStyle { pressed_color: Some(ORANGE_COLOR), border: Some((TEXT_COLOR, 2)), ..ButtonStyle::default() };
+    let mut all_buttons = vec![Button { has_transition: Some(TransitionType::Fade(0.)), style: Some(back_button_style), tooltip: Some("Back to the previous page".into()), ..Button::new(ButtonId::ButtonBack, PINK_COLOR, Rect::new(20, 20, 50, 40)) }];
+    // Widget buttons are only the box or track, the check mark, knob and fill are drawn over them
+    let widget_buttons = [(ButtonId::CheckboxSoundSubPage, Rect::new(100, 200, 30, 30), 5), (ButtonId::ToggleFullscreenSubPage, Rect::new(100, 250, 60, 30), 15), (ButtonId::RadioEasySubPage, Rect::new(100, 300, 30, 30), 15), (ButtonId::RadioHardSubPage, Rect::new(250, 300, 30, 30), 15), (ButtonId::SliderVolumeSubPage, Rect::new(100, 350, 300, 20), 10), (ButtonId::ProgressVolumeSubPage, Rect::new(100, 400, 300, 20), 10)];
+    all_buttons.extend(widget_buttons.map(|(id, rect, radius)| Button { radius, ..Button::new(id, BLACK_COLOR, rect) }));
+
+    //===================== widgets =========================
+    // The progress bar follows the volume slider, see the on_widget_change callback in demo.rs
+    let all_widgets = vec![(PageId::Page2SubPage, ButtonId::CheckboxSoundSubPage, Widget { kind: WidgetKind::Checkbox, accent_color: PINK_COLOR }), (PageId::Page2SubPage, ButtonId::ToggleFullscreenSubPage, Widget { kind: WidgetKind::Toggle, accent_color: PINK_COLOR }), (PageId::Page2SubPage, ButtonId::RadioEasySubPage, Widget { kind: WidgetKind::Radio(0), accent_color: PURPLE_COLOR }), (PageId::Page2SubPage, ButtonId::RadioHardSubPage, Widget { kind: WidgetKind::Radio(0), accent_color: PURPLE_COLOR }), (PageId::Page2SubPage, ButtonId::SliderVolumeSubPage, Widget { kind: WidgetKind::Slider { min: 0., max: 100., step: 5. }, accent_color: ORANGE_COLOR }), (PageId::Page2SubPage, ButtonId::ProgressVolumeSubPage, Widget { kind: WidgetKind::ProgressBar, accent_color: ORANGE_COLOR })];
+
+    //===================== scroll regions =========================
+    // 30 lines in a 300px tall list, scrolled with the mouse wheel, Up/Down/PageUp/PageDown/Home/End or the scrollbar
+    let list_region = ScrollRegion { id: ButtonId::ScrollListSubPage, rect: Rect::new(1300, 300, 400, 300), content_height: 30 * 40, scrollbar_color: PURPLE_COLOR, kinetic: true };
 
     //===================== texts =========================
-    let all_text = vec![(18.0, (950, 400), "Random Text, Because I Can :)".to_string(), SUBTEXT_COLOR), (18.0, (all_buttons[0].rect.x + 10, all_buttons[0].rect.y + 7), "<-".to_string(), TEXT_COLOR)];
+    let mut all_text = vec![(18.0, (950, 400), "Random Text, Because I Can :)".to_string(), SUBTEXT_COLOR), (18.0, (all_buttons[0].rect.x + 10, all_buttons[0].rect.y + 7), "<-".to_string(), TEXT_COLOR)];
+    all_text.extend([(18.0, (150, 204), "Sound".to_string(), TEXT_COLOR), (18.0, (180, 254), "Fullscreen".to_string(), TEXT_COLOR), (18.0, (150, 304), "Easy".to_string(), TEXT_COLOR), (18.0, (300, 304), "Hard".to_string(), TEXT_COLOR), (18.0, (420, 347), "Volume".to_string(), TEXT_COLOR)]);
+    all_text.extend((1..=30).map(|line| (18.0, (list_region.rect.x() + 10, list_region.rect.y() + 40 * (line - 1) + 10), format!("Scrolled Line {}", line), TEXT_COLOR)));
 
     //===================== images =========================
     //this image is local and is not embedded, so if the path provided doesn't have any image, the
@@ -140,5 +182,5 @@ pub fn subpage_page2() -> Page<PageId, ButtonId>
     let all_images = vec![((500, 500), (300, 300), "gif_example/cinnamoroll.gif".to_string())];
 
     //===================== page creation =========================
-    Page { has_userinput: None, has_persistent_elements: Some(vec![(PageId::Persistent2, Rc::new(persistent_elements2))]), id: PageId::Page2SubPage, background_color: Some(BACKGROUND_COLOR), rects: None, buttons: Some(all_buttons), texts: Some(all_text), images: Some(all_images) }
+    Page { has_persistent_elements: Some(vec![(PageId::Persistent2, Rc::new(persistent_elements2))]), has_scroll_regions: Some(vec![list_region]), has_widgets: Some(all_widgets), background_color: Some(BACKGROUND_COLOR), buttons: Some(all_buttons), texts: Some(all_text), images: Some(all_images), ..Page::new(PageId::Page2SubPage) }
 }
@@ -3,9 +3,10 @@ use rust_page_system::{
     misc::{center_elements::get_center, vec::GetOrCreate},
     system::{
         input_handler::InputHandler,
-        page_system::{Page, PageData, PersistentElements},
+        page_system::{Page, PageData, PersistentElements, TextBox},
         renderer::RendererConfig,
         state::AppState,
+        text_layout::{HorizontalAlign, VerticalAlign},
         window::{WindowConfig, create_window, get_monitor_refresh_rate}
     }
 };
@@ -56,7 +57,10 @@ fn main()
         //using 900 / your_refresh_rate to a very crispy experience
         std::thread::sleep(Duration::from_millis(900 / get_monitor_refresh_rate()));
         app_state.update_window_size(renderer.canvas.window().size().0, renderer.canvas.window().size().1);
-        input_handler.handle_input(&mut window_modules.event_pump, &mut window_modules.clipboard_system, &mut page_data, &mut app_state, &mut button_action_closure);
+        if input_handler.handle_input(&mut window_modules.event_pump, &mut window_modules.clipboard_system, &mut page_data, &mut app_state, &mut button_action_closure)
+        {
+            break;
+        }
         page_data.create_current_page(&mut app_state);
         renderer.render(&page_data, &mut app_state, &input_handler);
     }
@@ -147,7 +151,7 @@ pub fn persistent_elements(_string: String) -> PersistentElements<PageId, Button
     let all_images = vec![((10, 10), (50, 50), format!("{}/.cache/page_system/example_1.jpg", env::home_dir().unwrap().display()))];
 
     //===================== page creation =========================
-    PersistentElements { id: PageId::Persistent, background_color: None, rects: Some(all_rects), buttons: None, texts: Some(all_text), images: Some(all_images) }
+    PersistentElements { id: PageId::Persistent, background_color: None, rects: Some(all_rects), buttons: None, texts: Some(all_text), text_boxes: None, images: Some(all_images) }
 }
 
 pub fn page_1(user_input: &mut Vec<String>, _int: i32) -> Page<PageId, ButtonId>
@@ -159,23 +163,26 @@ pub fn page_1(user_input: &mut Vec<String>, _int: i32) -> Page<PageId, ButtonId>
     let subpage_button_data = get_center((235, 40), (1920, 1080));
 
     //===================== buttons =========================
-    let all_buttons = vec![Button { enabled: true, color: PURPLE_COLOR, rect: Rect::new(subpage_button_data.pos_x, 150, subpage_button_data.w, subpage_button_data.h), radius: 20, id: ButtonId::ButtonSubPage, has_transition: None }, Button { enabled: true, color: PURPLE_COLOR, rect: Rect::new(purple_button_data.pos_x, purple_button_data.pos_y, purple_button_data.w, purple_button_data.h), radius: 5, id: ButtonId::ButtonPurpleInputStartPage1, has_transition: None }];
+    let all_buttons = vec![Button { radius: 20, ..Button::new(ButtonId::ButtonSubPage, PURPLE_COLOR, Rect::new(subpage_button_data.pos_x, 150, subpage_button_data.w, subpage_button_data.h)) }, Button { radius: 5, ..Button::new(ButtonId::ButtonPurpleInputStartPage1, PURPLE_COLOR, Rect::new(purple_button_data.pos_x, purple_button_data.pos_y, purple_button_data.w, purple_button_data.h)) }];
 
     //===================== texts =========================
-    let all_text = vec![(18.0, (all_buttons[0].rect.x + 10, all_buttons[0].rect.y + 7), "Go To subpage_page1".to_string(), TEXT_COLOR), (18.0, (all_buttons[1].rect.x + 75, all_buttons[1].rect.y - 25), "Click the Button To Start Getting Input".to_string(), SUBTEXT_COLOR), (25.0, (all_buttons[1].rect.x + 15, all_buttons[1].rect.y + 35), user_input.get_or_create(0), BLACK_COLOR)];
+    let all_text = vec![(18.0, (all_buttons[1].rect.x + 75, all_buttons[1].rect.y - 25), "Click the Button To Start Getting Input".to_string(), SUBTEXT_COLOR)];
+
+    //===================== text boxes =========================
+    let all_text_boxes = vec![TextBox { horizontal_align: HorizontalAlign::Center, vertical_align: VerticalAlign::Middle, ..TextBox::new("Go To subpage_page1", 18.0, TEXT_COLOR, all_buttons[0].rect) }, TextBox { vertical_align: VerticalAlign::Middle, ..TextBox::new(&user_input.get_or_create(0), 25.0, BLACK_COLOR, Rect::new(all_buttons[1].rect.x + 15, all_buttons[1].rect.y, all_buttons[1].rect.width() - 30, all_buttons[1].rect.height())).with_clip_rect(all_buttons[1].rect).with_input(ButtonId::ButtonPurpleInputStartPage1) }];
 
     //===================== page creation =========================
-    Page { has_userinput: Some(vec![(PageId::Page1, ButtonId::ButtonPurpleInputStartPage1)]), has_persistent_elements: Some(vec![(PageId::Persistent, Rc::new(|| persistent_elements("a".to_string())))]), id: PageId::Page1, background_color: Some(BACKGROUND_COLOR), rects: None, buttons: Some(all_buttons), texts: Some(all_text), images: None }
+    Page { has_userinput: Some(vec![(PageId::Page1, ButtonId::ButtonPurpleInputStartPage1)]), has_persistent_elements: Some(vec![(PageId::Persistent, Rc::new(|| persistent_elements("a".to_string())))]), background_color: Some(BACKGROUND_COLOR), buttons: Some(all_buttons), texts: Some(all_text), text_boxes: Some(all_text_boxes), ..Page::new(PageId::Page1) }
 }
 
 pub fn subpage_page1() -> Page<PageId, ButtonId>
 {
     //===================== buttons =========================
-    let all_buttons = vec![Button { enabled: true, color: PINK_COLOR, rect: Rect::new(200, 0, 50, 150), radius: 0, id: ButtonId::ButtonBack, has_transition: None }];
+    let all_buttons = vec![Button::new(ButtonId::ButtonBack, PINK_COLOR, Rect::new(200, 0, 50, 150))];
 
-    //===================== texts =========================
-    let all_text = vec![(18.0, (all_buttons[0].rect.x + 10, all_buttons[0].rect.y + 7), "<-".to_string(), TEXT_COLOR)];
+    //===================== text boxes =========================
+    let all_text_boxes = vec![TextBox { horizontal_align: HorizontalAlign::Center, vertical_align: VerticalAlign::Middle, ..TextBox::new("<-", 18.0, TEXT_COLOR, all_buttons[0].rect) }];
 
     //===================== page creation =========================
-    Page { has_userinput: None, has_persistent_elements: None, id: PageId::Page1SubPage, background_color: Some(BACKGROUND_COLOR), rects: None, buttons: Some(all_buttons), texts: Some(all_text), images: None }
+    Page { background_color: Some(BACKGROUND_COLOR), buttons: Some(all_buttons), text_boxes: Some(all_text_boxes), ..Page::new(PageId::Page1SubPage) }
 }
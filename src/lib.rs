@@ -1,11 +1,15 @@
 pub use crate::{
     misc::{center_elements::get_center, list_embedded::list_embedded, vec::GetOrCreate},
     system::{
-        input_handler::{InputEvent, InputHandler},
-        page_system::{Button, Page, PersistentElements},
+        input_handler::{ButtonEvent, ButtonEventKind, InputEvent, InputHandler},
+        input_source::{InputSource, MemoryClipboard, ScriptedInput, ScriptedStep},
+        page_system::{Button, ButtonStyle, Page, PersistentElements, TextBox},
         renderer::Renderer,
+        scroll::ScrollRegion,
+        snapshot::RgbaFrame,
         state::AppState,
-        window::{WINDOW_DEFAULT_SCALE, create_window, get_monitor_refresh_rate}
+        widgets::{Widget, WidgetKind, WidgetValue},
+        window::{WINDOW_DEFAULT_SCALE, create_headless_window, create_window, get_monitor_refresh_rate}
     }
 };
 
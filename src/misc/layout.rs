@@ -0,0 +1,212 @@
+use sdl3::rect::Rect;
+
+/// Size Of A Layout Node Along One Axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Size
+{
+    /// Exact size in pixels (1920x1080 space)
+    Fixed(u32),
+    /// Percentage (0.0 to 100.0) of the parent content area
+    Percent(f32),
+    /// Share of the space left by the Fixed and Percent siblings, proportional to the weight.
+    /// On the cross axis it stretches to the whole parent content area.
+    Fill(u32)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction
+{
+    Row,
+    Column
+}
+
+/// Where The Children Are Placed Along The Main Axis When They Don't Fill It
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Justify
+{
+    Start,
+    Center,
+    End,
+    /// First child at the start, last child at the end, the free space split between them
+    SpaceBetween
+}
+
+/// Where Each Child Is Placed Along The Cross Axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align
+{
+    Start,
+    Center,
+    End
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Padding
+{
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+    pub left: i32
+}
+
+impl Padding
+{
+    pub fn all(padding: i32) -> Self
+    {
+        Self { top: padding, right: padding, bottom: padding, left: padding }
+    }
+
+    pub fn symmetric(horizontal: i32, vertical: i32) -> Self
+    {
+        Self { top: vertical, right: horizontal, bottom: vertical, left: horizontal }
+    }
+}
+
+/// A Box In The Layout Tree. Leaves Usually Carry A Key (A ButtonId, Or Any Id Of Yours)
+/// So Their Resolved Rect Can Be Looked Up, Containers Stack Their Children In A Row Or Column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutNode<Key>
+{
+    pub key: Option<Key>,
+    pub width: Size,
+    pub height: Size,
+    pub direction: Direction,
+    pub padding: Padding,
+    pub gap: i32,
+    pub justify: Justify,
+    pub align: Align,
+    pub children: Vec<LayoutNode<Key>>
+}
+
+impl<Key: Clone + PartialEq> LayoutNode<Key>
+{
+    /// Container That Places Its Children Left To Right, Filling The Parent By Default
+    pub fn row(children: Vec<LayoutNode<Key>>) -> Self
+    {
+        Self { key: None, width: Size::Fill(1), height: Size::Fill(1), direction: Direction::Row, padding: Padding::default(), gap: 0, justify: Justify::Start, align: Align::Start, children }
+    }
+
+    /// Container That Places Its Children Top To Bottom, Filling The Parent By Default
+    pub fn column(children: Vec<LayoutNode<Key>>) -> Self
+    {
+        Self { direction: Direction::Column, ..Self::row(children) }
+    }
+
+    /// Node Without Children Whose Rect Is Looked Up With `key`
+    pub fn leaf(key: Key, width: Size, height: Size) -> Self
+    {
+        Self { key: Some(key), width, height, ..Self::row(Vec::new()) }
+    }
+
+    /// Empty Node Used To Push Siblings Apart
+    pub fn spacer(width: Size, height: Size) -> Self
+    {
+        Self { width, height, ..Self::row(Vec::new()) }
+    }
+
+    /// Resolve The Whole Tree Inside `area` (Usually The Full WINDOW_DEFAULT_SCALE Rect)
+    pub fn resolve(&self, area: Rect) -> ResolvedLayout<Key>
+    {
+        let mut resolved_layout = ResolvedLayout { rects: Vec::new() };
+        self.resolve_into(area, &mut resolved_layout);
+        resolved_layout
+    }
+
+    fn resolve_into(&self, area: Rect, resolved_layout: &mut ResolvedLayout<Key>)
+    {
+        if let Some(key) = &self.key
+        {
+            resolved_layout.rects.push((key.clone(), area));
+        }
+        if self.children.is_empty()
+        {
+            return;
+        }
+
+        let content_x = area.x() + self.padding.left;
+        let content_y = area.y() + self.padding.top;
+        let content_w = (area.width() as i32 - self.padding.left - self.padding.right).max(0);
+        let content_h = (area.height() as i32 - self.padding.top - self.padding.bottom).max(0);
+        let (main_start, cross_start, main_length, cross_length) = match self.direction
+        {
+            Direction::Row => (content_x, content_y, content_w, content_h),
+            Direction::Column => (content_y, content_x, content_h, content_w)
+        };
+        let main_size = |child: &LayoutNode<Key>| if self.direction == Direction::Row { child.width } else { child.height };
+        let cross_size = |child: &LayoutNode<Key>| if self.direction == Direction::Row { child.height } else { child.width };
+
+        // Fixed and Percent children first, Fill children share what is left
+        let total_gap = self.gap * (self.children.len() as i32 - 1);
+        let mut main_lengths: Vec<i32> = self.children.iter().map(|child| fixed_length(main_size(child), main_length).unwrap_or(0)).collect();
+        let fill_weights: Vec<u32> = self.children.iter().map(|child| if let Size::Fill(weight) = main_size(child) { weight } else { 0 }).collect();
+        let total_weight: u32 = fill_weights.iter().sum();
+        let free_space = (main_length - total_gap - main_lengths.iter().sum::<i32>()).max(0);
+        if total_weight > 0
+        {
+            let mut remaining_space = free_space;
+            let last_fill_index = fill_weights.iter().rposition(|weight| *weight > 0).unwrap_or(0);
+            for (index, weight) in fill_weights.iter().enumerate().filter(|(_, weight)| **weight > 0)
+            {
+                // The last Fill child takes the rounding leftovers so the row ends exactly at the edge
+                let length = if index == last_fill_index { remaining_space } else { (free_space as i64 * *weight as i64 / total_weight as i64) as i32 };
+                main_lengths[index] = length;
+                remaining_space -= length;
+            }
+        }
+
+        let leftover_space = if total_weight > 0 { 0 } else { free_space };
+        let (mut main_position, extra_gap) = match self.justify
+        {
+            Justify::Start => (main_start, 0),
+            Justify::Center => (main_start + leftover_space / 2, 0),
+            Justify::End => (main_start + leftover_space, 0),
+            Justify::SpaceBetween if self.children.len() > 1 => (main_start, leftover_space / (self.children.len() as i32 - 1)),
+            Justify::SpaceBetween => (main_start, 0)
+        };
+
+        for (child, child_main_length) in self.children.iter().zip(main_lengths)
+        {
+            let child_cross_length = fixed_length(cross_size(child), cross_length).unwrap_or(cross_length);
+            let cross_position = match self.align
+            {
+                Align::Start => cross_start,
+                Align::Center => cross_start + (cross_length - child_cross_length) / 2,
+                Align::End => cross_start + cross_length - child_cross_length
+            };
+            let child_area = match self.direction
+            {
+                Direction::Row => Rect::new(main_position, cross_position, child_main_length.max(0) as u32, child_cross_length.max(0) as u32),
+                Direction::Column => Rect::new(cross_position, main_position, child_cross_length.max(0) as u32, child_main_length.max(0) as u32)
+            };
+            child.resolve_into(child_area, resolved_layout);
+            main_position += child_main_length + self.gap + extra_gap;
+        }
+    }
+}
+
+/// Pixel Length Of A Fixed Or Percent Size, `None` For Fill
+fn fixed_length(size: Size, parent_length: i32) -> Option<i32>
+{
+    match size
+    {
+        Size::Fixed(pixels) => Some(pixels as i32),
+        Size::Percent(percent) => Some((parent_length as f32 * percent / 100.).round() as i32),
+        Size::Fill(_) => None
+    }
+}
+
+/// Rects Of Every Keyed Node, In Tree Order
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedLayout<Key>
+{
+    pub rects: Vec<(Key, Rect)>
+}
+
+impl<Key: PartialEq> ResolvedLayout<Key>
+{
+    /// Rect Of The First Node With This Key
+    pub fn rect(&self, key: &Key) -> Option<Rect>
+    {
+        self.rects.iter().find(|(node_key, _)| node_key == key).map(|(_, rect)| *rect)
+    }
+}
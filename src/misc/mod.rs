@@ -1,3 +1,4 @@
 pub mod center_elements;
+pub mod layout;
 pub mod list_embedded;
 pub mod vec;
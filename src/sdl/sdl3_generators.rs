@@ -1,3 +1,7 @@
+use crate::system::{
+    page_system::TextBox,
+    text_layout::{FontMeasurer, layout_text_box}
+};
 use gif::{ColorOutput, DecodeOptions};
 use gif_dispose::Screen;
 use include_dir::Dir;
@@ -202,6 +206,32 @@ impl GenerateText for (&mut Vec<(f64, (i32, i32), String, Color)>, &TextureCreat
     }
 }
 
+pub trait GenerateTextBox
+{
+    fn generate_text_box(&mut self, font_path: &str) -> Vec<(Texture<'_>, Rect)>;
+}
+impl<ButtonId> GenerateTextBox for (&mut Vec<TextBox<ButtonId>>, &TextureCreator<WindowContext>, &Sdl3TtfContext)
+{
+    ///Helper Function That Generates The Page TextBoxes, One Texture Per Non Empty Line
+    fn generate_text_box(&mut self, font_path: &str) -> Vec<(Texture<'_>, Rect)>
+    {
+        let measurer = FontMeasurer::new(self.2, font_path);
+        let mut vector_to_send = Vec::new();
+        for text_box in &*self.0
+        {
+            let font = self.2.load_font(font_path, text_box.font_size as f32).expect("Failed to load font");
+            for line in layout_text_box(&measurer, text_box).into_iter().filter(|line| !line.text.is_empty())
+            {
+                let surface = font.render(&line.text).blended(text_box.color).expect("Failed to blend font");
+                let surface_argb = surface.convert_format(sdl3::pixels::PixelFormat::ARGB8888).expect("Failed to convert surface to ARGB8888");
+                let texture = self.1.create_texture_from_surface(&surface_argb).expect("Failed to create ARGB font texture");
+                vector_to_send.push((texture, Rect::new(line.rect.x(), line.rect.y(), surface.width(), surface.height())));
+            }
+        }
+        vector_to_send
+    }
+}
+
 
 // === Embed your assets folder ===
 pub trait GenerateImage
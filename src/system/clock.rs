@@ -0,0 +1,66 @@
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::{Duration, Instant}
+};
+
+/// Where The InputHandler Reads The Time (Long Presses, Tooltip Delays, Kinetic Scrolling, Session Timestamps),
+/// Set With `InputHandler::set_clock`
+pub trait Clock
+{
+    fn now(&self) -> Instant;
+}
+
+/// The Real Time, Used By Default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock
+{
+    fn now(&self) -> Instant
+    {
+        Instant::now()
+    }
+}
+
+/// A Clock That Only Moves When Told To, For Tests And Replays. Clones share the same time,
+/// so one can be given to the InputHandler and another kept to move it
+#[derive(Debug, Clone)]
+pub struct ManualClock
+{
+    now: Rc<Cell<Instant>>
+}
+
+impl Default for ManualClock
+{
+    fn default() -> Self
+    {
+        Self::new(Instant::now())
+    }
+}
+
+impl ManualClock
+{
+    pub fn new(now: Instant) -> Self
+    {
+        Self { now: Rc::new(Cell::new(now)) }
+    }
+
+    pub fn advance(&self, duration: Duration)
+    {
+        self.now.set(self.now.get() + duration)
+    }
+
+    pub fn set(&self, now: Instant)
+    {
+        self.now.set(now)
+    }
+}
+
+impl Clock for ManualClock
+{
+    fn now(&self) -> Instant
+    {
+        self.now.get()
+    }
+}
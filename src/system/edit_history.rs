@@ -0,0 +1,126 @@
+use std::collections::VecDeque;
+
+/// Content, Caret And Selection Of One Input, Restored By Undo And Redo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditSnapshot
+{
+    pub text: String,
+    pub cursor_position: usize,
+    pub text_selection_range: Option<(usize, usize)>
+}
+
+/// What An Edit Did, Consecutive Typing Or Deleting Edits Are Merged Into A Single Undo Step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditKind
+{
+    Typing,
+    Deleting,
+    /// Paste, cut, word deletion, new line... always a step of its own
+    Other
+}
+
+struct FieldHistory<PageId, ButtonId>
+{
+    page_id: PageId,
+    button_id: ButtonId,
+    undo_stack: VecDeque<EditSnapshot>,
+    redo_stack: Vec<EditSnapshot>,
+    current_run: Option<EditKind>
+}
+
+impl<PageId, ButtonId> FieldHistory<PageId, ButtonId>
+{
+    /// Push An Undo Step, Dropping The Oldest Ones Past `max_depth`
+    fn push_undo(&mut self, snapshot: EditSnapshot, max_depth: usize)
+    {
+        self.undo_stack.push_back(snapshot);
+        while self.undo_stack.len() > max_depth
+        {
+            self.undo_stack.pop_front();
+        }
+    }
+}
+
+/// Undo/Redo Stacks Kept Separately For Every (PageId, ButtonId) Input
+pub struct EditHistory<PageId, ButtonId>
+{
+    /// Undo steps kept per input, the oldest ones are dropped past it
+    pub max_depth: usize,
+    fields: Vec<FieldHistory<PageId, ButtonId>>
+}
+
+impl<PageId: Copy + Eq, ButtonId: Copy + Eq> EditHistory<PageId, ButtonId>
+{
+    pub fn new(max_depth: usize) -> Self
+    {
+        Self { max_depth, fields: Vec::new() }
+    }
+
+    /// Save The State Of An Input From Right Before An Edit That Changed It, Unless The Edit Continues The Current Typing Or Deleting Run
+    pub fn record(&mut self, page_id: PageId, button_id: ButtonId, before_edit: EditSnapshot, edit_kind: EditKind)
+    {
+        let max_depth = self.max_depth;
+        let field = self.field(page_id, button_id);
+        field.redo_stack.clear();
+        if (edit_kind != EditKind::Other && field.current_run == Some(edit_kind)) || field.undo_stack.back() == Some(&before_edit)
+        {
+            return;
+        }
+        field.current_run = Some(edit_kind);
+        field.push_undo(before_edit, max_depth);
+    }
+
+    /// Close The Typing Or Deleting Runs, The Next Edit Starts A New Undo Step (Called When The Caret Moves Or The Focus Changes)
+    pub fn end_run(&mut self)
+    {
+        for field in &mut self.fields
+        {
+            field.current_run = None;
+        }
+    }
+
+    /// Step Back, `current` Goes To The Redo Stack And The Previous State Is Returned
+    pub fn undo(&mut self, page_id: PageId, button_id: ButtonId, current: EditSnapshot) -> Option<EditSnapshot>
+    {
+        let field = self.field(page_id, button_id);
+        field.current_run = None;
+        let previous = field.undo_stack.pop_back()?;
+        field.redo_stack.push(current);
+        Some(previous)
+    }
+
+    /// Step Forward Again, `current` Goes Back To The Undo Stack And The Undone State Is Returned
+    pub fn redo(&mut self, page_id: PageId, button_id: ButtonId, current: EditSnapshot) -> Option<EditSnapshot>
+    {
+        let max_depth = self.max_depth;
+        let field = self.field(page_id, button_id);
+        field.current_run = None;
+        let next = field.redo_stack.pop()?;
+        field.push_undo(current, max_depth);
+        Some(next)
+    }
+
+    pub fn can_undo(&self, page_id: PageId, button_id: ButtonId) -> bool
+    {
+        self.fields.iter().any(|field| field.page_id == page_id && field.button_id == button_id && !field.undo_stack.is_empty())
+    }
+
+    pub fn can_redo(&self, page_id: PageId, button_id: ButtonId) -> bool
+    {
+        self.fields.iter().any(|field| field.page_id == page_id && field.button_id == button_id && !field.redo_stack.is_empty())
+    }
+
+    fn field(&mut self, page_id: PageId, button_id: ButtonId) -> &mut FieldHistory<PageId, ButtonId>
+    {
+        let field_index = match self.fields.iter().position(|field| field.page_id == page_id && field.button_id == button_id)
+        {
+            Some(field_index) => field_index,
+            None =>
+            {
+                self.fields.push(FieldHistory { page_id, button_id, undo_stack: VecDeque::new(), redo_stack: Vec::new(), current_run: None });
+                self.fields.len() - 1
+            }
+        };
+        &mut self.fields[field_index]
+    }
+}
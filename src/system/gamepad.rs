@@ -0,0 +1,136 @@
+use crate::system::input_handler::InputEvent;
+use sdl3::{
+    GamepadSubsystem, Sdl,
+    event::Event,
+    gamepad::{Axis, Button, Gamepad},
+    joystick::JoystickId
+};
+
+/// How Far (Out Of 32767) The Left Stick Must Be Pushed To Move The Focus
+pub const STICK_THRESHOLD: i16 = 16000;
+
+/// Turns Gamepad Events Into Navigation InputEvents:
+/// D-pad and left stick move the focus, South (A/Cross) activates, East (B/Circle) and the left
+/// shoulder go Back, the right shoulder goes Front. Back/Front follow `enable_rollback_pages` like the mouse side buttons.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GamepadNavigation
+{
+    /// Where the left stick is pushed on each axis (-1, 0 or 1), the focus moves once each time it gets pushed
+    stick_direction: (i8, i8)
+}
+
+impl GamepadNavigation
+{
+    pub fn translate(&mut self, event: &Event, enable_rollback_pages: bool) -> Option<InputEvent>
+    {
+        match event
+        {
+            Event::ControllerButtonDown { button, .. } => match button
+            {
+                Button::DPadUp => Some(InputEvent::NavigateUp),
+                Button::DPadDown => Some(InputEvent::NavigateDown),
+                Button::DPadLeft => Some(InputEvent::NavigateLeft),
+                Button::DPadRight => Some(InputEvent::NavigateRight),
+                Button::South => Some(InputEvent::Activate),
+                Button::East | Button::LeftShoulder => enable_rollback_pages.then_some(InputEvent::Back),
+                Button::RightShoulder => enable_rollback_pages.then_some(InputEvent::Front),
+                _ => None
+            },
+            Event::ControllerAxisMotion { axis: axis @ (Axis::LeftX | Axis::LeftY), value, .. } =>
+            {
+                let direction = if *value > STICK_THRESHOLD
+                {
+                    1
+                }
+                else if *value < -STICK_THRESHOLD
+                {
+                    -1
+                }
+                else
+                {
+                    0
+                };
+                let previous_direction = if *axis == Axis::LeftX { &mut self.stick_direction.0 } else { &mut self.stick_direction.1 };
+                if direction == *previous_direction
+                {
+                    return None;
+                }
+                *previous_direction = direction;
+                // SDL's Y axis points down
+                match (axis, direction)
+                {
+                    (Axis::LeftX, 1) => Some(InputEvent::NavigateRight),
+                    (Axis::LeftX, -1) => Some(InputEvent::NavigateLeft),
+                    (Axis::LeftY, 1) => Some(InputEvent::NavigateDown),
+                    (Axis::LeftY, -1) => Some(InputEvent::NavigateUp),
+                    _ => None
+                }
+            }
+            _ => None
+        }
+    }
+}
+
+/// Every Connected Gamepad, Opened So SDL Sends Their Events. Virtual gamepads
+/// (SDL_AttachVirtualJoystick) are opened like real ones, so menus can be driven without hardware
+pub struct Gamepads
+{
+    gamepad_system: GamepadSubsystem,
+    opened: Vec<(JoystickId, Gamepad)>,
+    pub navigation: GamepadNavigation
+}
+
+impl Gamepads
+{
+    /// Start SDL's Gamepad Subsystem And Open The Gamepads Already Connected
+    pub fn new(sdl: &Sdl) -> Result<Self, String>
+    {
+        let gamepad_system = sdl.gamepad().map_err(|err| format!("Failed to start the gamepad subsystem: {}", err))?;
+        let connected = gamepad_system.gamepads().map_err(|err| format!("Failed to list the gamepads: {}", err))?;
+        let mut gamepads = Self { gamepad_system, opened: Vec::new(), navigation: GamepadNavigation::default() };
+        for joystick_id in connected
+        {
+            gamepads.open(joystick_id);
+        }
+        Ok(gamepads)
+    }
+
+    /// Number Of Gamepads Currently Opened
+    pub fn connected(&self) -> usize
+    {
+        self.opened.len()
+    }
+
+    /// Open Or Close Gamepads As They Are Plugged In And Out, Then Translate The Event
+    pub fn handle_event(&mut self, event: &Event, enable_rollback_pages: bool) -> Option<InputEvent>
+    {
+        match event
+        {
+            Event::ControllerDeviceAdded { which, .. } =>
+            {
+                self.open(*which);
+                None
+            }
+            Event::ControllerDeviceRemoved { which, .. } =>
+            {
+                self.opened.retain(|(joystick_id, _)| *joystick_id != *which);
+                None
+            }
+            _ => self.navigation.translate(event, enable_rollback_pages)
+        }
+    }
+
+    fn open(&mut self, joystick_id: JoystickId)
+    {
+        // SDL also reports the gamepads found at startup as added
+        if self.opened.iter().any(|(opened_id, _)| *opened_id == joystick_id)
+        {
+            return;
+        }
+        match self.gamepad_system.open(joystick_id)
+        {
+            Ok(gamepad) => self.opened.push((joystick_id, gamepad)),
+            Err(err) => eprintln!("Failed to open gamepad {:?}: {}", joystick_id, err)
+        }
+    }
+}
@@ -1,19 +1,51 @@
-use crate::{AppState, system::page_system::PageData};
+#[cfg(feature = "serde")]
+use crate::system::{
+    clock::ManualClock,
+    session_recorder::{RecordedStep, SessionRecorder}
+};
+use crate::{
+    AppState,
+    system::{
+        clock::{Clock, SystemClock},
+        edit_history::{EditHistory, EditKind, EditSnapshot},
+        gamepad::Gamepads,
+        input_source::{ClipboardProvider, InputSource, ScriptedInput},
+        keymap::{KeyCombo, Keymap, KeymapMatch},
+        page_system::PageData,
+        scroll::{SCROLL_STEP, ScrollRegion},
+        text_edit::{line_end, line_start, move_by_lines, next_grapheme, next_word_end, previous_grapheme, previous_word_start, snap_to_grapheme, word_bounds},
+        text_layout::{CaretStop, caret_stop_at},
+        widgets::{WidgetKind, WidgetValue}
+    }
+};
 use sdl3::{
-    EventPump,
-    clipboard::ClipboardUtil,
+    EventPump, Sdl,
     event::Event,
-    keyboard::{Keycode, Mod},
-    mouse::MouseButton
+    mouse::{MouseButton, MouseWheelDirection}
+};
+#[cfg(feature = "serde")]
+use std::path::Path;
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    rc::Rc,
+    time::{Duration, Instant}
 };
-use std::{fmt::Debug, process::exit};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputEvent
 {
     Click,
+    DoubleClick,
+    TripleClick,
+    Drag,
+    MouseUp,
     Text(String),
     Backspace,
+    Delete,
+    DeleteWordBackward,
+    DeleteWordForward,
     Submit,
     Front,
     Back,
@@ -21,30 +53,235 @@ pub enum InputEvent
     Copy,
     Cut,
     Undo,
+    Redo,
     SelectAll,
     DeleteAll,
     CursorLeft(bool),
     CursorRight(bool),
+    WordLeft(bool),
+    WordRight(bool),
+    CursorUp(bool),
+    CursorDown(bool),
+    Home(bool),
+    End(bool),
+    PageUp(bool),
+    PageDown(bool),
     ExitCapturingInput,
+    FocusNext,
+    FocusPrevious,
+    Activate,
+    NavigateUp,
+    NavigateDown,
+    NavigateLeft,
+    NavigateRight,
+    RightClick,
+    /// Mouse wheel notches, positive when scrolled up (away from the user)
+    Wheel(f32),
     Quit,
     None
 }
 
+/// What Happened To A Button, Besides The button_action Fired When It Is Clicked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonEventKind
+{
+    HoverEnter,
+    HoverLeave,
+    /// The left mouse button went down on it
+    Press,
+    /// The left mouse button was released on the button it went down on
+    Click,
+    /// The left mouse button was released after being dragged off the button
+    PressCancelled,
+    RightClick,
+    DoubleClick,
+    /// The left mouse button stayed down on it for `long_press_duration`
+    LongPress
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ButtonEvent<ButtonId>
+{
+    pub button_id: ButtonId,
+    pub kind: ButtonEventKind,
+    pub mouse_position: (f32, f32)
+}
+
+/// Button Events Kept When The App Never Takes Them, The Oldest Are Dropped First
+const MAX_PENDING_BUTTON_EVENTS: usize = 256;
+
+#[cfg(feature = "serde")]
+type ActiveSessionRecorder = SessionRecorder;
+// Sessions can't be recorded without the serde feature
+#[cfg(not(feature = "serde"))]
+type ActiveSessionRecorder = std::convert::Infallible;
+
+type WidgetCallback<PageId, ButtonId> = Box<dyn Fn(&mut AppState<PageId, ButtonId>, &ButtonId, WidgetValue, &mut PageData<PageId, ButtonId>)>;
+
 pub struct InputHandler<PageId, ButtonId>
 {
     pub button_selected: Option<ButtonId>,
+    /// Button under the mouse, unlike button_selected it doesn't follow the input being typed in
+    pub hovered_button: Option<ButtonId>,
+    /// Button the left mouse button went down on, until it is released
+    pub button_pressed: Option<ButtonId>,
+    /// Button reached with Tab/Shift+Tab (or the last one clicked), Enter or Space activates it
+    pub focused_button: Option<ButtonId>,
     pub mouse_position: (f32, f32),
+    /// Byte index into the active input, always moved between grapheme clusters (so 'é', '日' or '👍🏽' are one step)
     pub cursor_position: usize,
+    /// (anchor, cursor) byte indexes of the selection, on grapheme cluster boundaries like cursor_position
     pub text_selection_range: Option<(usize, usize)>,
+    /// Lines the caret moves with PageUp/PageDown in text areas
+    pub page_lines: usize,
+    /// Per input undo/redo steps, its max_depth can be changed at any time
+    pub edit_history: EditHistory<PageId, ButtonId>,
+    /// Shortcuts read by poll, `Keymap::default()` has the usual desktop ones
+    pub keymap: Keymap<PageId>,
+    /// How long the left mouse button has to stay down on a button for a LongPress event
+    pub long_press_duration: Duration,
     enable_rollback_pages: bool,
-    input_history_stack: Vec<Vec<(PageId, ButtonId, String)>>
+    selecting_with_mouse: bool,
+    session_recorder: Option<ActiveSessionRecorder>,
+    // Opened in poll as they get plugged in, which only borrows the InputHandler
+    gamepads: Option<RefCell<Gamepads>>,
+    // Keys of a chord typed so far
+    pending_keys: RefCell<Vec<KeyCombo>>,
+    // Page the keymap resolves page bindings for, poll doesn't get the AppState
+    current_page: Option<PageId>,
+    button_events: Vec<ButtonEvent<ButtonId>>,
+    // When the left mouse button went down on button_pressed, cleared once the LongPress is sent
+    pressed_since: Option<Instant>,
+    // When the mouse entered hovered_button (or released it), for the tooltip delay
+    hovered_since: Option<Instant>,
+    clock: Rc<dyn Clock>,
+    // When handle_input last stepped kinetic scrolling
+    last_kinetic_step: Option<Instant>,
+    // Scroll region whose scrollbar is being dragged, and where the thumb was grabbed (from its top)
+    scrollbar_drag: Option<(ScrollRegion<ButtonId>, i32)>,
+    // Slider following the mouse until the left mouse button is released
+    slider_drag: Option<ButtonId>,
+    widget_callback: Option<WidgetCallback<PageId, ButtonId>>,
+    // Where the caret can be placed in the active input, saved by the Renderer every frame so clicks can move the caret
+    input_caret_stops: RefCell<Option<(ButtonId, Vec<CaretStop>)>>,
+    // An Activate just started capturing an input, the space typed by the same Space key press must not end up in it
+    skip_space_text: bool
+}
+
+impl<PageId, ButtonId: Copy> InputHandler<PageId, ButtonId>
+{
+    /// Where The Caret Can Be Placed In The Active Input, Saved By The Renderer Every Frame (None When It Isn't Drawn)
+    pub fn set_input_caret_stops(&self, input_caret_stops: Option<(ButtonId, Vec<CaretStop>)>)
+    {
+        *self.input_caret_stops.borrow_mut() = input_caret_stops
+    }
+
+    /// Hovered Button Whose Tooltip Is Due: The Mouse Rested On It For `tooltip_delay` (On The InputHandler Clock)
+    /// And It Isn't Pressed, The Wait Starts Again Once It Is Released
+    pub fn tooltip_button(&self, tooltip_delay: Duration) -> Option<ButtonId>
+    {
+        let hovered_since = self.hovered_since?;
+        self.hovered_button.filter(|_| self.button_pressed.is_none() && self.clock.now().saturating_duration_since(hovered_since) >= tooltip_delay)
+    }
 }
 
 impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId, ButtonId>
 {
     pub fn new(enable_rollback_pages: bool) -> Self
     {
-        Self { cursor_position: 0, text_selection_range: None, enable_rollback_pages, mouse_position: (0., 0.), button_selected: None, input_history_stack: Vec::new() }
+        Self { cursor_position: 0, text_selection_range: None, page_lines: 10, enable_rollback_pages, selecting_with_mouse: false, mouse_position: (0., 0.), button_selected: None, focused_button: None, hovered_button: None, button_pressed: None, edit_history: EditHistory::new(100), session_recorder: None, gamepads: None, keymap: Keymap::default(), pending_keys: RefCell::new(Vec::new()), current_page: None, long_press_duration: Duration::from_millis(500), button_events: Vec::new(), pressed_since: None, hovered_since: None, clock: Rc::new(SystemClock), last_kinetic_step: None, scrollbar_drag: None, slider_drag: None, widget_callback: None, input_caret_stops: RefCell::new(None), skip_space_text: false }
+    }
+
+    /// Run `widget_callback` With The New Value Every Time A Widget Is Changed By The Mouse, The Keyboard Or change_widget
+    pub fn on_widget_change(&mut self, widget_callback: impl Fn(&mut AppState<PageId, ButtonId>, &ButtonId, WidgetValue, &mut PageData<PageId, ButtonId>) + 'static)
+    {
+        self.widget_callback = Some(Box::new(widget_callback))
+    }
+
+    /// Read The Time From `clock` Instead Of The System Clock, A `ManualClock` Makes Long Presses Testable
+    pub fn set_clock(&mut self, clock: impl Clock + 'static)
+    {
+        self.clock = Rc::new(clock);
+        self.last_kinetic_step = None
+    }
+
+    /// Start Writing Every Handled InputEvent (With The Mouse Position And Current Page) To A JSON Lines File,
+    /// Mouse Moves Without An Event Included
+    #[cfg(feature = "serde")]
+    pub fn start_recording<P: AsRef<Path>>(&mut self, path: P) -> Result<(), String>
+    {
+        self.session_recorder = Some(SessionRecorder::create(path, self.clock.now())?);
+        Ok(())
+    }
+
+    /// Flush And Close The Session File, If Recording
+    #[cfg(feature = "serde")]
+    pub fn stop_recording(&mut self)
+    {
+        if let Some(mut session_recorder) = self.session_recorder.take()
+            && let Err(err) = session_recorder.flush()
+        {
+            eprintln!("{}", err)
+        }
+    }
+
+    pub fn is_recording(&self) -> bool
+    {
+        self.session_recorder.is_some()
+    }
+
+    /// Take The Button Events (Hover, Press, Click, Long Press...) Gathered Since The Last Call, Oldest First
+    pub fn take_button_events(&mut self) -> Vec<ButtonEvent<ButtonId>>
+    {
+        std::mem::take(&mut self.button_events)
+    }
+
+    fn push_button_event(&mut self, button_id: ButtonId, kind: ButtonEventKind)
+    {
+        if self.button_events.len() >= MAX_PENDING_BUTTON_EVENTS
+        {
+            self.button_events.remove(0);
+        }
+        self.button_events.push(ButtonEvent { button_id, kind, mouse_position: self.mouse_position })
+    }
+
+    /// Send HoverEnter/HoverLeave When The Button Under The Mouse Changes
+    fn update_hovered_button(&mut self, hovered_button: Option<ButtonId>)
+    {
+        if hovered_button == self.hovered_button
+        {
+            return;
+        }
+        if let Some(left_button_id) = self.hovered_button
+        {
+            self.push_button_event(left_button_id, ButtonEventKind::HoverLeave)
+        }
+        if let Some(entered_button_id) = hovered_button
+        {
+            self.push_button_event(entered_button_id, ButtonEventKind::HoverEnter)
+        }
+        self.hovered_button = hovered_button;
+        self.hovered_since = hovered_button.map(|_| self.clock.now())
+    }
+
+    /// Send LongPress Once The Pressed Button Was Held Long Enough Without The Mouse Leaving It
+    fn check_long_press(&mut self)
+    {
+        if let Some(pressed_since) = self.pressed_since
+            && let Some(button_id) = self.button_pressed
+            && self.hovered_button == Some(button_id)
+            && self.clock.now().saturating_duration_since(pressed_since) >= self.long_press_duration
+        {
+            self.pressed_since = None;
+            self.push_button_event(button_id, ButtonEventKind::LongPress)
+        }
+    }
+
+    /// Open The Connected Gamepads (And The Ones Plugged In Later) So poll Turns Their D-pad, Stick And Buttons Into Focus Navigation
+    pub fn enable_gamepads(&mut self, sdl: &Sdl) -> Result<(), String>
+    {
+        self.gamepads = Some(RefCell::new(Gamepads::new(sdl)?));
+        Ok(())
     }
 
     pub fn poll(&self, event_pump: &mut EventPump) -> InputEvent
@@ -54,74 +291,54 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
             match event
             {
                 //mouse events
-                Event::MouseButtonDown { mouse_btn: MouseButton::Left, .. } => return InputEvent::Click,
-                Event::MouseButtonDown { mouse_btn: MouseButton::X1, .. } =>
-                {
-                    if self.enable_rollback_pages
-                    {
-                        return InputEvent::Back;
-                    }
-                }
-                Event::MouseButtonDown { mouse_btn: MouseButton::X2, .. } =>
-                {
-                    if self.enable_rollback_pages
-                    {
-                        return InputEvent::Front;
-                    }
-                }
-
-                //keyboard events
-                Event::TextInput { text, .. } => return InputEvent::Text(text),
-                Event::KeyDown { keycode: Some(Keycode::Return), .. } => return InputEvent::Submit,
-                Event::KeyDown { keycode: Some(Keycode::Backspace), keymod, .. } =>
+                Event::MouseButtonDown { mouse_btn: MouseButton::Left, clicks, .. } =>
                 {
-                    if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD)
+                    return match clicks
                     {
-                        return InputEvent::DeleteAll;
-                    }
-                    else
-                    {
-                        return InputEvent::Backspace;
-                    }
+                        0 | 1 => InputEvent::Click,
+                        2 => InputEvent::DoubleClick,
+                        _ => InputEvent::TripleClick
+                    };
                 }
-                Event::KeyDown { keycode: Some(Keycode::Z), keymod, .. } =>
+                Event::MouseButtonUp { mouse_btn: MouseButton::Left, .. } => return InputEvent::MouseUp,
+                Event::MouseWheel { y, direction, .. } =>
                 {
-                    if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD)
-                    {
-                        return InputEvent::Undo;
-                    }
+                    return InputEvent::Wheel(if direction == MouseWheelDirection::Flipped { -y } else { y });
                 }
-                Event::KeyDown { keycode: Some(Keycode::C), keymod, .. } =>
+                Event::MouseMotion { mousestate, .. } if mousestate.left() => return InputEvent::Drag,
+                Event::MouseButtonDown { mouse_btn, .. } =>
                 {
-                    if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD)
+                    if let Some(input_event) = self.keymap.resolve_mouse(self.current_page, mouse_btn)
+                        && self.is_allowed(&input_event)
                     {
-                        return InputEvent::Copy;
+                        return input_event;
                     }
-                }
-                Event::KeyDown { keycode: Some(Keycode::X), keymod, .. } =>
-                {
-                    if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD)
+                    if mouse_btn == MouseButton::Right
                     {
-                        return InputEvent::Cut;
+                        return InputEvent::RightClick;
                     }
                 }
-                Event::KeyDown { keycode: Some(Keycode::V), keymod, .. } =>
+
+                //keyboard events
+                Event::TextInput { text, .. } => return InputEvent::Text(text),
+                // The TextInput event of a space follows its KeyDown: Activate is ignored while typing, and the space of the Space key press that starts capturing an input is dropped by process_event
+                Event::KeyDown { keycode: Some(keycode), keymod, .. } =>
                 {
-                    if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD)
+                    if let Some(input_event) = self.key_event(KeyCombo::from_event(keycode, keymod))
                     {
-                        return InputEvent::Paste;
+                        return input_event;
                     }
                 }
-                Event::KeyDown { keycode: Some(Keycode::A), keymod, .. } =>
+
+                //gamepad events
+                gamepad_event @ (Event::ControllerButtonDown { .. } | Event::ControllerAxisMotion { .. } | Event::ControllerDeviceAdded { .. } | Event::ControllerDeviceRemoved { .. }) =>
                 {
-                    if keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD)
+                    if let Some(gamepads) = &self.gamepads
+                        && let Some(input_event) = gamepads.borrow_mut().handle_event(&gamepad_event, self.enable_rollback_pages)
                     {
-                        return InputEvent::SelectAll;
+                        return input_event;
                     }
                 }
-                Event::KeyDown { keycode: Some(Keycode::Left), keymod, .. } => return InputEvent::CursorLeft(keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD)),
-                Event::KeyDown { keycode: Some(Keycode::Right), keymod, .. } => return InputEvent::CursorRight(keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD)),
-                Event::KeyDown { keycode: Some(Keycode::Escape), .. } => return InputEvent::ExitCapturingInput,
 
                 //window events
                 Event::Quit { .. } => return InputEvent::Quit,
@@ -132,16 +349,157 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
         InputEvent::None
     }
 
+    /// Feed A Key To The Keymap, Returning The Event Once A Shortcut Or The Last Key Of A Chord Is Pressed
+    fn key_event(&self, key_combo: KeyCombo) -> Option<InputEvent>
+    {
+        if key_combo.is_modifier()
+        {
+            return None;
+        }
+        let mut pending_keys = self.pending_keys.borrow_mut();
+        pending_keys.push(key_combo);
+        let mut keymap_match = self.keymap.resolve_keys(self.current_page, &pending_keys);
+        // A key that breaks a chord still counts on its own
+        if keymap_match == KeymapMatch::NoMatch && pending_keys.len() > 1
+        {
+            pending_keys.clear();
+            pending_keys.push(key_combo);
+            keymap_match = self.keymap.resolve_keys(self.current_page, &pending_keys);
+        }
+        match keymap_match
+        {
+            KeymapMatch::Pending => None,
+            KeymapMatch::Event(input_event) =>
+            {
+                pending_keys.clear();
+                (input_event != InputEvent::None && self.is_allowed(&input_event)).then_some(input_event)
+            }
+            KeymapMatch::NoMatch =>
+            {
+                pending_keys.clear();
+                None
+            }
+        }
+    }
+
+    /// Page History Shortcuts Only Work With enable_rollback_pages
+    fn is_allowed(&self, input_event: &InputEvent) -> bool
+    {
+        self.enable_rollback_pages || !matches!(input_event, InputEvent::Back | InputEvent::Front)
+    }
+
+    /// Read The Mouse Position And The Next Event From The Input Source (EventPump Or ScriptedInput) And Process Them.
+    /// Returns true once Quit is handled, the main loop should then stop
     #[allow(clippy::type_complexity)]
-    pub fn handle_input(&mut self, event_pump: &mut EventPump, clipboard_util: &mut ClipboardUtil, page_data: &mut PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, button_action: &mut dyn Fn(&mut AppState<PageId, ButtonId>, &ButtonId, &mut PageData<PageId, ButtonId>))
+    pub fn handle_input(&mut self, input_source: &mut dyn InputSource<PageId, ButtonId>, clipboard: &mut dyn ClipboardProvider, page_data: &mut PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, button_action: &mut dyn Fn(&mut AppState<PageId, ButtonId>, &ButtonId, &mut PageData<PageId, ButtonId>)) -> bool
     {
-        self.button_selected = page_data.page_button_at(app_state, event_pump.mouse_state().x(), event_pump.mouse_state().y());
-        if let Some(active_button_id) = app_state.capturing_input.1
+        self.mouse_position = input_source.mouse_position();
+        self.current_page = Some(app_state.current_page);
+        let now = self.clock.now();
+        page_data.step_kinetic_scroll(self.last_kinetic_step.map(|last_kinetic_step| now.saturating_duration_since(last_kinetic_step)).unwrap_or_default());
+        self.last_kinetic_step = Some(now);
+        let hovered_button = page_data.page_button_at(app_state, self.mouse_position.0, self.mouse_position.1);
+        self.update_hovered_button(hovered_button);
+        self.check_long_press();
+        self.button_selected = app_state.capturing_input.1.or(hovered_button);
+
+        let input_event = input_source.next_event(self);
+        #[cfg(feature = "serde")]
+        if let Some(session_recorder) = &mut self.session_recorder
+            && let Err(err) = session_recorder.record(&app_state.current_page, self.clock.now(), self.mouse_position, &input_event)
+        {
+            eprintln!("{}, recording stopped", err);
+            self.session_recorder = None
+        }
+        self.process_event(input_event, clipboard, page_data, app_state, button_action)
+    }
+
+    /// Play Every Step Of A Script, Building The Current Page Before Each One Like The Main Loop Does.
+    /// Stops early and returns true when the script quits
+    #[allow(clippy::type_complexity)]
+    pub fn play_script(&mut self, script: &mut ScriptedInput, clipboard: &mut dyn ClipboardProvider, page_data: &mut PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, button_action: &mut dyn Fn(&mut AppState<PageId, ButtonId>, &ButtonId, &mut PageData<PageId, ButtonId>)) -> bool
+    {
+        let mut quit_requested = false;
+        while !script.is_finished() && !quit_requested
         {
-            self.button_selected = Some(active_button_id)
+            page_data.create_current_page(app_state);
+            quit_requested = self.handle_input(script, clipboard, page_data, app_state, button_action);
         }
+        page_data.create_current_page(app_state);
+        quit_requested
+    }
+
+    /// Replay A Recorded Session Step By Step, Returning How Many Steps Were Applied.
+    /// The clock follows the recorded timestamps so long presses happen again, it is restored afterwards.
+    /// Stops at the recorded Quit (without exiting) and fails as soon as the active page
+    /// differs from the one recorded, which means the session is no longer reproducible.
+    #[cfg(feature = "serde")]
+    #[allow(clippy::type_complexity)]
+    pub fn replay_session(&mut self, recorded_steps: &[RecordedStep], clipboard: &mut dyn ClipboardProvider, page_data: &mut PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, button_action: &mut dyn Fn(&mut AppState<PageId, ButtonId>, &ButtonId, &mut PageData<PageId, ButtonId>)) -> Result<usize, String>
+    {
+        let replay_clock = ManualClock::default();
+        let previous_clock = std::mem::replace(&mut self.clock, Rc::new(replay_clock.clone()));
+        self.last_kinetic_step = None;
+        let replayed_steps = self.replay_steps(recorded_steps, &replay_clock, clipboard, page_data, app_state, button_action);
+        self.clock = previous_clock;
+        self.last_kinetic_step = None;
+        replayed_steps
+    }
+
+    #[cfg(feature = "serde")]
+    #[allow(clippy::type_complexity)]
+    fn replay_steps(&mut self, recorded_steps: &[RecordedStep], replay_clock: &ManualClock, clipboard: &mut dyn ClipboardProvider, page_data: &mut PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, button_action: &mut dyn Fn(&mut AppState<PageId, ButtonId>, &ButtonId, &mut PageData<PageId, ButtonId>)) -> Result<usize, String>
+    {
+        let started_at = replay_clock.now();
+        for (step_index, recorded_step) in recorded_steps.iter().enumerate()
+        {
+            if recorded_step.event == InputEvent::Quit
+            {
+                return Ok(step_index);
+            }
+            page_data.create_current_page(app_state);
+            let current_page = format!("{:?}", app_state.current_page);
+            if current_page != recorded_step.page
+            {
+                return Err(format!("Replay diverged at step {} ({:?}): expected page '{}', found '{}'", step_index + 1, recorded_step.event, recorded_step.page, current_page));
+            }
+            replay_clock.set(started_at + Duration::from_millis(recorded_step.timestamp_ms));
+            self.handle_input(&mut ScriptedInput::new(vec![recorded_step.to_scripted_step()]), clipboard, page_data, app_state, button_action);
+        }
+        page_data.create_current_page(app_state);
+        Ok(recorded_steps.len())
+    }
+
+    /// Apply An Already Translated InputEvent To The Page Data And App State.
+    /// Returns true for a Quit (the recording is flushed first), leaving the exit to the caller
+    #[allow(clippy::type_complexity)]
+    pub fn process_event(&mut self, input_event: InputEvent, clipboard: &mut dyn ClipboardProvider, page_data: &mut PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, button_action: &mut dyn Fn(&mut AppState<PageId, ButtonId>, &ButtonId, &mut PageData<PageId, ButtonId>)) -> bool
+    {
+        let quit_requested = input_event == InputEvent::Quit && !app_state.all_events_disable;
+        self.apply_event(input_event, clipboard, page_data, app_state, button_action);
+        quit_requested
+    }
 
-        match self.poll(event_pump)
+    #[allow(clippy::type_complexity)]
+    fn apply_event(&mut self, input_event: InputEvent, clipboard: &mut dyn ClipboardProvider, page_data: &mut PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, button_action: &mut dyn Fn(&mut AppState<PageId, ButtonId>, &ButtonId, &mut PageData<PageId, ButtonId>))
+    {
+        // SDL sends the TextInput of a Space right after its KeyDown, so it is the very next event
+        if std::mem::take(&mut self.skip_space_text) && input_event == InputEvent::Text(" ".to_string())
+        {
+            return;
+        }
+        // Only consecutive typing or deleting are merged into one undo step, anything else in between (caret moves, clicks...) splits them
+        if !matches!(input_event, InputEvent::Text(_) | InputEvent::Backspace | InputEvent::Delete | InputEvent::None)
+        {
+            self.edit_history.end_run()
+        }
+        if input_event == InputEvent::DoubleClick
+            && !app_state.all_events_disable
+            && let Some(button_id) = self.hovered_button
+        {
+            self.push_button_event(button_id, ButtonEventKind::DoubleClick)
+        }
+        match input_event
         {
             InputEvent::Click =>
             {
@@ -149,29 +507,163 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
                 {
                     return;
                 };
-                if let Some(button_id) = self.button_selected
+                // Scrollbars sit above the buttons of their region
+                if let Some(scroll_region) = page_data.scrollbar_at(app_state, self.mouse_position.0, self.mouse_position.1)
+                {
+                    self.grab_scrollbar(scroll_region, app_state, page_data);
+                    return;
+                }
+                self.focused_button = self.button_selected;
+                self.button_pressed = self.hovered_button;
+                self.pressed_since = self.hovered_button.map(|_| self.clock.now());
+                if let Some(button_id) = self.hovered_button
+                {
+                    self.push_button_event(button_id, ButtonEventKind::Press)
+                }
+                // Widgets change their value instead of running the button_action, sliders follow the mouse from the press and the others change on release
+                if let Some(button_id) = self.hovered_button
+                    && let Some(widget) = page_data.widget_of(app_state.current_page, button_id)
                 {
-                    (*button_action)(app_state, &button_id, page_data);
-                    if app_state.capturing_input.0
+                    if matches!(widget.kind, WidgetKind::Slider { .. })
                     {
-                        self.cursor_position = self.get_current_input_length(app_state, page_data, button_id);
-                        self.text_selection_range = None
+                        self.slider_drag = Some(button_id);
+                        self.drag_slider(app_state, page_data)
                     }
+                    self.focused_button = Some(button_id);
+                    app_state.capturing_input = (false, None);
+                    self.text_selection_range = None;
+                    return;
+                }
+                // The button_action runs on release, but the caret of the captured input follows the press so dragging selects from there
+                if let Some(button_id) = app_state.capturing_input.1.filter(|_| app_state.capturing_input.0)
+                {
+                    self.cursor_position = self.caret_index_at_mouse(app_state, page_data).unwrap_or_else(|| self.get_current_input_length(app_state, page_data, button_id));
+                    self.text_selection_range = None;
+                    self.selecting_with_mouse = true
                 }
-                else
+                else if self.button_selected.is_none()
                 {
                     app_state.capturing_input = (false, None);
                     self.text_selection_range = None
                 }
             }
+            // Words and lines are only selected in the captured input under the mouse, elsewhere it is a plain click
+            InputEvent::DoubleClick | InputEvent::TripleClick if !app_state.capturing_input.0 || self.hovered_button != app_state.capturing_input.1 => self.apply_event(InputEvent::Click, clipboard, page_data, app_state, button_action),
+            InputEvent::DoubleClick =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.select_word_at_mouse(app_state, page_data)
+            }
+            InputEvent::TripleClick =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.selecting_with_mouse = false;
+                self.select_all(app_state, page_data)
+            }
+            InputEvent::Drag =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                if self.scrollbar_drag.is_some()
+                {
+                    self.drag_scrollbar(app_state, page_data);
+                    return;
+                }
+                if self.slider_drag.is_some()
+                {
+                    self.drag_slider(app_state, page_data);
+                    return;
+                }
+                self.drag_selection(app_state, page_data)
+            }
+            InputEvent::MouseUp =>
+            {
+                self.selecting_with_mouse = false;
+                self.scrollbar_drag = None;
+                self.slider_drag = None;
+                self.pressed_since = None;
+                if let Some(button_id) = self.button_pressed.take()
+                {
+                    self.hovered_since = self.hovered_button.map(|_| self.clock.now());
+                    // Dragging off the button before releasing cancels the click
+                    if self.hovered_button != Some(button_id)
+                    {
+                        self.push_button_event(button_id, ButtonEventKind::PressCancelled);
+                        return;
+                    }
+                    self.push_button_event(button_id, ButtonEventKind::Click);
+                    if !app_state.all_events_disable
+                    {
+                        self.release_on_button(button_id, app_state, page_data, button_action)
+                    }
+                }
+            }
+            InputEvent::Wheel(notches) =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                if let Some(scroll_region) = page_data.scroll_region_at(app_state, self.mouse_position.0, self.mouse_position.1)
+                {
+                    page_data.scroll_by(app_state.current_page, &scroll_region, -notches * SCROLL_STEP)
+                }
+            }
+            // Without an input to move the caret in, Left/Right move the focused slider
+            InputEvent::CursorLeft(_) | InputEvent::CursorRight(_) if !app_state.capturing_input.0 =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                if let Some(button_id) = self.focused_button.filter(|focused_button| page_data.focus_order().contains(focused_button))
+                    && let Some(widget) = page_data.widget_of(app_state.current_page, button_id)
+                    && let Some(value) = page_data.widget_value(app_state.current_page, button_id)
+                    && matches!(widget.kind, WidgetKind::Slider { .. })
+                {
+                    self.change_widget(button_id, widget.nudged(value, matches!(input_event, InputEvent::CursorRight(_))), app_state, page_data)
+                }
+            }
+            // Without an input to move the caret in, the same keys scroll
+            InputEvent::CursorUp(_) | InputEvent::CursorDown(_) | InputEvent::PageUp(_) | InputEvent::PageDown(_) | InputEvent::Home(_) | InputEvent::End(_) if !app_state.capturing_input.0 =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.scroll_with_key(&input_event, app_state, page_data)
+            }
+            InputEvent::RightClick =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                if let Some(button_id) = self.hovered_button
+                {
+                    self.push_button_event(button_id, ButtonEventKind::RightClick)
+                }
+            }
             InputEvent::Text(text_input) =>
             {
                 if app_state.all_events_disable
                 {
                     return;
                 };
-                self.push_state(page_data);
-                self.insert_text(&text_input, app_state, page_data, false)
+                // Text the input rules reject entirely isn't an edit, the redo steps are kept
+                let before_edit = self.active_snapshot(app_state, page_data);
+                if self.insert_text(&text_input, app_state, page_data, false)
+                {
+                    self.push_state(before_edit, EditKind::Typing, app_state, page_data)
+                }
             }
             InputEvent::Backspace =>
             {
@@ -179,8 +671,39 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
                 {
                     return;
                 };
-                self.push_state(page_data);
-                self.backspace(app_state, page_data)
+                let before_edit = self.active_snapshot(app_state, page_data);
+                self.backspace(app_state, page_data);
+                self.push_state(before_edit, EditKind::Deleting, app_state, page_data)
+            }
+            InputEvent::Delete =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                let before_edit = self.active_snapshot(app_state, page_data);
+                self.delete(app_state, page_data);
+                self.push_state(before_edit, EditKind::Deleting, app_state, page_data)
+            }
+            InputEvent::DeleteWordBackward =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                let before_edit = self.active_snapshot(app_state, page_data);
+                self.delete_word(false, app_state, page_data);
+                self.push_state(before_edit, EditKind::Other, app_state, page_data)
+            }
+            InputEvent::DeleteWordForward =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                let before_edit = self.active_snapshot(app_state, page_data);
+                self.delete_word(true, app_state, page_data);
+                self.push_state(before_edit, EditKind::Other, app_state, page_data)
             }
             InputEvent::Paste =>
             {
@@ -188,8 +711,9 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
                 {
                     return;
                 };
-                self.push_state(page_data);
-                self.paste(Some(clipboard_util), app_state, page_data)
+                let before_edit = self.active_snapshot(app_state, page_data);
+                self.paste(Some(clipboard), app_state, page_data);
+                self.push_state(before_edit, EditKind::Other, app_state, page_data)
             }
             InputEvent::Cut =>
             {
@@ -197,8 +721,9 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
                 {
                     return;
                 };
-                self.push_state(page_data);
-                self.copy(Some(clipboard_util), app_state, page_data, true)
+                let before_edit = self.active_snapshot(app_state, page_data);
+                self.copy(Some(clipboard), app_state, page_data, true);
+                self.push_state(before_edit, EditKind::Other, app_state, page_data)
             }
             InputEvent::DeleteAll =>
             {
@@ -206,8 +731,9 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
                 {
                     return;
                 };
-                self.push_state(page_data);
-                self.delete_all(app_state, page_data)
+                let before_edit = self.active_snapshot(app_state, page_data);
+                self.delete_all(app_state, page_data);
+                self.push_state(before_edit, EditKind::Other, app_state, page_data)
             }
             InputEvent::Copy =>
             {
@@ -215,7 +741,7 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
                 {
                     return;
                 };
-                self.copy(Some(clipboard_util), app_state, page_data, false)
+                self.copy(Some(clipboard), app_state, page_data, false)
             }
             InputEvent::SelectAll =>
             {
@@ -231,7 +757,15 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
                 {
                     return;
                 };
-                self.undo(page_data)
+                self.undo(app_state, page_data)
+            }
+            InputEvent::Redo =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.redo(app_state, page_data)
             }
             InputEvent::CursorLeft(shift_held) =>
             {
@@ -249,13 +783,88 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
                 };
                 self.move_cursor(true, shift_held, app_state, page_data)
             }
+            InputEvent::WordLeft(shift_held) =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.move_cursor_by_word(false, shift_held, app_state, page_data)
+            }
+            InputEvent::WordRight(shift_held) =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.move_cursor_by_word(true, shift_held, app_state, page_data)
+            }
+            InputEvent::CursorUp(shift_held) =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.move_cursor_by_lines(-1, shift_held, app_state, page_data)
+            }
+            InputEvent::CursorDown(shift_held) =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.move_cursor_by_lines(1, shift_held, app_state, page_data)
+            }
+            InputEvent::PageUp(shift_held) =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.move_cursor_by_lines(-(self.page_lines as isize), shift_held, app_state, page_data)
+            }
+            InputEvent::PageDown(shift_held) =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.move_cursor_by_lines(self.page_lines as isize, shift_held, app_state, page_data)
+            }
+            InputEvent::Home(shift_held) =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.move_cursor_to_line_edge(false, shift_held, app_state, page_data)
+            }
+            InputEvent::End(shift_held) =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.move_cursor_to_line_edge(true, shift_held, app_state, page_data)
+            }
             InputEvent::Submit =>
             {
                 if app_state.all_events_disable
                 {
                     return;
                 };
-                app_state.capturing_input = (false, None)
+                // Text areas keep capturing, Return is just a new line there
+                if let Some(active_button_id) = app_state.capturing_input.1
+                    && page_data.is_text_area(app_state, active_button_id)
+                {
+                    let before_edit = self.active_snapshot(app_state, page_data);
+                    if self.insert_text("\n", app_state, page_data, false)
+                    {
+                        self.push_state(before_edit, EditKind::Other, app_state, page_data);
+                    }
+                    return;
+                }
+                if app_state.capturing_input.0 { app_state.capturing_input = (false, None) } else { self.activate_focused(app_state, page_data, button_action) }
             }
             InputEvent::Front =>
             {
@@ -281,13 +890,51 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
                 };
                 app_state.capturing_input = (false, None)
             }
-            InputEvent::Quit =>
+            InputEvent::FocusNext =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.move_focus(false, app_state, page_data)
+            }
+            InputEvent::FocusPrevious =>
+            {
+                if app_state.all_events_disable
+                {
+                    return;
+                };
+                self.move_focus(true, app_state, page_data)
+            }
+            InputEvent::Activate =>
+            {
+                // Space types a space while capturing input
+                if app_state.all_events_disable || app_state.capturing_input.0
+                {
+                    return;
+                };
+                self.activate_focused(app_state, page_data, button_action);
+                self.skip_space_text = app_state.capturing_input.0
+            }
+            InputEvent::NavigateUp | InputEvent::NavigateDown | InputEvent::NavigateLeft | InputEvent::NavigateRight =>
             {
                 if app_state.all_events_disable
                 {
                     return;
                 };
-                exit(0)
+                let direction = match input_event
+                {
+                    InputEvent::NavigateUp => (0, -1),
+                    InputEvent::NavigateDown => (0, 1),
+                    InputEvent::NavigateLeft => (-1, 0),
+                    _ => (1, 0)
+                };
+                self.move_focus_towards(direction, app_state, page_data)
+            }
+            InputEvent::Quit if !app_state.all_events_disable =>
+            {
+                #[cfg(feature = "serde")]
+                self.stop_recording();
             }
             _ =>
             {}
@@ -295,51 +942,75 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
     }
 
     // To Be Tweaked more, is_paste is giving weird outcome
-    pub fn insert_text(&mut self, text_to_insert: &str, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>, _is_paste: bool)
+    /// Insert At The Cursor (Replacing The Selection), Returns False When Nothing Was Inserted Because The Input Rules Rejected All Of It
+    pub fn insert_text(&mut self, text_to_insert: &str, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>, _is_paste: bool) -> bool
     {
         if !app_state.capturing_input.0
         {
-            return;
+            return false;
         }
         let Some(active_button_id) = app_state.capturing_input.1
         else
         {
-            return;
+            return false;
         };
+        let input_rules = page_data.input_rules_of(app_state.current_page, active_button_id).cloned();
         for (page_id, button_id, input_string) in &mut page_data.vec_user_input
         {
             if *page_id == app_state.current_page && *button_id == active_button_id
             {
-                if let Some((anchor_index, cursor_index)) = self.text_selection_range.take()
+                let (start_index, end_index) = match self.text_selection_range
                 {
-                    let (mut start_index, mut end_index) = if anchor_index <= cursor_index { (anchor_index, cursor_index) } else { (cursor_index, anchor_index) };
-                    start_index = start_index.min(input_string.len());
-                    end_index = end_index.min(input_string.len());
-                    if start_index != end_index
+                    Some(selection_range) => selection_bounds(input_string, selection_range),
+                    None =>
                     {
-                        input_string.replace_range(start_index..end_index, text_to_insert);
-                        self.cursor_position = start_index + text_to_insert.len()
+                        let insert_index = snap_to_grapheme(input_string, self.cursor_position);
+                        (insert_index, insert_index)
                     }
-                    else
-                    {
-                        let insert_index = self.cursor_position.min(input_string.len());
-                        input_string.insert_str(insert_index, text_to_insert);
-                        self.cursor_position = insert_index + text_to_insert.len()
-                    }
-                }
-                else
+                };
+                // Characters that are filtered out or don't fit are dropped, if nothing is left the selection stays as it was
+                let accepted_text = input_rules.as_ref().map_or_else(|| text_to_insert.to_string(), |input_rules| input_rules.accepted_insert(input_string, (start_index, end_index), text_to_insert));
+                if accepted_text.is_empty() && !text_to_insert.is_empty()
                 {
-                    let insert_index = self.cursor_position.min(input_string.len());
-                    input_string.insert_str(insert_index, text_to_insert);
-                    self.cursor_position = insert_index + text_to_insert.len()
+                    return false;
                 }
-                break;
+                input_string.replace_range(start_index..end_index, &accepted_text);
+                self.cursor_position = start_index + accepted_text.len();
+                self.text_selection_range = None;
+                page_data.update_vec_user_input_string();
+                return true;
             }
         }
-        page_data.update_vec_user_input_string()
+        false
     }
 
+    /// Delete The Selection, Or The Grapheme Cluster Before The Cursor (Like 'e' + Combining Accent Or A Flag Emoji)
     pub fn backspace(&mut self, app_state: &mut AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        self.delete_towards(previous_grapheme, app_state, page_data)
+    }
+
+    /// Delete The Selection, Or The Grapheme Cluster After The Cursor
+    pub fn delete(&mut self, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        self.delete_towards(next_grapheme, app_state, page_data)
+    }
+
+    /// Delete The Selection, Or Up To The Start Of The Previous Word (Or The End Of The Next One When `forward`)
+    pub fn delete_word(&mut self, forward: bool, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        let target: fn(&str, usize) -> usize = match (self.is_active_input_masked(app_state, page_data), forward)
+        {
+            (true, true) => |text, _| text.len(),
+            (true, false) => |_, _| 0,
+            (false, true) => next_word_end,
+            (false, false) => previous_word_start
+        };
+        self.delete_towards(target, app_state, page_data)
+    }
+
+    /// Delete The Selection If There Is One, Otherwise The Text Between The Cursor And `target(text, cursor)`
+    fn delete_towards(&mut self, target: fn(&str, usize) -> usize, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
     {
         if !app_state.capturing_input.0
         {
@@ -354,33 +1025,20 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
         {
             if *page_id == app_state.current_page && *button_id == active_button_id
             {
-                if let Some((anchor_index, cursor_index)) = self.text_selection_range.take()
-                {
-                    let (mut start_index, mut end_index) = if anchor_index <= cursor_index { (anchor_index, cursor_index) } else { (cursor_index, anchor_index) };
-                    start_index = start_index.min(input_string.len());
-                    end_index = end_index.min(input_string.len());
-                    if start_index != end_index
-                    {
-                        input_string.replace_range(start_index..end_index, "");
-                        self.cursor_position = start_index
-                    }
-                }
-                else if self.cursor_position > 0
+                let (start_index, end_index) = match self.text_selection_range.take()
                 {
-                    let remove_index = self.cursor_position - 1;
-                    if remove_index < input_string.len()
-                    {
-                        input_string.remove(remove_index);
-                        self.cursor_position -= 1
-                    }
-                }
+                    Some(selection_range) => selection_bounds(input_string, selection_range),
+                    None => selection_bounds(input_string, (self.cursor_position, target(input_string, self.cursor_position)))
+                };
+                input_string.replace_range(start_index..end_index, "");
+                self.cursor_position = start_index;
                 break;
             }
         }
         page_data.update_vec_user_input_string()
     }
 
-    pub fn copy(&mut self, clipboard_util_option: Option<&mut ClipboardUtil>, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>, is_cut_operation: bool)
+    pub fn copy(&mut self, clipboard_option: Option<&mut dyn ClipboardProvider>, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>, is_cut_operation: bool)
     {
         if !app_state.capturing_input.0
         {
@@ -391,7 +1049,12 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
         {
             return;
         };
-        if let Some(clipboard_util) = clipboard_util_option
+        // Masked inputs (passwords) never leave the field
+        if page_data.input_rules_of(app_state.current_page, active_button_id).is_some_and(|input_rules| input_rules.mask.is_some())
+        {
+            return;
+        }
+        if let Some(clipboard) = clipboard_option
         {
             for (page_id, button_id, input_string) in &mut page_data.vec_user_input
             {
@@ -400,13 +1063,11 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
                     && !input_string.is_empty()
                     && let Some((anchor_index, cursor_index)) = self.text_selection_range
                 {
-                    let (mut start_index, mut end_index) = if anchor_index <= cursor_index { (anchor_index, cursor_index) } else { (cursor_index, anchor_index) };
-                    start_index = start_index.min(input_string.len());
-                    end_index = end_index.min(input_string.len());
+                    let (start_index, end_index) = selection_bounds(input_string, (anchor_index, cursor_index));
                     if start_index < end_index
                     {
                         let selected_text = input_string[start_index..end_index].to_string();
-                        let _ = clipboard_util.set_clipboard_text(&selected_text);
+                        clipboard.set_clipboard_text(&selected_text);
                         if is_cut_operation
                         {
                             input_string.replace_range(start_index..end_index, "");
@@ -467,44 +1128,297 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
         page_data.update_vec_user_input_string()
     }
 
+    /// Move The Cursor One Grapheme Cluster To The Right Or Left
     pub fn move_cursor(&mut self, move_right: bool, shift_held: bool, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        if let Some(input_string) = self.active_input(app_state, page_data)
+        {
+            let new_cursor_index = if move_right { next_grapheme(input_string, self.cursor_position) } else { previous_grapheme(input_string, self.cursor_position) };
+            self.set_cursor(new_cursor_index, shift_held)
+        }
+    }
+
+    /// Move The Cursor To The Start Of The Previous Word Or The End Of The Next One
+    pub fn move_cursor_by_word(&mut self, move_right: bool, shift_held: bool, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>)
+    {
+        if let Some(input_string) = self.active_input(app_state, page_data)
+        {
+            let new_cursor_index = match (self.is_active_input_masked(app_state, page_data), move_right)
+            {
+                (true, true) => input_string.len(),
+                (true, false) => 0,
+                (false, true) => next_word_end(input_string, self.cursor_position),
+                (false, false) => previous_word_start(input_string, self.cursor_position)
+            };
+            self.set_cursor(new_cursor_index, shift_held)
+        }
+    }
+
+    /// Move The Cursor `lines` Lines Down (Or Up When Negative), Keeping Its Column
+    pub fn move_cursor_by_lines(&mut self, lines: isize, shift_held: bool, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>)
+    {
+        if let Some(input_string) = self.active_input(app_state, page_data)
+        {
+            let new_cursor_index = move_by_lines(input_string, self.cursor_position, lines);
+            self.set_cursor(new_cursor_index, shift_held)
+        }
+    }
+
+    /// Move The Cursor To The Start Or End Of Its Line (The Whole Text For Single Line Inputs)
+    pub fn move_cursor_to_line_edge(&mut self, to_end: bool, shift_held: bool, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>)
+    {
+        if let Some(input_string) = self.active_input(app_state, page_data)
+        {
+            let new_cursor_index = if to_end { line_end(input_string, self.cursor_position) } else { line_start(input_string, self.cursor_position) };
+            self.set_cursor(new_cursor_index, shift_held)
+        }
+    }
+
+    /// Byte Index Of The Active Input Closest To The Mouse, From The Caret Stops The Renderer Saved
+    pub fn caret_index_at_mouse(&self, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>) -> Option<usize>
+    {
+        let input_string = self.active_input(app_state, page_data)?;
+        let input_caret_stops = self.input_caret_stops.borrow();
+        let (button_id, caret_stops) = input_caret_stops.as_ref()?;
+        if Some(*button_id) != app_state.capturing_input.1
+        {
+            return None;
+        }
+        // The stops are from the last frame, the text may have changed since
+        caret_stop_at(caret_stops, (self.mouse_position.0 as i32, self.mouse_position.1 as i32)).map(|byte_index| snap_to_grapheme(input_string, byte_index))
+    }
+
+    /// Select The Word Under The Mouse (Double Click)
+    pub fn select_word_at_mouse(&mut self, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>)
+    {
+        self.selecting_with_mouse = false;
+        if let Some(input_string) = self.active_input(app_state, page_data)
+            && let Some(mouse_index) = self.caret_index_at_mouse(app_state, page_data)
+        {
+            let (word_start, word_end) = if self.is_active_input_masked(app_state, page_data) { (0, input_string.len()) } else { word_bounds(input_string, mouse_index) };
+            self.text_selection_range = Some((word_start, word_end));
+            self.cursor_position = word_end
+        }
+    }
+
+    /// Extend The Selection From Where The Mouse Was Pressed To Where It Is Now
+    pub fn drag_selection(&mut self, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>)
+    {
+        if self.selecting_with_mouse
+            && let Some(mouse_index) = self.caret_index_at_mouse(app_state, page_data)
+        {
+            self.set_cursor(mouse_index, true)
+        }
+    }
+
+    /// Place The Cursor, Extending The Selection From Its Anchor When Shift Is Held
+    fn set_cursor(&mut self, new_cursor_index: usize, shift_held: bool)
+    {
+        if shift_held
+        {
+            let anchor_index = self.text_selection_range.map_or(self.cursor_position, |(anchor_index, _)| anchor_index);
+            self.text_selection_range = Some((anchor_index, new_cursor_index));
+        }
+        else
+        {
+            self.text_selection_range = None;
+        }
+        self.cursor_position = new_cursor_index;
+    }
+
+    /// Text Of The Input Being Captured On The Current Page
+    fn active_input<'p>(&self, app_state: &AppState<PageId, ButtonId>, page_data: &'p PageData<PageId, ButtonId>) -> Option<&'p str>
     {
         if !app_state.capturing_input.0
+        {
+            return None;
+        }
+        let active_button_id = app_state.capturing_input.1?;
+        page_data.vec_user_input.iter().find(|(page_id, button_id, _)| *page_id == app_state.current_page && *button_id == active_button_id).map(|(_, _, input_string)| input_string.as_str())
+    }
+
+    /// Masked Inputs Are A Single Word For Word Moves, Deletions And Selections, Which Would Otherwise Reveal Where Their Spaces Are
+    fn is_active_input_masked(&self, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>) -> bool
+    {
+        app_state.capturing_input.1.and_then(|active_button_id| page_data.input_rules_of(app_state.current_page, active_button_id)).is_some_and(|input_rules| input_rules.mask.is_some())
+    }
+
+    /// Move The Focus To The Next (Or Previous) Button Of The Focus Order, Leaving The Input Being Typed In
+    pub fn move_focus(&mut self, backwards: bool, app_state: &mut AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        let current_focus = app_state.capturing_input.1.or(self.focused_button);
+        if app_state.capturing_input.0
+        {
+            app_state.capturing_input = (false, None);
+            self.text_selection_range = None
+        }
+        self.focused_button = page_data.next_focus(current_focus, backwards);
+        if let Some(focused_button) = self.focused_button
+        {
+            page_data.scroll_to_button(focused_button)
+        }
+    }
+
+    /// Move The Focus To The Closest Button In A Direction, Leaving The Input Being Typed In
+    pub fn move_focus_towards(&mut self, direction: (i32, i32), app_state: &mut AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        let current_focus = app_state.capturing_input.1.or(self.focused_button);
+        if app_state.capturing_input.0
+        {
+            app_state.capturing_input = (false, None);
+            self.text_selection_range = None
+        }
+        self.focused_button = page_data.focus_towards(current_focus, direction);
+        if let Some(focused_button) = self.focused_button
+        {
+            page_data.scroll_to_button(focused_button)
+        }
+    }
+
+    /// Scroll The Region Under The Mouse (Or The First One Of The Page) By A Line, A Page Or To Either End
+    fn scroll_with_key(&self, input_event: &InputEvent, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        let Some((scroll_region, offset)) = page_data.scroll_region_at(app_state, self.mouse_position.0, self.mouse_position.1).map(|scroll_region| (scroll_region, page_data.scroll_offset(app_state.current_page, scroll_region.id))).or_else(|| page_data.scrolled_regions().first().map(|(scroll_region, offset)| (*scroll_region, *offset as f32)))
+        else
+        {
+            return;
+        };
+        let page_height = scroll_region.rect.height() as f32;
+        let new_offset = match input_event
+        {
+            InputEvent::CursorUp(_) => offset - SCROLL_STEP,
+            InputEvent::CursorDown(_) => offset + SCROLL_STEP,
+            InputEvent::PageUp(_) => offset - page_height,
+            InputEvent::PageDown(_) => offset + page_height,
+            InputEvent::Home(_) => 0.,
+            _ => scroll_region.max_offset()
+        };
+        page_data.set_scroll_offset(app_state.current_page, &scroll_region, new_offset)
+    }
+
+    /// Start Dragging A Scrollbar, Clicks On The Track Outside The Thumb First Jump So The Thumb Is Centered On The Mouse
+    fn grab_scrollbar(&mut self, scroll_region: ScrollRegion<ButtonId>, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        let (_, mouse_y) = app_state.logical_mouse_position(self.mouse_position);
+        let Some(thumb_rect) = scroll_region.thumb_rect(page_data.scroll_offset(app_state.current_page, scroll_region.id))
+        else
         {
             return;
+        };
+        let grab_y = if (thumb_rect.y()..thumb_rect.bottom()).contains(&(mouse_y as i32)) { mouse_y as i32 - thumb_rect.y() } else { thumb_rect.height() as i32 / 2 };
+        self.scrollbar_drag = Some((scroll_region, grab_y));
+        self.drag_scrollbar(app_state, page_data)
+    }
+
+    /// Move The Grabbed Scrollbar Thumb Along With The Mouse
+    fn drag_scrollbar(&mut self, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        if let Some((scroll_region, grab_y)) = self.scrollbar_drag
+        {
+            let (_, mouse_y) = app_state.logical_mouse_position(self.mouse_position);
+            page_data.set_scroll_offset(app_state.current_page, &scroll_region, scroll_region.offset_for_thumb(mouse_y as i32 - grab_y))
         }
-        let Some(active_button_id) = app_state.capturing_input.1
+    }
+
+    /// Check, Uncheck Or Pick A Widget Like A Click Does, Sliders Are Left Alone (They Follow The Mouse From The Press).
+    /// Returns If `button_id` Is A Widget Of The Current Page
+    fn press_widget(&mut self, button_id: ButtonId, app_state: &mut AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>) -> bool
+    {
+        let Some(widget) = page_data.widget_of(app_state.current_page, button_id)
+        else
+        {
+            return false;
+        };
+        let value = page_data.widget_value(app_state.current_page, button_id).unwrap_or(widget.default_value());
+        match widget.kind
+        {
+            WidgetKind::Checkbox | WidgetKind::Toggle => self.change_widget(button_id, WidgetValue::Checked(!value.is_checked()), app_state, page_data),
+            WidgetKind::Radio(_) => self.change_widget(button_id, WidgetValue::Checked(true), app_state, page_data),
+            WidgetKind::Slider { .. } | WidgetKind::ProgressBar =>
+            {}
+        }
+        true
+    }
+
+    /// Set The Dragged Slider To The Value Under The Mouse
+    fn drag_slider(&mut self, app_state: &mut AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        let Some(button_id) = self.slider_drag
         else
         {
             return;
         };
-        let mut text_length = 0;
-        for (page_id, button_id, input_string) in &mut page_data.vec_user_input
+        let Some(widget) = page_data.widget_of(app_state.current_page, button_id)
+        else
         {
-            if *page_id == app_state.current_page && *button_id == active_button_id
+            return;
+        };
+        // Scroll regions only move elements up and down, so the declared rect is horizontally where the slider is shown
+        let Some(slider_rect) = page_data.page_to_render.as_ref().and_then(|page| page.buttons.iter().flatten().find(|button| button.id == button_id)).map(|button| button.rect)
+        else
+        {
+            return;
+        };
+        let (mouse_x, _) = app_state.logical_mouse_position(self.mouse_position);
+        self.change_widget(button_id, widget.value_at(slider_rect, mouse_x), app_state, page_data)
+    }
+
+    /// Set The Value Of A Widget Of The Current Page, Running The Widget Callback For It And For Every Radio It Unchecked
+    pub fn change_widget(&self, button_id: ButtonId, value: WidgetValue, app_state: &mut AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        let changed_widgets = page_data.set_widget_value(app_state.current_page, button_id, value);
+        if let Some(widget_callback) = &self.widget_callback
+        {
+            for (changed_button_id, new_value) in changed_widgets
             {
-                text_length = input_string.len();
-                break;
+                widget_callback(app_state, &changed_button_id, new_value, page_data)
             }
         }
-        if shift_held
+    }
+
+    /// Finish A Click: Check The Released Widget Or Run The button_action, Putting The Caret At The Mouse In A Newly Captured Input
+    #[allow(clippy::type_complexity)]
+    fn release_on_button(&mut self, button_id: ButtonId, app_state: &mut AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>, button_action: &mut dyn Fn(&mut AppState<PageId, ButtonId>, &ButtonId, &mut PageData<PageId, ButtonId>))
+    {
+        if self.press_widget(button_id, app_state, page_data)
+        {
+            return;
+        }
+        let Some(button_selected) = self.button_selected
+        else
+        {
+            return;
+        };
+        let capturing_before = app_state.capturing_input;
+        (*button_action)(app_state, &button_selected, page_data);
+        if app_state.capturing_input.0
+            && app_state.capturing_input != capturing_before
+            && let Some(captured_button_id) = app_state.capturing_input.1
+        {
+            // Inputs that were just focused have no caret stops yet, the caret goes to the end
+            self.cursor_position = self.caret_index_at_mouse(app_state, page_data).unwrap_or_else(|| self.get_current_input_length(app_state, page_data, captured_button_id));
+            self.text_selection_range = None
+        }
+    }
+
+    /// Run The button_action Of The Focused Button (Or Check The Focused Widget), Like Clicking It
+    #[allow(clippy::type_complexity)]
+    pub fn activate_focused(&mut self, app_state: &mut AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>, button_action: &mut dyn Fn(&mut AppState<PageId, ButtonId>, &ButtonId, &mut PageData<PageId, ButtonId>))
+    {
+        // The focus may be left over from another page
+        if let Some(button_id) = self.focused_button.filter(|focused_button| page_data.focus_order().contains(focused_button))
         {
-            if self.text_selection_range.is_none()
+            if self.press_widget(button_id, app_state, page_data)
             {
-                self.text_selection_range = Some((self.cursor_position, self.cursor_position))
+                return;
             }
-            if let Some((anchor_index, _cursor_index)) = self.text_selection_range
+            (*button_action)(app_state, &button_id, page_data);
+            if app_state.capturing_input.0
             {
-                let new_cursor_index = if move_right { (self.cursor_position + 1).min(text_length) } else { self.cursor_position.saturating_sub(1) };
-                self.text_selection_range = Some((anchor_index, new_cursor_index));
-                self.cursor_position = new_cursor_index;
+                self.cursor_position = self.get_current_input_length(app_state, page_data, button_id);
+                self.text_selection_range = None
             }
         }
-        else
-        {
-            self.text_selection_range = None;
-            self.cursor_position = if move_right { (self.cursor_position + 1).min(text_length) } else { self.cursor_position.saturating_sub(1) };
-        }
     }
 
     pub fn navigate_history(&self, move_forward: bool, app_state: &mut AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
@@ -536,29 +1450,81 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
         }
     }
 
-    pub fn paste(&mut self, clipboard_util_option: Option<&mut ClipboardUtil>, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    pub fn paste(&mut self, clipboard_option: Option<&mut dyn ClipboardProvider>, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
     {
-        if let Some(clipboard_util) = clipboard_util_option
-            && let Ok(clipboard_text) = clipboard_util.clipboard_text()
+        if let Some(clipboard) = clipboard_option
+            && let Some(clipboard_text) = clipboard.clipboard_text()
         {
             self.insert_text(&clipboard_text, app_state, page_data, true);
         }
     }
 
-    pub fn push_state(&mut self, page_data: &PageData<PageId, ButtonId>)
+    /// Text, Caret And Selection Of The Active Input, Taken Before An Edit And Given To `push_state` After It
+    pub fn active_snapshot(&self, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>) -> Option<EditSnapshot>
     {
-        self.input_history_stack.push(page_data.vec_user_input.clone())
+        self.active_input(app_state, page_data).map(|input_string| self.edit_snapshot(input_string))
     }
 
-    pub fn undo(&mut self, page_data: &mut PageData<PageId, ButtonId>)
+    /// Save The Active Input As It Was Before An Edit, Only When The Edit Changed Its Text Or Selection
+    /// (so a key that does nothing keeps the redo steps), Typing Or Deleting Runs Are Kept As One Step
+    pub fn push_state(&mut self, before_edit: Option<EditSnapshot>, edit_kind: EditKind, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>)
     {
-        if let Some(previous_input_state) = self.input_history_stack.pop()
+        if let Some(active_button_id) = app_state.capturing_input.1
+            && let Some(before_edit) = before_edit
+            && let Some(input_string) = self.active_input(app_state, page_data)
+            && (before_edit.text != input_string || before_edit.text_selection_range != self.text_selection_range)
         {
-            page_data.vec_user_input = previous_input_state;
+            self.edit_history.record(app_state.current_page, active_button_id, before_edit, edit_kind)
+        }
+    }
+
+    /// Revert The Last Edit Step Of The Active Input
+    pub fn undo(&mut self, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        self.step_history(false, app_state, page_data)
+    }
+
+    /// Reapply The Last Undone Edit Step Of The Active Input
+    pub fn redo(&mut self, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        self.step_history(true, app_state, page_data)
+    }
+
+    fn step_history(&mut self, redo: bool, app_state: &AppState<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>)
+    {
+        let Some(active_button_id) = app_state.capturing_input.1
+        else
+        {
+            return;
+        };
+        let Some(input_string) = self.active_input(app_state, page_data)
+        else
+        {
+            return;
+        };
+        let current = self.edit_snapshot(input_string);
+        let restored = if redo { self.edit_history.redo(app_state.current_page, active_button_id, current) } else { self.edit_history.undo(app_state.current_page, active_button_id, current) };
+        if let Some(snapshot) = restored
+        {
+            for (page_id, button_id, input_string) in &mut page_data.vec_user_input
+            {
+                if *page_id == app_state.current_page && *button_id == active_button_id
+                {
+                    *input_string = snapshot.text;
+                    break;
+                }
+            }
+            self.cursor_position = snapshot.cursor_position;
+            self.text_selection_range = snapshot.text_selection_range;
             page_data.update_vec_user_input_string()
         }
     }
 
+    fn edit_snapshot(&self, input_string: &str) -> EditSnapshot
+    {
+        EditSnapshot { text: input_string.to_string(), cursor_position: self.cursor_position, text_selection_range: self.text_selection_range }
+    }
+
     pub fn get_current_input_length(&self, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>, button_id: ButtonId) -> usize
     {
         for (page_id, button_id_in_vec, input_string) in &page_data.vec_user_input
@@ -571,3 +1537,9 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputHandler<PageId
         0
     }
 }
+
+/// Sorted (start, end) Of A Selection, Snapped To The Grapheme Cluster Boundaries Of `text`
+fn selection_bounds(text: &str, (anchor_index, cursor_index): (usize, usize)) -> (usize, usize)
+{
+    (snap_to_grapheme(text, anchor_index.min(cursor_index)), snap_to_grapheme(text, anchor_index.max(cursor_index)))
+}
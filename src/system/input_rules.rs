@@ -0,0 +1,136 @@
+use crate::system::text_edit::snap_to_grapheme;
+use std::rc::Rc;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Checks The Whole Text Of An Input, Returning The Message To Show When It Is Invalid
+pub type Validator = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+/// Characters Accepted When Typing Or Pasting Into An Input, Others Are Dropped
+#[derive(Clone)]
+pub enum CharFilter
+{
+    Digits,
+    Hex,
+    Alphanumeric,
+    Custom(Rc<dyn Fn(char) -> bool>)
+}
+
+impl CharFilter
+{
+    pub fn allows(&self, character: char) -> bool
+    {
+        match self
+        {
+            CharFilter::Digits => character.is_ascii_digit(),
+            CharFilter::Hex => character.is_ascii_hexdigit(),
+            CharFilter::Alphanumeric => character.is_alphanumeric(),
+            CharFilter::Custom(allows) => allows(character)
+        }
+    }
+}
+
+/// Constraints Of One Input, Declared In `Page::has_input_rules` Next To `has_userinput`:
+/// `InputRules { max_length: Some(4), char_filter: Some(CharFilter::Digits), mask: Some('•'), ..InputRules::default() }`
+#[derive(Clone, Default)]
+pub struct InputRules
+{
+    /// Longest text accepted, in grapheme clusters, what doesn't fit of a paste is cut
+    pub max_length: Option<usize>,
+    pub char_filter: Option<CharFilter>,
+    /// Run after the other checks, the text is kept when they fail so it can still be fixed
+    pub validators: Vec<Validator>,
+    /// Every grapheme cluster is shown as this character (passwords), `vec_user_input` keeps the real text
+    pub mask: Option<char>,
+    /// Hint drawn in a dimmer color while the input is empty, the caret still sits at its start
+    pub placeholder: Option<String>
+}
+
+impl InputRules
+{
+    /// Password Field Rules, Bullets Are Shown Instead Of The Text And It Can't Be Copied
+    pub fn password() -> Self
+    {
+        Self { mask: Some('•'), ..Self::default() }
+    }
+
+    /// Part Of `text_to_insert` Accepted When Replacing `replaced_range` (Byte Indexes) Of `text`
+    pub fn accepted_insert(&self, text: &str, replaced_range: (usize, usize), text_to_insert: &str) -> String
+    {
+        let mut accepted: String = match &self.char_filter
+        {
+            Some(char_filter) => text_to_insert.chars().filter(|character| char_filter.allows(*character)).collect(),
+            None => text_to_insert.to_string()
+        };
+        if let Some(max_length) = self.max_length
+        {
+            let kept_length = text[..replaced_range.0].graphemes(true).count() + text[replaced_range.1..].graphemes(true).count();
+            let room = max_length.saturating_sub(kept_length);
+            if let Some((cut_index, _)) = accepted.grapheme_indices(true).nth(room)
+            {
+                accepted.truncate(cut_index);
+            }
+        }
+        accepted
+    }
+
+    /// Check The Max Length, The Char Filter And Every Validator, Returning The First Error Message
+    pub fn validate(&self, text: &str) -> Result<(), String>
+    {
+        if let Some(max_length) = self.max_length
+            && text.graphemes(true).count() > max_length
+        {
+            return Err(format!("Longer than {} characters", max_length));
+        }
+        if let Some(char_filter) = &self.char_filter
+            && let Some(character) = text.chars().find(|character| !char_filter.allows(*character))
+        {
+            return Err(format!("'{}' is not allowed", character));
+        }
+        self.validators.iter().try_for_each(|validator| validator(text))
+    }
+
+    /// The Text As It Is Shown, Masked If There Is A Mask
+    pub fn display_text(&self, text: &str) -> String
+    {
+        match self.mask
+        {
+            Some(mask) => text.graphemes(true).map(|_| mask).collect(),
+            None => text.to_string()
+        }
+    }
+
+    /// The Placeholder To Draw Instead Of `text`, Only While It Is Empty
+    pub fn placeholder_text(&self, text: &str) -> Option<&str>
+    {
+        self.placeholder.as_deref().filter(|_| text.is_empty())
+    }
+
+    /// Byte Index In The Displayed Text Of The Byte Index `position` Of The Real Text
+    pub fn display_index(&self, text: &str, position: usize) -> usize
+    {
+        match self.mask
+        {
+            Some(mask) => text[..snap_to_grapheme(text, position)].graphemes(true).count() * mask.len_utf8(),
+            None => position
+        }
+    }
+
+    /// Byte Index In The Real Text Of The Byte Index `display_position` Of The Displayed Text
+    pub fn text_index(&self, text: &str, display_position: usize) -> usize
+    {
+        match self.mask
+        {
+            Some(mask) => text.grapheme_indices(true).nth(display_position / mask.len_utf8()).map_or(text.len(), |(grapheme_start, _)| grapheme_start),
+            None => display_position
+        }
+    }
+}
+
+/// Validator Failing With `error_message` When The Text Doesn't Match `pattern`, Errors If The Pattern Is Not A Valid Regex
+#[cfg(feature = "regex-validation")]
+pub fn regex_validator(pattern: &str, error_message: &str) -> Result<Validator, String>
+{
+    let regex = regex::Regex::new(pattern).map_err(|err| format!("Invalid pattern '{}': {}", pattern, err))?;
+    let error_message = error_message.to_string();
+    Ok(Rc::new(move |text: &str| if regex.is_match(text) { Ok(()) } else { Err(error_message.clone()) }))
+}
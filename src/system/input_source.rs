@@ -0,0 +1,167 @@
+use crate::system::input_handler::{InputEvent, InputHandler};
+use sdl3::{EventPump, clipboard::ClipboardUtil};
+use std::{collections::VecDeque, fmt::Debug, time::Instant};
+
+/// Where The InputHandler Reads The Mouse Position And The Next InputEvent From.
+/// Implemented for SDL's EventPump and for ScriptedInput, so the same input pipeline
+/// can run with a live window or with a synthetic event stream.
+pub trait InputSource<PageId, ButtonId>
+{
+    /// Current mouse position in window coordinates
+    fn mouse_position(&mut self) -> (f32, f32);
+    /// Next event to process this frame, `InputEvent::None` when there is nothing to do
+    fn next_event(&mut self, input_handler: &InputHandler<PageId, ButtonId>) -> InputEvent;
+}
+
+impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> InputSource<PageId, ButtonId> for EventPump
+{
+    fn mouse_position(&mut self) -> (f32, f32)
+    {
+        let mouse_state = self.mouse_state();
+        (mouse_state.x(), mouse_state.y())
+    }
+
+    fn next_event(&mut self, input_handler: &InputHandler<PageId, ButtonId>) -> InputEvent
+    {
+        input_handler.poll(self)
+    }
+}
+
+/// Clipboard Used By Copy, Cut And Paste
+pub trait ClipboardProvider
+{
+    fn clipboard_text(&mut self) -> Option<String>;
+    fn set_clipboard_text(&mut self, text: &str);
+}
+
+impl ClipboardProvider for ClipboardUtil
+{
+    fn clipboard_text(&mut self) -> Option<String>
+    {
+        ClipboardUtil::clipboard_text(self).ok()
+    }
+
+    fn set_clipboard_text(&mut self, text: &str)
+    {
+        let _ = ClipboardUtil::set_clipboard_text(self, text);
+    }
+}
+
+/// In Memory Clipboard, For Tests And Scripted Sessions Without A Window
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryClipboard
+{
+    pub text: String
+}
+
+impl ClipboardProvider for MemoryClipboard
+{
+    fn clipboard_text(&mut self) -> Option<String>
+    {
+        Some(self.text.clone())
+    }
+
+    fn set_clipboard_text(&mut self, text: &str)
+    {
+        self.text = text.to_string();
+    }
+}
+
+/// One Event Of A Script, With The Mouse Position At That Moment And When It Happens
+/// (in milliseconds since the script started)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptedStep
+{
+    pub timestamp_ms: u64,
+    pub mouse_position: (f32, f32),
+    pub event: InputEvent
+}
+
+impl ScriptedStep
+{
+    pub fn new(timestamp_ms: u64, mouse_position: (f32, f32), event: InputEvent) -> Self
+    {
+        Self { timestamp_ms, mouse_position, event }
+    }
+}
+
+/// InputSource That Replays A Recorded Sequence Of Steps Instead Of Reading SDL Events
+pub struct ScriptedInput
+{
+    steps: VecDeque<ScriptedStep>,
+    mouse_position: (f32, f32),
+    realtime: bool,
+    started_at: Option<Instant>
+}
+
+impl ScriptedInput
+{
+    /// Deliver one step per poll, ignoring the timestamps (deterministic, for tests)
+    pub fn new(steps: Vec<ScriptedStep>) -> Self
+    {
+        Self { steps: VecDeque::from(steps), mouse_position: (0., 0.), realtime: false, started_at: None }
+    }
+
+    /// Deliver each step once its timestamp has elapsed since the first poll (for demos)
+    pub fn realtime(steps: Vec<ScriptedStep>) -> Self
+    {
+        Self { realtime: true, ..Self::new(steps) }
+    }
+
+    /// Returns whether every step was already delivered
+    pub fn is_finished(&self) -> bool
+    {
+        self.steps.is_empty()
+    }
+
+    /// Number of steps still waiting to be delivered
+    pub fn remaining_steps(&self) -> usize
+    {
+        self.steps.len()
+    }
+
+    fn next_step_is_due(&mut self) -> bool
+    {
+        let Some(next_step) = self.steps.front()
+        else
+        {
+            return false;
+        };
+        if !self.realtime
+        {
+            return true;
+        }
+        let started_at = *self.started_at.get_or_insert_with(Instant::now);
+        started_at.elapsed().as_millis() as u64 >= next_step.timestamp_ms
+    }
+}
+
+impl<PageId, ButtonId> InputSource<PageId, ButtonId> for ScriptedInput
+{
+    fn mouse_position(&mut self) -> (f32, f32)
+    {
+        if self.next_step_is_due()
+            && let Some(next_step) = self.steps.front()
+        {
+            self.mouse_position = next_step.mouse_position;
+        }
+        self.mouse_position
+    }
+
+    fn next_event(&mut self, _input_handler: &InputHandler<PageId, ButtonId>) -> InputEvent
+    {
+        if !self.next_step_is_due()
+        {
+            return InputEvent::None;
+        }
+        match self.steps.pop_front()
+        {
+            Some(step) =>
+            {
+                self.mouse_position = step.mouse_position;
+                step.event
+            }
+            None => InputEvent::None
+        }
+    }
+}
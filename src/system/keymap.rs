@@ -0,0 +1,298 @@
+use crate::system::input_handler::InputEvent;
+use sdl3::{
+    keyboard::{Keycode, Mod},
+    mouse::MouseButton
+};
+#[cfg(feature = "serde")]
+use serde::{
+    Deserialize,
+    de::value::{self, MapAccessDeserializer, MapDeserializer, StrDeserializer}
+};
+#[cfg(feature = "serde")]
+use std::{fs, iter, path::Path};
+
+/// A Key And The Modifiers Held With It, Left And Right Modifiers Count The Same:
+/// `KeyCombo::new(Keycode::Z).ctrl().shift()` or `KeyCombo::parse("Ctrl+Shift+Z")`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyCombo
+{
+    pub keycode: Keycode,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool
+}
+
+impl KeyCombo
+{
+    pub fn new(keycode: Keycode) -> Self
+    {
+        Self { keycode, ctrl: false, shift: false, alt: false }
+    }
+
+    pub fn ctrl(self) -> Self
+    {
+        Self { ctrl: true, ..self }
+    }
+
+    pub fn shift(self) -> Self
+    {
+        Self { shift: true, ..self }
+    }
+
+    pub fn alt(self) -> Self
+    {
+        Self { alt: true, ..self }
+    }
+
+    /// The Combo Of A KeyDown Event, Lock Keys (Caps, Num) Are Ignored
+    pub fn from_event(keycode: Keycode, keymod: Mod) -> Self
+    {
+        Self { keycode, ctrl: keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD), shift: keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD), alt: keymod.intersects(Mod::LALTMOD | Mod::RALTMOD) }
+    }
+
+    /// Whether This Is Only A Modifier Being Pressed, Which Doesn't Break A Chord
+    pub fn is_modifier(&self) -> bool
+    {
+        [Keycode::LCtrl, Keycode::RCtrl, Keycode::LShift, Keycode::RShift, Keycode::LAlt, Keycode::RAlt, Keycode::LGui, Keycode::RGui].contains(&self.keycode)
+    }
+
+    /// Parse Modifiers And An SDL Key Name Joined By '+', Like "Ctrl+Shift+Z" Or "Alt+Left"
+    pub fn parse(text: &str) -> Result<Self, String>
+    {
+        let mut parts: Vec<&str> = text.split('+').map(str::trim).collect();
+        let key_name = parts.pop().filter(|key_name| !key_name.is_empty()).ok_or(format!("Missing key in '{}'", text))?;
+        let keycode = Keycode::from_name(key_name).ok_or(format!("Unknown key '{}'", key_name))?;
+        let mut key_combo = Self::new(keycode);
+        for modifier in parts
+        {
+            key_combo = match modifier.to_lowercase().as_str()
+            {
+                "ctrl" | "control" => key_combo.ctrl(),
+                "shift" => key_combo.shift(),
+                "alt" => key_combo.alt(),
+                _ => return Err(format!("Unknown modifier '{}' in '{}'", modifier, text))
+            };
+        }
+        Ok(key_combo)
+    }
+}
+
+/// What Fires A Binding: A Key Combo, A Chord (Combos Pressed One After Another, Like Ctrl+K Then Ctrl+C) Or A Mouse Button
+#[derive(Debug, Clone, PartialEq)]
+pub enum Trigger
+{
+    Keys(Vec<KeyCombo>),
+    Mouse(MouseButton)
+}
+
+impl Trigger
+{
+    pub fn key(key_combo: KeyCombo) -> Self
+    {
+        Trigger::Keys(vec![key_combo])
+    }
+
+    /// Parse "Alt+Left", A Chord "Ctrl+K, Ctrl+C" Or A Mouse Button "MouseMiddle", "MouseRight", "MouseX1", "MouseX2"
+    pub fn parse(text: &str) -> Result<Self, String>
+    {
+        let mouse_button = match text.trim()
+        {
+            "MouseMiddle" => Some(MouseButton::Middle),
+            "MouseRight" => Some(MouseButton::Right),
+            "MouseX1" => Some(MouseButton::X1),
+            "MouseX2" => Some(MouseButton::X2),
+            _ => None
+        };
+        match mouse_button
+        {
+            Some(mouse_button) => Ok(Trigger::Mouse(mouse_button)),
+            None => Ok(Trigger::Keys(text.split(',').map(KeyCombo::parse).collect::<Result<_, _>>()?))
+        }
+    }
+}
+
+/// Result Of Looking Up The Keys Pressed So Far
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeymapMatch
+{
+    Event(InputEvent),
+    /// The keys start a chord, wait for the next one
+    Pending,
+    NoMatch
+}
+
+/// Which InputEvent Every Shortcut Produces, Read By `InputHandler::poll`. Page bindings win over the global ones
+/// on their page, and binding a trigger to `InputEvent::None` there disables the global shortcut on that page.
+/// Typing, left clicks and dragging are not shortcuts and always work.
+#[derive(Debug, Clone)]
+pub struct Keymap<PageId>
+{
+    pub bindings: Vec<(Trigger, InputEvent)>,
+    pub page_bindings: Vec<(PageId, Trigger, InputEvent)>
+}
+
+impl<PageId> Default for Keymap<PageId>
+{
+    /// The Desktop Shortcuts: Ctrl+Z/Y/C/X/V/A, Arrows (Ctrl By Word, Shift Selects), Tab, Alt+Left/Right And The Mouse Side Buttons For The Page History...
+    fn default() -> Self
+    {
+        let mut bindings = vec![
+            (Trigger::key(KeyCombo::new(Keycode::Return)), InputEvent::Submit),
+            (Trigger::key(KeyCombo::new(Keycode::Escape)), InputEvent::ExitCapturingInput),
+            (Trigger::key(KeyCombo::new(Keycode::Backspace)), InputEvent::Backspace),
+            (Trigger::key(KeyCombo::new(Keycode::Backspace).shift()), InputEvent::Backspace),
+            (Trigger::key(KeyCombo::new(Keycode::Backspace).ctrl()), InputEvent::DeleteWordBackward),
+            (Trigger::key(KeyCombo::new(Keycode::Backspace).ctrl().shift()), InputEvent::DeleteAll),
+            (Trigger::key(KeyCombo::new(Keycode::Delete)), InputEvent::Delete),
+            (Trigger::key(KeyCombo::new(Keycode::Delete).shift()), InputEvent::Delete),
+            (Trigger::key(KeyCombo::new(Keycode::Delete).ctrl()), InputEvent::DeleteWordForward),
+            (Trigger::key(KeyCombo::new(Keycode::Z).ctrl()), InputEvent::Undo),
+            (Trigger::key(KeyCombo::new(Keycode::Z).ctrl().shift()), InputEvent::Redo),
+            (Trigger::key(KeyCombo::new(Keycode::Y).ctrl()), InputEvent::Redo),
+            (Trigger::key(KeyCombo::new(Keycode::C).ctrl()), InputEvent::Copy),
+            (Trigger::key(KeyCombo::new(Keycode::X).ctrl()), InputEvent::Cut),
+            (Trigger::key(KeyCombo::new(Keycode::V).ctrl()), InputEvent::Paste),
+            (Trigger::key(KeyCombo::new(Keycode::A).ctrl()), InputEvent::SelectAll),
+            (Trigger::key(KeyCombo::new(Keycode::Tab)), InputEvent::FocusNext),
+            (Trigger::key(KeyCombo::new(Keycode::Tab).shift()), InputEvent::FocusPrevious),
+            (Trigger::key(KeyCombo::new(Keycode::Space)), InputEvent::Activate),
+            (Trigger::key(KeyCombo::new(Keycode::Left).alt()), InputEvent::Back),
+            (Trigger::key(KeyCombo::new(Keycode::Right).alt()), InputEvent::Front),
+            (Trigger::Mouse(MouseButton::X1), InputEvent::Back),
+            (Trigger::Mouse(MouseButton::X2), InputEvent::Front),
+        ];
+        // Shift extends the selection
+        for shift_held in [false, true]
+        {
+            let with_shift = |key_combo: KeyCombo| if shift_held { key_combo.shift() } else { key_combo };
+            bindings.extend([(Trigger::key(with_shift(KeyCombo::new(Keycode::Left))), InputEvent::CursorLeft(shift_held)), (Trigger::key(with_shift(KeyCombo::new(Keycode::Right))), InputEvent::CursorRight(shift_held)), (Trigger::key(with_shift(KeyCombo::new(Keycode::Left).ctrl())), InputEvent::WordLeft(shift_held)), (Trigger::key(with_shift(KeyCombo::new(Keycode::Right).ctrl())), InputEvent::WordRight(shift_held)), (Trigger::key(with_shift(KeyCombo::new(Keycode::Up))), InputEvent::CursorUp(shift_held)), (Trigger::key(with_shift(KeyCombo::new(Keycode::Down))), InputEvent::CursorDown(shift_held)), (Trigger::key(with_shift(KeyCombo::new(Keycode::Home))), InputEvent::Home(shift_held)), (Trigger::key(with_shift(KeyCombo::new(Keycode::End))), InputEvent::End(shift_held)), (Trigger::key(with_shift(KeyCombo::new(Keycode::PageUp))), InputEvent::PageUp(shift_held)), (Trigger::key(with_shift(KeyCombo::new(Keycode::PageDown))), InputEvent::PageDown(shift_held))]);
+        }
+        Self { bindings, page_bindings: Vec::new() }
+    }
+}
+
+impl<PageId: Copy + Eq> Keymap<PageId>
+{
+    /// A Keymap Without Any Shortcut
+    pub fn empty() -> Self
+    {
+        Self { bindings: Vec::new(), page_bindings: Vec::new() }
+    }
+
+    /// Bind A Trigger Everywhere, Replacing What It Did Before
+    pub fn bind(&mut self, trigger: Trigger, input_event: InputEvent)
+    {
+        self.unbind(&trigger);
+        self.bindings.push((trigger, input_event));
+    }
+
+    pub fn unbind(&mut self, trigger: &Trigger)
+    {
+        self.bindings.retain(|(bound_trigger, _)| bound_trigger != trigger);
+    }
+
+    /// Bind A Trigger Only On One Page, `InputEvent::None` Disables The Global Binding There
+    pub fn bind_on_page(&mut self, page_id: PageId, trigger: Trigger, input_event: InputEvent)
+    {
+        self.page_bindings.retain(|(bound_page_id, bound_trigger, _)| *bound_page_id != page_id || *bound_trigger != trigger);
+        self.page_bindings.push((page_id, trigger, input_event));
+    }
+
+    #[cfg(feature = "serde")]
+    /// Apply The Overrides Of A Config File, See `apply_overrides`
+    pub fn load_overrides<P: AsRef<Path>>(&mut self, path: P) -> Result<(), String>
+    {
+        let config = fs::read_to_string(path.as_ref()).map_err(|err| format!("Failed to read keymap '{}': {}", path.as_ref().display(), err))?;
+        self.apply_overrides(&config)
+    }
+
+    #[cfg(feature = "serde")]
+    /// Apply One `Trigger = Event` Override Per Line, Nothing Is Changed If A Line Is Invalid:
+    /// ```text
+    /// # Comments start with '#'
+    /// Alt+Left = Back
+    /// Ctrl+K, Ctrl+C = Copy
+    /// Shift+Home = Home(true)
+    /// MouseX1 = Back
+    /// Ctrl+Y = None
+    /// ```
+    /// `None` removes the binding.
+    pub fn apply_overrides(&mut self, config: &str) -> Result<(), String>
+    {
+        let mut overrides = Vec::new();
+        for (line_index, line) in config.lines().enumerate()
+        {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#')
+            {
+                continue;
+            }
+            // The trigger itself may be '=' (Ctrl+=)
+            let (trigger, input_event) = line.rsplit_once('=').ok_or(format!("Line {}: expected 'Trigger = Event'", line_index + 1))?;
+            let trigger = Trigger::parse(trigger).map_err(|err| format!("Line {}: {}", line_index + 1, err))?;
+            let input_event = parse_input_event(input_event.trim()).map_err(|err| format!("Line {}: {}", line_index + 1, err))?;
+            overrides.push((trigger, input_event));
+        }
+        for (trigger, input_event) in overrides
+        {
+            match input_event
+            {
+                InputEvent::None => self.unbind(&trigger),
+                input_event => self.bind(trigger, input_event)
+            }
+        }
+        Ok(())
+    }
+
+    /// Look Up The Keys Pressed So Far (One Combo, Or More For A Chord) On A Page,
+    /// Falling Back To The Last Combo Without Modifiers When Nothing Matches Exactly
+    pub fn resolve_keys(&self, page_id: Option<PageId>, keys: &[KeyCombo]) -> KeymapMatch
+    {
+        let page_bindings = self.page_bindings.iter().filter(|(bound_page_id, _, _)| Some(*bound_page_id) == page_id).map(|(_, trigger, input_event)| (trigger, input_event));
+        let all_bindings: Vec<(&Trigger, &InputEvent)> = page_bindings.chain(self.bindings.iter().map(|(trigger, input_event)| (trigger, input_event))).collect();
+        let full_match = |keys: &[KeyCombo]| all_bindings.iter().find(|(trigger, _)| matches!(trigger, Trigger::Keys(bound_keys) if bound_keys == keys)).map(|(_, input_event)| KeymapMatch::Event((*input_event).clone()));
+        // A full match fires right away, even when a longer chord starts the same way
+        if let Some(keymap_match) = full_match(keys)
+        {
+            return keymap_match;
+        }
+        if all_bindings.iter().any(|(trigger, input_event)| **input_event != InputEvent::None && matches!(trigger, Trigger::Keys(bound_keys) if bound_keys.len() > keys.len() && bound_keys.starts_with(keys)))
+        {
+            return KeymapMatch::Pending;
+        }
+        // Modifiers nothing is bound to are ignored, so Shift+Return still submits and Alt+Backspace still deletes
+        match keys.split_last()
+        {
+            Some((last_key, previous_keys)) if *last_key != KeyCombo::new(last_key.keycode) => full_match(&[previous_keys, &[KeyCombo::new(last_key.keycode)]].concat()).unwrap_or(KeymapMatch::NoMatch),
+            _ => KeymapMatch::NoMatch
+        }
+    }
+
+    /// Event Bound To A Mouse Button On A Page
+    pub fn resolve_mouse(&self, page_id: Option<PageId>, mouse_button: MouseButton) -> Option<InputEvent>
+    {
+        let page_binding = self.page_bindings.iter().find(|(bound_page_id, trigger, _)| Some(*bound_page_id) == page_id && *trigger == Trigger::Mouse(mouse_button)).map(|(_, _, input_event)| input_event);
+        page_binding.or_else(|| self.bindings.iter().find(|(trigger, _)| *trigger == Trigger::Mouse(mouse_button)).map(|(_, input_event)| input_event)).filter(|input_event| **input_event != InputEvent::None).cloned()
+    }
+}
+
+/// Parse An InputEvent Name With The Names Its Serde Derive Uses, With `(true)`/`(false)` For The Ones Taking The Shift State.
+/// Mouse events and typed text can't be bound.
+#[cfg(feature = "serde")]
+fn parse_input_event(text: &str) -> Result<InputEvent, String>
+{
+    let input_event = match text.strip_suffix(')').and_then(|text| text.split_once('('))
+    {
+        Some((name, shift_held)) =>
+        {
+            let shift_held: bool = shift_held.parse().map_err(|_| format!("Expected '(true)' or '(false)' in '{}'", text))?;
+            InputEvent::deserialize(MapAccessDeserializer::new(MapDeserializer::<_, value::Error>::new(iter::once((name, shift_held)))))
+        }
+        None => InputEvent::deserialize(StrDeserializer::<value::Error>::new(text))
+    };
+    match input_event
+    {
+        Ok(InputEvent::Click | InputEvent::DoubleClick | InputEvent::TripleClick | InputEvent::Drag | InputEvent::MouseUp) | Err(_) => Err(format!("Unknown or unbindable event '{}'", text)),
+        Ok(input_event) => Ok(input_event)
+    }
+}
@@ -1,6 +1,21 @@
+pub mod clock;
+pub mod edit_history;
+pub mod gamepad;
 pub mod input_handler;
+pub mod input_rules;
+pub mod input_source;
+pub mod keymap;
+#[cfg(feature = "page-files")]
+pub mod page_file;
 pub mod page_system;
 pub mod renderer;
 pub mod scene_transition;
+pub mod scroll;
+#[cfg(feature = "serde")]
+pub mod session_recorder;
+pub mod snapshot;
 pub mod state;
+pub mod text_edit;
+pub mod text_layout;
+pub mod widgets;
 pub mod window;
@@ -0,0 +1,570 @@
+#[cfg(feature = "regex-validation")]
+use crate::system::input_rules::regex_validator;
+use crate::{
+    misc::vec::GetOrCreate,
+    system::{
+        input_rules::{CharFilter, InputRules},
+        page_system::{Button, ButtonStyle, Buttons, Images, Page, PageData, PersistentElements, Rects, TextBox, TextBoxes, Texts},
+        scene_transition::{SlidePos, TransitionType},
+        text_layout::{HorizontalAlign, VerticalAlign}
+    }
+};
+use sdl3::{pixels::Color, rect::Rect};
+use serde::Deserialize;
+use std::{
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::SystemTime
+};
+
+/// Page Builder Produced From A Page File, Ready To Be Passed To `PageData::populate_rps_data`
+pub type PageWithInputFn<PageId, ButtonId> = Rc<dyn Fn(&mut Vec<String>) -> Page<PageId, ButtonId>>;
+
+/// Resolves The Names Used In Page Files To Your PageId/ButtonId Enums.
+/// Usually implemented with the `impl_id_from_name!` macro.
+pub trait IdFromName: Sized
+{
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+/// Implement `IdFromName` For A Fieldless Enum, Matching Each Variant By Its Name
+/// ```ignore
+/// impl_id_from_name!(PageId { Page1, Page2 });
+/// ```
+#[macro_export]
+macro_rules! impl_id_from_name {
+    ($enum_name:ident { $($variant:ident),* $(,)? }) => {
+        impl $crate::system::page_file::IdFromName for $enum_name
+        {
+            fn from_name(name: &str) -> Option<Self>
+            {
+                match name
+                {
+                    $(stringify!($variant) => Some($enum_name::$variant),)*
+                    _ => None
+                }
+            }
+        }
+    };
+}
+
+/// Format Of A Page File, Picked From The File Extension By `PageFile::load`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageFileFormat
+{
+    Ron,
+    Toml,
+    Json
+}
+
+impl PageFileFormat
+{
+    pub fn from_path(path: &Path) -> Result<Self, String>
+    {
+        match path.extension().and_then(|extension| extension.to_str()).map(|extension| extension.to_ascii_lowercase()).as_deref()
+        {
+            Some("ron") => Ok(PageFileFormat::Ron),
+            Some("toml") => Ok(PageFileFormat::Toml),
+            Some("json") => Ok(PageFileFormat::Json),
+            _ => Err(format!("Unsupported page file extension for '{}', expected .ron, .toml or .json", path.display()))
+        }
+    }
+}
+
+/// Content Of A Page File: Any Number Of Pages And The Persistent Elements They Use
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PageFile
+{
+    #[serde(default)]
+    pub pages: Vec<PageDefinition>,
+    #[serde(default)]
+    pub persistent_elements: Vec<PersistentElementsDefinition>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PageDefinition
+{
+    /// Name of a PageId variant
+    pub id: String,
+    /// Ids of persistent elements (declared in the same file) drawn with this page
+    #[serde(default)]
+    pub persistent_elements: Vec<String>,
+    /// Names of the ButtonId variants of this page that capture user input
+    #[serde(default)]
+    pub user_input: Vec<String>,
+    /// Names of the user_input buttons edited as multi-line text areas
+    #[serde(default)]
+    pub text_area: Vec<String>,
+    /// Rules of the user_input buttons that have some
+    #[serde(default)]
+    pub input_rules: Vec<InputRulesDefinition>,
+    #[serde(default)]
+    pub background_color: Option<Vec<u8>>,
+    #[serde(default)]
+    pub rects: Vec<RectDefinition>,
+    #[serde(default)]
+    pub buttons: Vec<ButtonDefinition>,
+    #[serde(default)]
+    pub texts: Vec<TextDefinition>,
+    #[serde(default)]
+    pub text_boxes: Vec<TextBoxDefinition>,
+    #[serde(default)]
+    pub images: Vec<ImageDefinition>
+}
+
+/// InputRules Of One Input, Closure Filters And Validators Are Code Only
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InputRulesDefinition
+{
+    /// Name of a ButtonId variant listed in the page's user_input
+    pub id: String,
+    #[serde(default)]
+    pub max_length: Option<usize>,
+    #[serde(default)]
+    pub char_filter: Option<CharFilterDefinition>,
+    #[serde(default)]
+    pub mask: Option<char>,
+    #[serde(default)]
+    pub placeholder: Option<String>,
+    /// Pattern and error message of a regex validator, needs the regex-validation feature
+    #[serde(default)]
+    pub regex: Option<(String, String)>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum CharFilterDefinition
+{
+    Digits,
+    Hex,
+    Alphanumeric
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PersistentElementsDefinition
+{
+    /// Name of a PageId variant
+    pub id: String,
+    #[serde(default)]
+    pub background_color: Option<Vec<u8>>,
+    #[serde(default)]
+    pub rects: Vec<RectDefinition>,
+    #[serde(default)]
+    pub buttons: Vec<ButtonDefinition>,
+    #[serde(default)]
+    pub texts: Vec<TextDefinition>,
+    #[serde(default)]
+    pub text_boxes: Vec<TextBoxDefinition>,
+    #[serde(default)]
+    pub images: Vec<ImageDefinition>
+}
+
+/// Colors Are Written As `[r, g, b]` Or `[r, g, b, a]`, Rects As `[x, y, w, h]`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RectDefinition
+{
+    pub color: Vec<u8>,
+    pub rect: (i32, i32, u32, u32),
+    #[serde(default)]
+    pub radius: i32
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ButtonDefinition
+{
+    /// Name of a ButtonId variant
+    pub id: String,
+    pub color: Vec<u8>,
+    pub rect: (i32, i32, u32, u32),
+    #[serde(default)]
+    pub radius: i32,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub transition: Option<TransitionDefinition>,
+    #[serde(default)]
+    pub tab_index: Option<u32>,
+    #[serde(default)]
+    pub style: Option<ButtonStyleDefinition>,
+    #[serde(default)]
+    pub tooltip: Option<String>
+}
+
+/// Colors Of A Button In Each State, Every Field Is Optional
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct ButtonStyleDefinition
+{
+    pub hover_color: Option<Vec<u8>>,
+    pub pressed_color: Option<Vec<u8>>,
+    pub focused_color: Option<Vec<u8>>,
+    pub disabled_color: Option<Vec<u8>>,
+    /// Color and width in pixels
+    pub border: Option<(Vec<u8>, u32)>
+}
+
+/// A Text Shows Either `text` Or, When `user_input` Is Set, The User Input At That Index
+/// (the same index you would use with `user_input.get_or_create(index)` in Rust)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TextDefinition
+{
+    pub size: f64,
+    pub position: (i32, i32),
+    #[serde(default)]
+    pub text: String,
+    pub color: Vec<u8>,
+    #[serde(default)]
+    pub user_input: Option<usize>
+}
+
+/// Text Aligned Inside `rect`, `user_input` Works Like In TextDefinition and `input` Names The
+/// user_input Button Edited Through This Box (It Gets The Caret While That Input Is Captured)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TextBoxDefinition
+{
+    pub size: f64,
+    pub rect: (i32, i32, u32, u32),
+    #[serde(default)]
+    pub text: String,
+    pub color: Vec<u8>,
+    #[serde(default)]
+    pub horizontal_align: HorizontalAlign,
+    #[serde(default)]
+    pub vertical_align: VerticalAlign,
+    #[serde(default)]
+    pub max_width: Option<u32>,
+    #[serde(default)]
+    pub clip_rect: Option<(i32, i32, u32, u32)>,
+    #[serde(default)]
+    pub user_input: Option<usize>,
+    #[serde(default)]
+    pub input: Option<String>
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImageDefinition
+{
+    pub position: (i32, i32),
+    pub size: (u32, u32),
+    pub path: String
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum TransitionDefinition
+{
+    Fade,
+    Slide(SlidePos, i32)
+}
+
+/// A Page File Loaded With `PageData::load_page_files`, With The Modification Time Seen
+/// When It Was Last Loaded And The Pages It Linked
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchedPageFile<PageId>
+{
+    pub path: PathBuf,
+    pub modified: Option<SystemTime>,
+    pub page_ids: Vec<PageId>
+}
+
+fn enabled_by_default() -> bool
+{
+    true
+}
+
+impl PageFile
+{
+    /// Read And Parse A Page File, The Format Is Picked From The Extension (.ron, .toml, .json)
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String>
+    {
+        let path = path.as_ref();
+        let format = PageFileFormat::from_path(path)?;
+        let contents = fs::read_to_string(path).map_err(|err| format!("Failed to read page file '{}': {}", path.display(), err))?;
+        Self::parse(&contents, format).map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    pub fn parse(contents: &str, format: PageFileFormat) -> Result<Self, String>
+    {
+        match format
+        {
+            PageFileFormat::Ron => ron::from_str(contents).map_err(|err| format!("Invalid RON page file: {}", err)),
+            PageFileFormat::Toml => toml::from_str(contents).map_err(|err| format!("Invalid TOML page file: {}", err)),
+            PageFileFormat::Json => serde_json::from_str(contents).map_err(|err| format!("Invalid JSON page file: {}", err))
+        }
+    }
+
+    /// Resolve Every Name And Build One Page Builder Per Page, Failing On The First Unknown Name
+    pub fn input_linked_pages<PageId: IdFromName + Copy + 'static, ButtonId: IdFromName + Copy + 'static>(&self) -> Result<Vec<PageWithInputFn<PageId, ButtonId>>, String>
+    {
+        let mut persistent_elements = Vec::new();
+        for persistent_definition in &self.persistent_elements
+        {
+            persistent_elements.push((persistent_definition.id.as_str(), persistent_definition.to_persistent_elements::<PageId, ButtonId>()?));
+        }
+
+        let mut page_builders: Vec<PageWithInputFn<PageId, ButtonId>> = Vec::new();
+        for page_definition in &self.pages
+        {
+            let (page, input_bindings) = page_definition.to_page::<PageId, ButtonId>(&persistent_elements)?;
+            page_builders.push(Rc::new(move |user_input: &mut Vec<String>| {
+                let mut page = page.clone();
+                if let Some(texts) = &mut page.texts
+                {
+                    for (text_index, input_index) in &input_bindings.texts
+                    {
+                        texts[*text_index].2 = user_input.get_or_create(*input_index);
+                    }
+                }
+                if let Some(text_boxes) = &mut page.text_boxes
+                {
+                    for (text_box_index, input_index) in &input_bindings.text_boxes
+                    {
+                        text_boxes[*text_box_index].text = user_input.get_or_create(*input_index);
+                    }
+                }
+                page
+            }));
+        }
+        Ok(page_builders)
+    }
+}
+
+impl<PageId: IdFromName + Copy + Eq + Debug + 'static, ButtonId: IdFromName + Copy + Eq + Debug + 'static> PageData<PageId, ButtonId>
+{
+    /// Link Every Page Of The Given Files (Replacing Already Linked Pages With The Same Id, With Or Without Input)
+    /// And Watch The Files For `reload_changed_page_files`
+    pub fn load_page_files<P: AsRef<Path>>(&mut self, paths: &[P]) -> Result<(), String>
+    {
+        for path in paths
+        {
+            let path = path.as_ref().to_path_buf();
+            let modified = file_modified(&path);
+            let page_ids = self.link_page_file(&path, &[])?;
+            self.watched_page_files.retain(|watched_page_file| watched_page_file.path != path);
+            self.watched_page_files.push(WatchedPageFile { path, modified, page_ids });
+        }
+        Ok(())
+    }
+
+    /// Poll The Modification Time Of Every Watched File And Relink The Pages Of The Changed Ones.
+    /// User input and page history are kept, returns `true` when at least one file was reloaded.
+    /// A file that fails to load keeps its previous pages and is retried on its next change.
+    pub fn reload_changed_page_files(&mut self) -> Result<bool, String>
+    {
+        let mut reloaded = false;
+        for index in 0..self.watched_page_files.len()
+        {
+            let modified = file_modified(&self.watched_page_files[index].path);
+            if modified == self.watched_page_files[index].modified
+            {
+                continue;
+            }
+            self.watched_page_files[index].modified = modified;
+            let path = self.watched_page_files[index].path.clone();
+            let previous_page_ids = self.watched_page_files[index].page_ids.clone();
+            self.watched_page_files[index].page_ids = self.link_page_file(&path, &previous_page_ids)?;
+            reloaded = true;
+        }
+        Ok(reloaded)
+    }
+
+    /// Unlink The Pages Previously Loaded From `path` And Link The Ones It Contains Now
+    fn link_page_file(&mut self, path: &Path, previous_page_ids: &[PageId]) -> Result<Vec<PageId>, String>
+    {
+        let page_builders = PageFile::load(path)?.input_linked_pages::<PageId, ButtonId>()?;
+        let mut page_ids = Vec::new();
+        self.page_w_input_linked.retain(|(page_id, _)| !previous_page_ids.contains(page_id));
+        for page_builder in page_builders
+        {
+            let page_id = page_builder(&mut self.vec_user_input_string).id;
+            // create_current_page looks in page_linked first, a code page with the same id would hide the file one
+            self.page_linked.retain(|(linked_page_id, _)| *linked_page_id != page_id);
+            match self.page_w_input_linked.iter_mut().find(|(linked_page_id, _)| *linked_page_id == page_id)
+            {
+                Some(linked_page) => linked_page.1 = page_builder,
+                None => self.page_w_input_linked.push((page_id, page_builder))
+            }
+            page_ids.push(page_id);
+        }
+        Ok(page_ids)
+    }
+}
+
+/// (element index, user input index) Pairs Filled In Every Time A Page From A File Is Built
+struct InputBindings
+{
+    texts: Vec<(usize, usize)>,
+    text_boxes: Vec<(usize, usize)>
+}
+
+fn file_modified(path: &Path) -> Option<SystemTime>
+{
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+impl PageDefinition
+{
+    /// Returns The Page And The User Inputs To Fill In When It Is Built
+    #[allow(clippy::type_complexity)]
+    fn to_page<PageId: IdFromName + Copy + 'static, ButtonId: IdFromName + Copy + 'static>(&self, persistent_elements: &[(&str, PersistentElements<PageId, ButtonId>)]) -> Result<(Page<PageId, ButtonId>, InputBindings), String>
+    {
+        let page_id: PageId = resolve_name(&self.id, "page")?;
+
+        let mut has_persistent_elements: Vec<(PageId, Rc<dyn Fn() -> PersistentElements<PageId, ButtonId>>)> = Vec::new();
+        for persistent_name in &self.persistent_elements
+        {
+            let Some((_, persistent)) = persistent_elements.iter().find(|(name, _)| name == persistent_name)
+            else
+            {
+                return Err(format!("Page '{}' uses unknown persistent elements '{}'", self.id, persistent_name));
+            };
+            let persistent = persistent.clone();
+            has_persistent_elements.push((persistent.id, Rc::new(move || persistent.clone())));
+        }
+
+        let mut has_userinput = Vec::new();
+        for button_name in &self.user_input
+        {
+            has_userinput.push((page_id, resolve_name::<ButtonId>(button_name, "button")?));
+        }
+        let mut has_text_area = Vec::new();
+        for button_name in &self.text_area
+        {
+            if !self.user_input.contains(button_name)
+            {
+                return Err(format!("Page '{}' declares text area '{}' which is not in its user_input", self.id, button_name));
+            }
+            has_text_area.push((page_id, resolve_name::<ButtonId>(button_name, "button")?));
+        }
+
+        let mut has_input_rules = Vec::new();
+        for input_rules in &self.input_rules
+        {
+            if !self.user_input.contains(&input_rules.id)
+            {
+                return Err(format!("Page '{}' declares input rules for '{}' which is not in its user_input", self.id, input_rules.id));
+            }
+            has_input_rules.push((page_id, resolve_name::<ButtonId>(&input_rules.id, "button")?, input_rules.to_input_rules()?));
+        }
+
+        if let Some(button_name) = self.text_boxes.iter().filter_map(|text_box| text_box.input.as_ref()).find(|button_name| !self.user_input.contains(button_name))
+        {
+            return Err(format!("Page '{}' has a text box for input '{}' which is not in its user_input", self.id, button_name));
+        }
+
+        let input_bindings = InputBindings { texts: self.texts.iter().enumerate().filter_map(|(text_index, text)| text.user_input.map(|input_index| (text_index, input_index))).collect(), text_boxes: self.text_boxes.iter().enumerate().filter_map(|(text_box_index, text_box)| text_box.user_input.map(|input_index| (text_box_index, input_index))).collect() };
+        let page = Page { has_persistent_elements: non_empty(has_persistent_elements), has_userinput: non_empty(has_userinput), has_text_area: non_empty(has_text_area), has_input_rules: non_empty(has_input_rules), has_scroll_regions: None, has_widgets: None, id: page_id, background_color: self.background_color.as_deref().map(to_color).transpose()?, rects: to_rects(&self.rects)?, buttons: to_buttons(&self.buttons)?, texts: to_texts(&self.texts)?, text_boxes: to_text_boxes(&self.text_boxes)?, images: to_images(&self.images) };
+        Ok((page, input_bindings))
+    }
+}
+
+impl InputRulesDefinition
+{
+    fn to_input_rules(&self) -> Result<InputRules, String>
+    {
+        let char_filter = self.char_filter.map(|char_filter| match char_filter
+        {
+            CharFilterDefinition::Digits => CharFilter::Digits,
+            CharFilterDefinition::Hex => CharFilter::Hex,
+            CharFilterDefinition::Alphanumeric => CharFilter::Alphanumeric
+        });
+        #[cfg(feature = "regex-validation")]
+        let validators = self.regex.iter().map(|(pattern, error_message)| regex_validator(pattern, error_message)).collect::<Result<Vec<_>, String>>()?;
+        #[cfg(not(feature = "regex-validation"))]
+        let validators = match &self.regex
+        {
+            Some((pattern, _)) => return Err(format!("Input rules of '{}' use the regex '{}' but the regex-validation feature is off", self.id, pattern)),
+            None => Vec::new()
+        };
+        Ok(InputRules { max_length: self.max_length, char_filter, validators, mask: self.mask, placeholder: self.placeholder.clone() })
+    }
+}
+
+impl PersistentElementsDefinition
+{
+    fn to_persistent_elements<PageId: IdFromName, ButtonId: IdFromName>(&self) -> Result<PersistentElements<PageId, ButtonId>, String>
+    {
+        if self.texts.iter().any(|text| text.user_input.is_some()) || self.text_boxes.iter().any(|text_box| text_box.user_input.is_some() || text_box.input.is_some())
+        {
+            return Err(format!("Persistent elements '{}' can't show user input", self.id));
+        }
+        Ok(PersistentElements { id: resolve_name(&self.id, "persistent elements")?, background_color: self.background_color.as_deref().map(to_color).transpose()?, rects: to_rects(&self.rects)?, buttons: to_buttons(&self.buttons)?, texts: to_texts(&self.texts)?, text_boxes: to_text_boxes(&self.text_boxes)?, images: to_images(&self.images) })
+    }
+}
+
+fn resolve_name<Id: IdFromName>(name: &str, kind: &str) -> Result<Id, String>
+{
+    Id::from_name(name).ok_or(format!("Unknown {} id '{}'", kind, name))
+}
+
+fn non_empty<T>(elements: Vec<T>) -> Option<Vec<T>>
+{
+    if elements.is_empty() { None } else { Some(elements) }
+}
+
+fn to_color(channels: &[u8]) -> Result<Color, String>
+{
+    match channels
+    {
+        [r, g, b] => Ok(Color::RGB(*r, *g, *b)),
+        [r, g, b, a] => Ok(Color::RGBA(*r, *g, *b, *a)),
+        _ => Err(format!("Colors need 3 or 4 channels, got {:?}", channels))
+    }
+}
+
+fn to_optional_color(channels: &Option<Vec<u8>>) -> Result<Option<Color>, String>
+{
+    channels.as_deref().map(to_color).transpose()
+}
+
+fn to_rects(rect_definitions: &[RectDefinition]) -> Result<Rects, String>
+{
+    let rects = rect_definitions.iter().map(|rect| Ok((to_color(&rect.color)?, (Rect::new(rect.rect.0, rect.rect.1, rect.rect.2, rect.rect.3), rect.radius)))).collect::<Result<Vec<_>, String>>()?;
+    Ok(non_empty(rects))
+}
+
+fn to_buttons<ButtonId: IdFromName>(button_definitions: &[ButtonDefinition]) -> Result<Buttons<ButtonId>, String>
+{
+    let mut buttons = Vec::new();
+    for button in button_definitions
+    {
+        let has_transition = button.transition.as_ref().map(|transition| match transition
+        {
+            TransitionDefinition::Fade => TransitionType::Fade(0.),
+            TransitionDefinition::Slide(slide_pos, speed) => TransitionType::Slide(0., *slide_pos, *speed)
+        });
+        let style = match &button.style
+        {
+            Some(style) => Some(ButtonStyle { hover_color: to_optional_color(&style.hover_color)?, pressed_color: to_optional_color(&style.pressed_color)?, focused_color: to_optional_color(&style.focused_color)?, disabled_color: to_optional_color(&style.disabled_color)?, border: style.border.as_ref().map(|(color, width)| Ok::<_, String>((to_color(color)?, *width))).transpose()? }),
+            None => None
+        };
+        buttons.push(Button { enabled: button.enabled, color: to_color(&button.color)?, rect: Rect::new(button.rect.0, button.rect.1, button.rect.2, button.rect.3), radius: button.radius, id: resolve_name(&button.id, "button")?, has_transition, tab_index: button.tab_index, style, tooltip: button.tooltip.as_deref().map(Rc::from) });
+    }
+    Ok(non_empty(buttons))
+}
+
+fn to_texts(text_definitions: &[TextDefinition]) -> Result<Texts, String>
+{
+    let texts = text_definitions.iter().map(|text| Ok((text.size, text.position, text.text.clone(), to_color(&text.color)?))).collect::<Result<Vec<_>, String>>()?;
+    Ok(non_empty(texts))
+}
+
+fn to_text_boxes<ButtonId: IdFromName>(text_box_definitions: &[TextBoxDefinition]) -> Result<TextBoxes<ButtonId>, String>
+{
+    let text_boxes = text_box_definitions.iter().map(|text_box| Ok(TextBox { text: text_box.text.clone(), font_size: text_box.size, color: to_color(&text_box.color)?, rect: Rect::new(text_box.rect.0, text_box.rect.1, text_box.rect.2, text_box.rect.3), horizontal_align: text_box.horizontal_align, vertical_align: text_box.vertical_align, max_width: text_box.max_width, clip_rect: text_box.clip_rect.map(|(x, y, w, h)| Rect::new(x, y, w, h)), input: text_box.input.as_deref().map(|button_name| resolve_name(button_name, "button")).transpose()? })).collect::<Result<Vec<_>, String>>()?;
+    Ok(non_empty(text_boxes))
+}
+
+fn to_images(image_definitions: &[ImageDefinition]) -> Images
+{
+    non_empty(image_definitions.iter().map(|image| (image.position, image.size, image.path.clone())).collect())
+}
@@ -1,10 +1,16 @@
 use crate::{
     AppState,
-    system::{scene_transition::TransitionType, window::WINDOW_DEFAULT_SCALE}
+    system::{
+        input_rules::InputRules,
+        scene_transition::TransitionType,
+        scroll::{ScrollRegion, ScrollState, scroll_placement},
+        text_layout::{HorizontalAlign, VerticalAlign},
+        widgets::{Widget, WidgetKind, WidgetValue}
+    }
 };
 use sdl3::{pixels::Color, rect::Rect};
 use std::rc::Rc;
-use std::{collections::VecDeque, fmt::Debug};
+use std::{collections::VecDeque, fmt::Debug, time::Duration};
 
 /// Type for optional list of persistent elements factories.
 /// Each entry associates a `PageId` with a boxed closure that constructs
@@ -21,10 +27,11 @@ use std::{collections::VecDeque, fmt::Debug};
 // instead of plain function pointers allows callers to capture additional
 // data via the closure, enabling more flexible APIs.
 type PersistentElementsType<PageId, ButtonId> = Option<Vec<(PageId, Rc<dyn Fn() -> PersistentElements<PageId, ButtonId>>)>>;
-type Rects = Option<Vec<(Color, (Rect, i32))>>;
-type Texts = Option<Vec<(f64, (i32, i32), String, Color)>>;
+pub type Rects = Option<Vec<(Color, (Rect, i32))>>;
+pub type Texts = Option<Vec<(f64, (i32, i32), String, Color)>>;
 pub type Buttons<ButtonId> = Option<Vec<Button<ButtonId>>>;
-type Images = Option<Vec<((i32, i32), (u32, u32), String)>>;
+pub type Images = Option<Vec<((i32, i32), (u32, u32), String)>>;
+pub type TextBoxes<ButtonId> = Option<Vec<TextBox<ButtonId>>>;
 // Note: we intentionally avoid deriving `PartialEq`, `Debug`, or `Clone` for `Page` because
 // closures stored in `PersistentElementsType` cannot implement these traits. If needed,
 // custom implementations can be provided for debugging or comparison.
@@ -32,21 +39,48 @@ pub struct Page<PageId, ButtonId>
 {
     pub has_persistent_elements: PersistentElementsType<PageId, ButtonId>,
     pub has_userinput: Option<Vec<(PageId, ButtonId)>>,
+    /// Inputs (also listed in has_userinput) edited as multi-line text areas, where Return inserts a new line
+    pub has_text_area: Option<Vec<(PageId, ButtonId)>>,
+    /// Max length, allowed characters, validators and mask of inputs listed in has_userinput
+    pub has_input_rules: Option<Vec<(PageId, ButtonId, InputRules)>>,
+    /// Areas that clip and scroll the elements laid out inside them, with the mouse wheel, the keyboard or their scrollbar
+    pub has_scroll_regions: Option<Vec<ScrollRegion<ButtonId>>>,
+    /// Buttons of the page drawn and used as checkboxes, toggles, radios, sliders or progress bars
+    pub has_widgets: Option<Vec<(PageId, ButtonId, Widget)>>,
     pub id: PageId,
     pub background_color: Option<Color>,
     pub rects: Rects,
     pub buttons: Buttons<ButtonId>,
     pub texts: Texts,
+    pub text_boxes: TextBoxes<ButtonId>,
     pub images: Images
 }
 
+impl<PageId, ButtonId> Page<PageId, ButtonId>
+{
+    /// Empty Page Without Any Element, Complete It With The Struct Update Syntax:
+    /// `Page { buttons: Some(all_buttons), texts: Some(all_text), ..Page::new(PageId::Page1) }`
+    pub fn new(id: PageId) -> Self
+    {
+        Self { has_persistent_elements: None, has_userinput: None, has_text_area: None, has_input_rules: None, has_scroll_regions: None, has_widgets: None, id, background_color: None, rects: None, buttons: None, texts: None, text_boxes: None, images: None }
+    }
+}
+
+impl<PageId: Default, ButtonId> Default for Page<PageId, ButtonId>
+{
+    fn default() -> Self
+    {
+        Self::new(PageId::default())
+    }
+}
+
 // We can clone `Page` because all of its fields implement `Clone` when closures are stored
 // in `Rc`. Deriving `Clone` enables cloning of pages for caching and rendering purposes.
 impl<PageId: Clone, ButtonId: Clone> Clone for Page<PageId, ButtonId>
 {
     fn clone(&self) -> Self
     {
-        Self { has_persistent_elements: self.has_persistent_elements.clone(), has_userinput: self.has_userinput.clone(), id: self.id.clone(), background_color: self.background_color, rects: self.rects.clone(), buttons: self.buttons.clone(), texts: self.texts.clone(), images: self.images.clone() }
+        Self { has_persistent_elements: self.has_persistent_elements.clone(), has_userinput: self.has_userinput.clone(), has_text_area: self.has_text_area.clone(), has_input_rules: self.has_input_rules.clone(), has_scroll_regions: self.has_scroll_regions.clone(), has_widgets: self.has_widgets.clone(), id: self.id.clone(), background_color: self.background_color, rects: self.rects.clone(), buttons: self.buttons.clone(), texts: self.texts.clone(), text_boxes: self.text_boxes.clone(), images: self.images.clone() }
     }
 }
 pub struct PersistentElements<PageId, ButtonId>
@@ -56,6 +90,7 @@ pub struct PersistentElements<PageId, ButtonId>
     pub rects: Rects,
     pub buttons: Buttons<ButtonId>,
     pub texts: Texts,
+    pub text_boxes: TextBoxes<ButtonId>,
     pub images: Images
 }
 
@@ -64,7 +99,7 @@ impl<PageId: Clone, ButtonId: Clone> Clone for PersistentElements<PageId, Button
 {
     fn clone(&self) -> Self
     {
-        Self { id: self.id.clone(), background_color: self.background_color, rects: self.rects.clone(), buttons: self.buttons.clone(), texts: self.texts.clone(), images: self.images.clone() }
+        Self { id: self.id.clone(), background_color: self.background_color, rects: self.rects.clone(), buttons: self.buttons.clone(), texts: self.texts.clone(), text_boxes: self.text_boxes.clone(), images: self.images.clone() }
     }
 }
 
@@ -75,13 +110,24 @@ type PageLinked<PageId, ButtonId> = Vec<(PageId, Rc<dyn Fn() -> Page<PageId, But
 pub struct PageData<PageId, ButtonId>
 {
     pub vec_user_input: Vec<(PageId, ButtonId, String)>,
+    /// Same order as vec_user_input, with masked inputs already masked, this is what pages get to show
     pub vec_user_input_string: Vec<String>,
+    /// Rules of every input, registered when their page is linked or created
+    pub input_rules: Vec<(PageId, ButtonId, InputRules)>,
+    /// Offset of every scroll region that was scrolled, kept when leaving its page
+    pub scroll_states: Vec<(PageId, ButtonId, ScrollState)>,
+    /// Widgets of every page, registered when their page is linked or created
+    pub widgets: Vec<(PageId, ButtonId, Widget)>,
+    /// Value of every registered widget, kept when leaving its page
+    pub widget_values: Vec<(PageId, ButtonId, WidgetValue)>,
     pub page_history: (VecDeque<PageId>, usize),
     pub page_linked: PageLinked<PageId, ButtonId>,
     pub page_w_input_linked: PageInputLinked<PageId, ButtonId>,
     pub page_to_render: Option<Page<PageId, ButtonId>>,
     pub persistent_elements_to_render: Option<Vec<PersistentElements<PageId, ButtonId>>>,
-    pub forced_persistent_elements: Option<Vec<PersistentElements<PageId, ButtonId>>>
+    pub forced_persistent_elements: Option<Vec<PersistentElements<PageId, ButtonId>>>,
+    #[cfg(feature = "page-files")]
+    pub watched_page_files: Vec<crate::system::page_file::WatchedPageFile<PageId>>
 }
 
 // Implement `Clone` for `PageData` as well. This clones the vectors of pages and persistent elements.
@@ -89,7 +135,22 @@ impl<PageId: Clone, ButtonId: Clone> Clone for PageData<PageId, ButtonId>
 {
     fn clone(&self) -> Self
     {
-        Self { vec_user_input: self.vec_user_input.clone(), vec_user_input_string: self.vec_user_input_string.clone(), page_history: self.page_history.clone(), page_linked: self.page_linked.clone(), page_w_input_linked: self.page_w_input_linked.clone(), page_to_render: self.page_to_render.clone(), persistent_elements_to_render: self.persistent_elements_to_render.clone(), forced_persistent_elements: None }
+        Self {
+            vec_user_input: self.vec_user_input.clone(),
+            vec_user_input_string: self.vec_user_input_string.clone(),
+            input_rules: self.input_rules.clone(),
+            scroll_states: self.scroll_states.clone(),
+            widgets: self.widgets.clone(),
+            widget_values: self.widget_values.clone(),
+            page_history: self.page_history.clone(),
+            page_linked: self.page_linked.clone(),
+            page_w_input_linked: self.page_w_input_linked.clone(),
+            page_to_render: self.page_to_render.clone(),
+            persistent_elements_to_render: self.persistent_elements_to_render.clone(),
+            forced_persistent_elements: None,
+            #[cfg(feature = "page-files")]
+            watched_page_files: self.watched_page_files.clone()
+        }
     }
 }
 impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, ButtonId>
@@ -97,7 +158,22 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
     /// Define PageData Default  Config
     pub fn new(app_state: &AppState<PageId, ButtonId>) -> Self
     {
-        Self { vec_user_input: Vec::new(), vec_user_input_string: Vec::new(), persistent_elements_to_render: None, page_history: (VecDeque::from([app_state.current_page]), 0), page_linked: Vec::new(), page_w_input_linked: Vec::new(), page_to_render: None, forced_persistent_elements: None }
+        Self {
+            vec_user_input: Vec::new(),
+            vec_user_input_string: Vec::new(),
+            input_rules: Vec::new(),
+            scroll_states: Vec::new(),
+            widgets: Vec::new(),
+            widget_values: Vec::new(),
+            persistent_elements_to_render: None,
+            page_history: (VecDeque::from([app_state.current_page]), 0),
+            page_linked: Vec::new(),
+            page_w_input_linked: Vec::new(),
+            page_to_render: None,
+            forced_persistent_elements: None,
+            #[cfg(feature = "page-files")]
+            watched_page_files: Vec::new()
+        }
     }
 
     ///Link The Page With Your Determined PageId
@@ -108,8 +184,10 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
             let mut page_linked_to_send = Vec::new();
             for page in page_linked_received
             {
-                let page_id = page().id;
-                page_linked_to_send.push((page_id, page));
+                let created_page = page();
+                self.register_input_rules(&created_page);
+                self.register_widgets(&created_page);
+                page_linked_to_send.push((created_page.id, page));
             }
             self.page_linked = page_linked_to_send;
         };
@@ -119,13 +197,66 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
             let mut page_w_input_linked_to_send = Vec::new();
             for page_w_input in page_w_input_linked_received
             {
-                let page_id = page_w_input(&mut self.vec_user_input_string).id;
-                page_w_input_linked_to_send.push((page_id, page_w_input));
+                let created_page = page_w_input(&mut self.vec_user_input_string);
+                self.register_input_rules(&created_page);
+                self.register_widgets(&created_page);
+                page_w_input_linked_to_send.push((created_page.id, page_w_input));
             }
             self.page_w_input_linked = page_w_input_linked_to_send;
         };
     }
 
+    /// Save (Or Replace) The Input Rules Declared By A Page, So Masks Apply Before The Page Is Shown
+    fn register_input_rules(&mut self, page: &Page<PageId, ButtonId>)
+    {
+        if let Some(has_input_rules) = &page.has_input_rules
+        {
+            for (page_id, button_id, rules) in has_input_rules
+            {
+                self.input_rules.retain(|(registered_page_id, registered_button_id, _)| !(registered_page_id == page_id && registered_button_id == button_id));
+                self.input_rules.push((*page_id, *button_id, rules.clone()));
+            }
+        }
+    }
+
+    /// Save (Or Replace) The Widgets Declared By A Page, Giving The New Ones Their Default Value
+    fn register_widgets(&mut self, page: &Page<PageId, ButtonId>)
+    {
+        if let Some(has_widgets) = &page.has_widgets
+        {
+            for (page_id, button_id, widget) in has_widgets
+            {
+                self.widgets.retain(|(registered_page_id, registered_button_id, _)| !(registered_page_id == page_id && registered_button_id == button_id));
+                self.widgets.push((*page_id, *button_id, *widget));
+                match self.widget_values.iter_mut().find(|(value_page_id, value_button_id, _)| value_page_id == page_id && value_button_id == button_id)
+                {
+                    // A redeclared widget keeps its value, brought back within what it can hold now
+                    Some((_, _, value)) => *value = widget.normalize(*value),
+                    None => self.widget_values.push((*page_id, *button_id, widget.default_value()))
+                }
+            }
+        }
+    }
+
+    /// Rules Of The Input `button_id` On `page_id`, If It Has Any
+    pub fn input_rules_of(&self, page_id: PageId, button_id: ButtonId) -> Option<&InputRules>
+    {
+        self.input_rules.iter().find(|(registered_page_id, registered_button_id, _)| *registered_page_id == page_id && *registered_button_id == button_id).map(|(_, _, rules)| rules)
+    }
+
+    /// Why The Current Text Of An Input Breaks Its Rules (None When It Is Valid Or Has No Rules), For Error Styling Or Blocking A Submit
+    pub fn input_error(&self, page_id: PageId, button_id: ButtonId) -> Option<String>
+    {
+        let rules = self.input_rules_of(page_id, button_id)?;
+        let (_, _, text) = self.vec_user_input.iter().find(|(input_page_id, input_button_id, _)| *input_page_id == page_id && *input_button_id == button_id)?;
+        rules.validate(text).err()
+    }
+
+    pub fn is_input_valid(&self, page_id: PageId, button_id: ButtonId) -> bool
+    {
+        self.input_error(page_id, button_id).is_none()
+    }
+
     /// Create The Page Setted In THe AppState current_page
     pub fn create_current_page(&mut self, app_state: &mut AppState<PageId, ButtonId>)
     {
@@ -194,6 +325,8 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
     /// Populate vec_user_input per page
     pub fn push_vec_user_input_per_page(&mut self, page: &mut Page<PageId, ButtonId>)
     {
+        self.register_input_rules(page);
+        self.register_widgets(page);
         if let Some(has_userinput) = &page.has_userinput
         {
             for (pageid, buttonid) in has_userinput
@@ -212,10 +345,14 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
     pub fn push_vec_user_input_per_vec(&mut self)
     {
         let mut changed = false;
-        for tuple_page in &self.page_w_input_linked
+        // Cloned (only the Rc pointers) so the rules can be registered while going through the pages
+        for tuple_page in self.page_w_input_linked.clone()
         {
             // call the boxed closure via deref to construct the page and inspect its input bindings
             let page = (*tuple_page.1)(&mut self.vec_user_input_string);
+            self.register_input_rules(&page);
+            self.register_widgets(&page);
+            changed |= page.has_input_rules.is_some();
             if let Some(has_userinput) = &page.has_userinput
             {
                 for (pageid, buttonid) in has_userinput
@@ -238,7 +375,13 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
     /// Update vec_user_input_string
     pub fn update_vec_user_input_string(&mut self)
     {
-        self.vec_user_input_string = self.vec_user_input.iter().map(|(_, _, s)| s.to_string()).collect();
+        self.vec_user_input_string = self.vec_user_input.iter().map(|(page_id, button_id, s)| self.input_rules_of(*page_id, *button_id).map_or_else(|| s.to_string(), |rules| rules.display_text(s))).collect();
+    }
+
+    /// Check If The Input Of `button_id` On The Current Page Is A Multi-Line Text Area
+    pub fn is_text_area(&self, app_state: &AppState<PageId, ButtonId>, button_id: ButtonId) -> bool
+    {
+        self.page_to_render.as_ref().and_then(|page| page.has_text_area.as_ref()).is_some_and(|text_areas| text_areas.contains(&(app_state.current_page, button_id)))
     }
 
     /// Returns the button ID under the cursor (if any)
@@ -246,10 +389,7 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
     /// respecting visual layering (persistent fully blocks below)
     pub fn page_button_at(&self, app_state: &AppState<PageId, ButtonId>, mouse_x: f32, mouse_y: f32) -> Option<ButtonId>
     {
-        let window_size = app_state.window_size;
-        let stretch = app_state.stretch_mode_is_on;
-
-        let (mx, my) = if stretch { (mouse_x * (WINDOW_DEFAULT_SCALE.0 as f32 / window_size.0 as f32), mouse_y * (WINDOW_DEFAULT_SCALE.1 as f32 / window_size.1 as f32)) } else { (mouse_x, mouse_y) };
+        let (mx, my) = app_state.logical_mouse_position((mouse_x, mouse_y));
 
         let inside = |r: &Rect| -> bool { mx >= r.x as f32 && mx <= (r.x + r.w) as f32 && my >= r.y as f32 && my <= (r.y + r.h) as f32 };
 
@@ -361,12 +501,25 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
         // === 2. Page elements (below persistent) ===
         if let Some(page) = &self.page_to_render
         {
+            // Elements inside scroll regions are hit where they are shown, and only within the region
+            let scrolled_regions = self.scrolled_regions();
+            let inside_scrolled = |rect: Rect| {
+                let (shown_rect, clip_rect) = scroll_placement(&scrolled_regions, rect);
+                inside(&shown_rect) && clip_rect.is_none_or(|clip_rect| inside(&clip_rect))
+            };
+
+            // Scrollbars (block clicks)
+            if scrolled_regions.iter().filter_map(|(scroll_region, _)| scroll_region.track_rect()).any(|track_rect| inside(&track_rect))
+            {
+                return None;
+            }
+
             // Buttons (clickable)
             if let Some(buttons) = &page.buttons
             {
                 for button in buttons.iter().rev()
                 {
-                    if inside(&button.rect)
+                    if inside_scrolled(button.rect)
                     {
                         if button.enabled
                         {
@@ -385,7 +538,7 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
             {
                 for (_, (rect, _)) in rects.iter().rev()
                 {
-                    if inside(rect)
+                    if inside_scrolled(*rect)
                     {
                         return None;
                     }
@@ -397,7 +550,7 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
             {
                 for (pos, size, _) in images.iter().rev()
                 {
-                    if inside(&Rect::new(pos.0, pos.1, size.0, size.1))
+                    if inside_scrolled(Rect::new(pos.0, pos.1, size.0, size.1))
                     {
                         return None;
                     }
@@ -407,9 +560,227 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> PageData<PageId, Bu
 
         None
     }
+
+    /// Enabled Buttons Of The Current Page And Its Persistent Elements In Tab Order:
+    /// the ones with a tab_index first (lowest first), then the others in declaration order
+    pub fn focus_order(&self) -> Vec<ButtonId>
+    {
+        let mut focusable = self.focusable_buttons();
+        // Stable, so equal tab indexes keep their declaration order
+        focusable.sort_by_key(|button| button.tab_index.unwrap_or(u32::MAX));
+        focusable.into_iter().map(|button| button.id).collect()
+    }
+
+    /// The Closest Button In `direction` ((1, 0) Is Right, (0, 1) Is Down) From The Center Of `current`, For Gamepad Navigation.
+    /// Buttons straight ahead win over closer ones off to the side, the focus stays put when there is nothing that way
+    pub fn focus_towards(&self, current: Option<ButtonId>, direction: (i32, i32)) -> Option<ButtonId>
+    {
+        let focusable = self.focusable_buttons();
+        let center = |button: &Button<ButtonId>| (button.rect.x() + button.rect.width() as i32 / 2, button.rect.y() + button.rect.height() as i32 / 2);
+        let Some(current_button) = current.and_then(|current| focusable.iter().find(|button| button.id == current))
+        else
+        {
+            return self.focus_order().first().copied();
+        };
+        let current_center = center(current_button);
+        let closest = focusable.iter().filter(|button| button.id != current_button.id).filter_map(|button| {
+            let button_center = center(button);
+            let offset = (button_center.0 - current_center.0, button_center.1 - current_center.1);
+            let along = offset.0 * direction.0 + offset.1 * direction.1;
+            let across = (offset.0 * direction.1 - offset.1 * direction.0).abs();
+            (along > 0).then_some((along + 2 * across, button.id))
+        });
+        Some(closest.min_by_key(|(distance, _)| *distance).map_or(current_button.id, |(_, button_id)| button_id))
+    }
+
+    /// Enabled Buttons Of The Current Page And Its Persistent Elements, In Declaration Order
+    fn focusable_buttons(&self) -> Vec<&Button<ButtonId>>
+    {
+        let persistent_buttons = self.persistent_elements_to_render.iter().chain(&self.forced_persistent_elements).flatten().filter_map(|persistent| persistent.buttons.as_ref());
+        self.page_to_render.iter().filter_map(|page| page.buttons.as_ref()).chain(persistent_buttons).flatten().filter(|button| button.enabled).collect()
+    }
+
+    /// The Button Focused After (Or Before, With `backwards`) `current`, Wrapping Around.
+    /// Starts at the first (or last) button when nothing or a button not in the focus order is focused
+    pub fn next_focus(&self, current: Option<ButtonId>, backwards: bool) -> Option<ButtonId>
+    {
+        let focus_order = self.focus_order();
+        let len = focus_order.len();
+        if len == 0
+        {
+            return None;
+        }
+        let next_index = match current.and_then(|current| focus_order.iter().position(|button_id| *button_id == current))
+        {
+            Some(current_index) if backwards => (current_index + len - 1) % len,
+            Some(current_index) => (current_index + 1) % len,
+            None if backwards => len - 1,
+            None => 0
+        };
+        Some(focus_order[next_index])
+    }
+}
+
+impl<PageId: Copy + Eq, ButtonId: Copy + Eq> PageData<PageId, ButtonId>
+{
+    /// Scroll Regions Of The Current Page With Their Offset In Whole Pixels
+    pub fn scrolled_regions(&self) -> Vec<(ScrollRegion<ButtonId>, i32)>
+    {
+        let Some(page) = &self.page_to_render
+        else
+        {
+            return Vec::new();
+        };
+        page.has_scroll_regions.iter().flatten().map(|scroll_region| (*scroll_region, self.scroll_offset(page.id, scroll_region.id).clamp(0., scroll_region.max_offset()).round() as i32)).collect()
+    }
+
+    /// How Far The Scroll Region `region_id` Of `page_id` Is Scrolled Down, 0 Until It Is Scrolled
+    pub fn scroll_offset(&self, page_id: PageId, region_id: ButtonId) -> f32
+    {
+        self.scroll_states.iter().find(|(state_page_id, state_region_id, _)| *state_page_id == page_id && *state_region_id == region_id).map_or(0., |(_, _, scroll_state)| scroll_state.offset)
+    }
+
+    /// The Topmost Scroll Region Of The Current Page Under The Mouse
+    pub fn scroll_region_at(&self, app_state: &AppState<PageId, ButtonId>, mouse_x: f32, mouse_y: f32) -> Option<ScrollRegion<ButtonId>>
+    {
+        let (mx, my) = app_state.logical_mouse_position((mouse_x, mouse_y));
+        self.page_to_render.as_ref()?.has_scroll_regions.iter().flatten().rev().find(|scroll_region| scroll_region.rect.contains_point((mx as i32, my as i32))).copied()
+    }
+
+    /// The Scroll Region Whose Scrollbar Is Under The Mouse
+    pub fn scrollbar_at(&self, app_state: &AppState<PageId, ButtonId>, mouse_x: f32, mouse_y: f32) -> Option<ScrollRegion<ButtonId>>
+    {
+        let (mx, my) = app_state.logical_mouse_position((mouse_x, mouse_y));
+        self.page_to_render.as_ref()?.has_scroll_regions.iter().flatten().rev().find(|scroll_region| scroll_region.track_rect().is_some_and(|track_rect| track_rect.contains_point((mx as i32, my as i32)))).copied()
+    }
+
+    /// Scroll By `delta` Pixels (Positive Goes Down), Gliding There Over The Next Frames For Kinetic Regions
+    pub fn scroll_by(&mut self, page_id: PageId, scroll_region: &ScrollRegion<ButtonId>, delta: f32)
+    {
+        let max_offset = scroll_region.max_offset();
+        self.scroll_state_mut(page_id, scroll_region.id).scroll_by(delta, max_offset, scroll_region.kinetic)
+    }
+
+    /// Jump To `offset`, Clamped To The Content And Stopping Any Kinetic Glide
+    pub fn set_scroll_offset(&mut self, page_id: PageId, scroll_region: &ScrollRegion<ButtonId>, offset: f32)
+    {
+        let max_offset = scroll_region.max_offset();
+        *self.scroll_state_mut(page_id, scroll_region.id) = ScrollState { offset: offset.clamp(0., max_offset), velocity: 0. }
+    }
+
+    /// Scroll The Region Holding `button_id` (If Any) Just Enough To Show The Whole Button, Used When The Focus Moves To It
+    pub fn scroll_to_button(&mut self, button_id: ButtonId)
+    {
+        let Some(page) = &self.page_to_render
+        else
+        {
+            return;
+        };
+        let page_id = page.id;
+        let Some(button_rect) = page.buttons.iter().flatten().find(|button| button.id == button_id).map(|button| button.rect)
+        else
+        {
+            return;
+        };
+        let Some((scroll_region, offset)) = self.scrolled_regions().into_iter().rev().find(|(scroll_region, _)| scroll_region.content_rect().contains_point((button_rect.x(), button_rect.y())))
+        else
+        {
+            return;
+        };
+        let shown_y = button_rect.y() - offset;
+        if shown_y < scroll_region.rect.y()
+        {
+            self.set_scroll_offset(page_id, &scroll_region, (button_rect.y() - scroll_region.rect.y()) as f32);
+        }
+        else if shown_y + button_rect.height() as i32 > scroll_region.rect.bottom()
+        {
+            self.set_scroll_offset(page_id, &scroll_region, (button_rect.bottom() - scroll_region.rect.bottom()) as f32);
+        }
+    }
+
+    /// Move The Kinetic Scroll Regions Of The Current Page By The Time Elapsed Since The Last Step, Called By handle_input
+    pub fn step_kinetic_scroll(&mut self, elapsed: Duration)
+    {
+        let Some(page) = &self.page_to_render
+        else
+        {
+            return;
+        };
+        let page_id = page.id;
+        for scroll_region in page.has_scroll_regions.clone().into_iter().flatten()
+        {
+            if let Some((_, _, scroll_state)) = self.scroll_states.iter_mut().find(|(state_page_id, state_region_id, _)| *state_page_id == page_id && *state_region_id == scroll_region.id)
+            {
+                scroll_state.step(scroll_region.max_offset(), elapsed)
+            }
+        }
+    }
+
+    /// Widget Declared For `button_id` On `page_id`, If It Is One
+    pub fn widget_of(&self, page_id: PageId, button_id: ButtonId) -> Option<Widget>
+    {
+        self.widgets.iter().find(|(registered_page_id, registered_button_id, _)| *registered_page_id == page_id && *registered_button_id == button_id).map(|(_, _, widget)| *widget)
+    }
+
+    /// Current Value Of A Registered Widget
+    pub fn widget_value(&self, page_id: PageId, button_id: ButtonId) -> Option<WidgetValue>
+    {
+        self.widget_values.iter().find(|(value_page_id, value_button_id, _)| *value_page_id == page_id && *value_button_id == button_id).map(|(_, _, value)| *value)
+    }
+
+    /// Set A Widget Value (Clamped To What The Widget Holds), Unchecking The Other Radios Of Its Group When A Radio Is Checked.
+    /// Returns Every Widget Whose Value Changed With Its New Value (Empty When Nothing Did), Unregistered Widgets Are Left Alone
+    pub fn set_widget_value(&mut self, page_id: PageId, button_id: ButtonId, value: WidgetValue) -> Vec<(ButtonId, WidgetValue)>
+    {
+        let Some(widget) = self.widget_of(page_id, button_id)
+        else
+        {
+            return Vec::new();
+        };
+        let value = widget.normalize(value);
+        if self.widget_value(page_id, button_id) == Some(value)
+        {
+            return Vec::new();
+        }
+        let mut changed_widgets = Vec::new();
+        if let WidgetKind::Radio(group) = widget.kind
+            && value.is_checked()
+        {
+            for (radio_page_id, radio_button_id, _) in self.widgets.iter().filter(|(radio_page_id, radio_button_id, radio)| *radio_page_id == page_id && *radio_button_id != button_id && radio.kind == WidgetKind::Radio(group))
+            {
+                if let Some((_, _, radio_value)) = self.widget_values.iter_mut().find(|(value_page_id, value_button_id, _)| value_page_id == radio_page_id && value_button_id == radio_button_id)
+                    && radio_value.is_checked()
+                {
+                    *radio_value = WidgetValue::Checked(false);
+                    changed_widgets.push((*radio_button_id, WidgetValue::Checked(false)))
+                }
+            }
+        }
+        match self.widget_values.iter_mut().find(|(value_page_id, value_button_id, _)| *value_page_id == page_id && *value_button_id == button_id)
+        {
+            Some((_, _, widget_value)) => *widget_value = value,
+            None => self.widget_values.push((page_id, button_id, value))
+        }
+        changed_widgets.insert(0, (button_id, value));
+        changed_widgets
+    }
+
+    fn scroll_state_mut(&mut self, page_id: PageId, region_id: ButtonId) -> &mut ScrollState
+    {
+        let state_index = match self.scroll_states.iter().position(|(state_page_id, state_region_id, _)| *state_page_id == page_id && *state_region_id == region_id)
+        {
+            Some(state_index) => state_index,
+            None =>
+            {
+                self.scroll_states.push((page_id, region_id, ScrollState::default()));
+                self.scroll_states.len() - 1
+            }
+        };
+        &mut self.scroll_states[state_index].2
+    }
 }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Clone, Debug)]
 pub struct Button<ButtonId>
 {
     pub enabled: bool,
@@ -417,5 +788,169 @@ pub struct Button<ButtonId>
     pub rect: Rect,
     pub radius: i32,
     pub id: ButtonId,
-    pub has_transition: Option<TransitionType>
+    pub has_transition: Option<TransitionType>,
+    /// Place in the Tab order, buttons with one come first (lowest first) and the others follow in declaration order
+    pub tab_index: Option<u32>,
+    pub style: Option<ButtonStyle>,
+    /// Shown near the cursor once the mouse rests on the button for `Renderer::tooltip_delay`
+    pub tooltip: Option<Rc<str>>
+}
+
+impl<ButtonId> Button<ButtonId>
+{
+    /// Enabled Button With Square Corners And No Transition, Tab Index, Style Or Tooltip:
+    /// `Button { radius: 20, ..Button::new(ButtonId::ButtonPage1, PURPLE_COLOR, Rect::new(100, 150, 235, 40)) }`
+    pub fn new(id: ButtonId, color: Color, rect: Rect) -> Self
+    {
+        Self { enabled: true, color, rect, radius: 0, id, has_transition: None, tab_index: None, style: None, tooltip: None }
+    }
+
+    pub fn with_radius(self, radius: i32) -> Self
+    {
+        Self { radius, ..self }
+    }
+
+    pub fn with_transition(self, transition: TransitionType) -> Self
+    {
+        Self { has_transition: Some(transition), ..self }
+    }
+
+    pub fn with_tab_index(self, tab_index: u32) -> Self
+    {
+        Self { tab_index: Some(tab_index), ..self }
+    }
+
+    pub fn with_style(self, style: ButtonStyle) -> Self
+    {
+        Self { style: Some(style), ..self }
+    }
+
+    pub fn with_tooltip(self, tooltip: &str) -> Self
+    {
+        Self { tooltip: Some(tooltip.into()), ..self }
+    }
+
+    pub fn disabled(self) -> Self
+    {
+        Self { enabled: false, ..self }
+    }
+
+    /// Color To Draw The Button With In Its Current State, Falling Back To `color` Darkened By
+    /// `decrease_color_when_selected` (Twice When Pressed) Or Greyed Out When Disabled
+    pub fn state_color(&self, hovered: bool, pressed: bool, focused: bool, decrease_color_when_selected: Option<(u8, u8, u8)>) -> Color
+    {
+        let style = self.style.unwrap_or_default();
+        let darken = |color: Color, times: u8| match decrease_color_when_selected
+        {
+            Some((r, g, b)) => Color::RGB(color.r.saturating_sub(r.saturating_mul(times)), color.g.saturating_sub(g.saturating_mul(times)), color.b.saturating_sub(b.saturating_mul(times))),
+            None => color
+        };
+        if !self.enabled
+        {
+            let grey = ((self.color.r as u32 * 30 + self.color.g as u32 * 59 + self.color.b as u32 * 11) / 100) as u8;
+            style.disabled_color.unwrap_or(Color::RGBA(grey, grey, grey, self.color.a))
+        }
+        else if pressed
+        {
+            style.pressed_color.unwrap_or_else(|| darken(self.color, 2))
+        }
+        else if hovered
+        {
+            style.hover_color.unwrap_or_else(|| darken(self.color, 1))
+        }
+        else if focused
+        {
+            style.focused_color.unwrap_or_else(|| darken(self.color, 1))
+        }
+        else
+        {
+            self.color
+        }
+    }
+}
+
+impl<ButtonId: Default> Default for Button<ButtonId>
+{
+    fn default() -> Self
+    {
+        Self::new(ButtonId::default(), Color::RGB(0, 0, 0), Rect::new(0, 0, 0, 0))
+    }
+}
+
+/// Colors Of A Button In Each State, The Unset Ones Fall Back To Its Color (See `Button::state_color`):
+/// `Button { style: Some(ButtonStyle { hover_color: Some(PINK_COLOR), border: Some((WHITE_COLOR, 2)), ..ButtonStyle::default() }), ..button }`
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct ButtonStyle
+{
+    pub hover_color: Option<Color>,
+    /// While the left mouse button is held on the button
+    pub pressed_color: Option<Color>,
+    /// Focused with Tab or a gamepad
+    pub focused_color: Option<Color>,
+    pub disabled_color: Option<Color>,
+    /// Color and width in pixels, drawn inside the button rect
+    pub border: Option<(Color, u32)>
+}
+
+/// Text Aligned Inside A Bounding Rect, For Example A Label Centered On A Button:
+/// `TextBox { horizontal_align: HorizontalAlign::Center, vertical_align: VerticalAlign::Middle, ..TextBox::new("Page 1", 17.0, TEXT_COLOR, button.rect) }`
+#[derive(PartialEq, Clone, Debug)]
+pub struct TextBox<ButtonId = ()>
+{
+    pub text: String,
+    pub font_size: f64,
+    pub color: Color,
+    pub rect: Rect,
+    pub horizontal_align: HorizontalAlign,
+    pub vertical_align: VerticalAlign,
+    /// Lines wider than this are wrapped on word boundaries (breaking long words between characters)
+    pub max_width: Option<u32>,
+    /// Nothing is drawn outside of this rect, usually the same as `rect` or the input button rect
+    pub clip_rect: Option<Rect>,
+    /// Input (listed in has_userinput) whose text is shown, the box gets its caret, selection and scrolling while it is edited
+    pub input: Option<ButtonId>
+}
+
+impl<ButtonId> TextBox<ButtonId>
+{
+    /// Text Starting At The Top Left Corner Of `rect`, Without Wrapping Or Clipping
+    pub fn new(text: &str, font_size: f64, color: Color, rect: Rect) -> Self
+    {
+        Self { text: text.to_string(), font_size, color, rect, horizontal_align: HorizontalAlign::Left, vertical_align: VerticalAlign::Top, max_width: None, clip_rect: None, input: None }
+    }
+
+    /// Wrap The Text At The Width Of `rect` And Clip Everything Outside Of It
+    pub fn bounded(self) -> Self
+    {
+        Self { max_width: Some(self.rect.width()), clip_rect: Some(self.rect), ..self }
+    }
+
+    pub fn aligned(self, horizontal_align: HorizontalAlign, vertical_align: VerticalAlign) -> Self
+    {
+        Self { horizontal_align, vertical_align, ..self }
+    }
+
+    pub fn with_max_width(self, max_width: u32) -> Self
+    {
+        Self { max_width: Some(max_width), ..self }
+    }
+
+    pub fn with_clip_rect(self, clip_rect: Rect) -> Self
+    {
+        Self { clip_rect: Some(clip_rect), ..self }
+    }
+
+    pub fn with_input(self, button_id: ButtonId) -> Self
+    {
+        Self { input: Some(button_id), ..self }
+    }
+}
+
+impl<ButtonId> Default for TextBox<ButtonId>
+{
+    /// Empty Black Text Of Size 16 At The Origin
+    fn default() -> Self
+    {
+        Self::new("", 16.0, Color::RGB(0, 0, 0), Rect::new(0, 0, 0, 0))
+    }
 }
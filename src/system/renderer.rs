@@ -1,21 +1,36 @@
 use crate::{
     AppState, Button, PersistentElements,
-    sdl::sdl3_generators::{GenerateImage, GenerateText},
+    sdl::sdl3_generators::{GenerateImage, GenerateText, GenerateTextBox},
     system::{
         input_handler::InputHandler,
-        page_system::{Page, PageData},
-        scene_transition::{SceneTransition, SlidePos, TransitionType}
+        input_rules::InputRules,
+        page_system::{Page, PageData, TextBox},
+        scene_transition::{SceneTransition, SlidePos, TransitionType},
+        scroll::{SCROLLBAR_WIDTH, scroll_page},
+        snapshot::RgbaFrame,
+        text_edit::{char_index, snap_to_grapheme},
+        text_layout::{CARET_WIDTH, CaretStop, FontMeasurer, TextMeasurer, caret_stops, caret_x, horizontal_scroll, layout_text_box, line_of_char, scrolled_text_box, vertical_scroll},
+        window::WINDOW_DEFAULT_SCALE
     }
 };
 use include_dir::Dir;
 use sdl3::{
-    pixels::Color,
+    pixels::{Color, PixelFormat},
     rect::Rect,
     render::{Canvas, TextureCreator},
     ttf::Sdl3TtfContext,
     video::{Window, WindowContext}
 };
-use std::f64::consts::PI;
+use std::{f64::consts::PI, time::Duration};
+use unicode_segmentation::UnicodeSegmentation;
+
+const TOOLTIP_FONT_SIZE: f64 = 18.;
+const TOOLTIP_PADDING: i32 = 8;
+/// Gap between the cursor and the top left corner of the tooltip
+const TOOLTIP_CURSOR_OFFSET: (i32, i32) = (16, 20);
+const TOOLTIP_BACKGROUND_COLOR: Color = Color::RGB(40, 40, 40);
+const TOOLTIP_BORDER_COLOR: Color = Color::RGB(120, 120, 120);
+const TOOLTIP_TEXT_COLOR: Color = Color::RGB(235, 235, 235);
 
 /// Renderer Function That Holds The Necessary Data To Render Pages And Transitions
 pub struct Renderer<'a, PageId, ButtonId>
@@ -27,10 +42,14 @@ pub struct Renderer<'a, PageId, ButtonId>
     pub decrease_color_when_selected: Option<(u8, u8, u8)>,
     pub selection_color: Option<(u8, u8, u8, u8)>,
     pub assets_dir: Option<&'a Dir<'a>>,
+    /// How long the mouse has to rest on a button before its tooltip shows up
+    pub tooltip_delay: Duration,
 
     cached_outgoing_page: Option<Page<PageId, ButtonId>>,
     cached_page_data_ptr: *const PageData<PageId, ButtonId>,
-    cached_input_handler_ptr: *const InputHandler<PageId, ButtonId>
+    cached_input_handler_ptr: *const InputHandler<PageId, ButtonId>,
+    /// (horizontal, vertical) scroll of the input TextBox being edited
+    input_scroll: Option<(ButtonId, (i32, i32))>
 }
 
 /// RendererConfig Holds The Necessary Configs And Modules
@@ -51,11 +70,58 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
     /// Create And Setup The Renderer
     pub fn new(render_config: RendererConfig<'a>) -> Self
     {
-        Self { canvas: render_config.canvas, texture_creator: render_config.texture_creator, ttf_context: render_config.ttf_context, font_path: render_config.font_path, decrease_color_when_selected: render_config.decrease_color_when_selected, selection_color: render_config.selection_color, assets_dir: render_config.assets_dir, cached_outgoing_page: None, cached_page_data_ptr: std::ptr::null(), cached_input_handler_ptr: std::ptr::null() }
+        Self { canvas: render_config.canvas, texture_creator: render_config.texture_creator, ttf_context: render_config.ttf_context, font_path: render_config.font_path, decrease_color_when_selected: render_config.decrease_color_when_selected, selection_color: render_config.selection_color, assets_dir: render_config.assets_dir, cached_outgoing_page: None, cached_page_data_ptr: std::ptr::null(), cached_input_handler_ptr: std::ptr::null(), input_scroll: None, tooltip_delay: Duration::from_millis(600) }
+    }
+
+    /// TextMeasurer Using The Renderer Font, Useful To Size Rects And Buttons Around Text
+    pub fn text_measurer(&self) -> FontMeasurer<'a>
+    {
+        FontMeasurer::new(self.ttf_context, self.font_path)
+    }
+
+    /// (width, height) In Pixels Of `text` Rendered On A Single Line With The Renderer Font
+    pub fn measure_text(&self, text: &str, font_size: f64) -> (u32, u32)
+    {
+        self.text_measurer().text_size(text, font_size)
     }
 
     /// Main render entry point. Draws the current page and applies transition overlay if any.
     pub fn render(&mut self, page_data: &PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, input_handler: &InputHandler<PageId, ButtonId>)
+    {
+        self.render_frame(page_data, app_state, input_handler);
+        self.canvas.present();
+    }
+
+    /// Render The Current Frame And Read It Back As RGBA Pixels, Meant To Be Used With `create_headless_window`
+    pub fn capture_frame(&mut self, page_data: &PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, input_handler: &InputHandler<PageId, ButtonId>) -> Result<RgbaFrame, String>
+    {
+        self.render_frame(page_data, app_state, input_handler);
+        // Pixels must be read before present, the backbuffer content is undefined after it
+        let frame = self.read_frame();
+        self.canvas.present();
+        frame
+    }
+
+    /// Read Back Everything Drawn Since The Last Present As RGBA Pixels
+    pub fn read_frame(&self) -> Result<RgbaFrame, String>
+    {
+        let surface = self.canvas.read_pixels(None).map_err(|err| format!("Failed to read canvas pixels: {}", err))?;
+        let surface_rgba = surface.convert_format(PixelFormat::ABGR8888).map_err(|err| format!("Failed to convert captured frame to RGBA: {}", err))?;
+        let (width, height, pitch) = (surface_rgba.width(), surface_rgba.height(), surface_rgba.pitch() as usize);
+        let row_length = width as usize * 4;
+        let pixels = surface_rgba.with_lock(|raw_pixels: &[u8]| {
+            let mut pixels = Vec::with_capacity(row_length * height as usize);
+            for row in 0..height as usize
+            {
+                pixels.extend_from_slice(&raw_pixels[row * pitch..row * pitch + row_length]);
+            }
+            pixels
+        });
+        RgbaFrame::new(width, height, pixels)
+    }
+
+    /// Draws The Current Page, Its Persistent Elements And The Transition Overlay Without Presenting
+    pub fn render_frame(&mut self, page_data: &PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, input_handler: &InputHandler<PageId, ButtonId>)
     {
         let page = &mut page_data.page_to_render.clone().unwrap();
         if let Some(bg) = page.background_color
@@ -106,57 +172,56 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
             self.cached_input_handler_ptr = input_handler as *const _;
         }
         let _ = self.scene_transition_overlay(app_state, input_handler);
-
-
-        self.canvas.present();
+        // Last, so it sits on top of the persistent elements
+        self.draw_tooltip(page, page_data, app_state, input_handler);
     }
 
     /// Render The Page Without Any Transition
     fn render_page_base(&mut self, page: &mut Page<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>, mut persistent_elements: Option<Vec<PersistentElements<PageId, ButtonId>>>, input_handler: &InputHandler<PageId, ButtonId>) -> Result<(), String>
     {
+        // Saved again below if the active input is drawn
+        input_handler.set_input_caret_stops(None);
         //NORMAL PAGES
+        // Elements inside scroll regions are moved by their scroll and clipped to the region
+        let scrolled_regions = page_data.scrolled_regions();
+        let scroll_clips = scroll_page(page, &scrolled_regions);
         // RECTS
         if let Some(rects) = &page.rects
         {
-            for (color, (r, radius)) in rects
+            for ((color, (r, radius)), clip_rect) in rects.iter().zip(&scroll_clips.rects)
             {
+                self.canvas.set_clip_rect(*clip_rect);
                 self.canvas.set_draw_color(*color);
                 self.draw_rounded_box(r.x(), r.y(), r.width() as i32, r.height() as i32, *radius, *color);
             }
+            self.canvas.set_clip_rect(None);
         }
         // BUTTONS
         if let Some(buttons) = &page.buttons
         {
-            for button in buttons
+            for (button, clip_rect) in buttons.iter().zip(&scroll_clips.buttons)
             {
-                if button.enabled
-                {
-                    let mut color = button.color;
-                    if let Some(button_selected) = input_handler.button_selected
-                        && let Some(amount_to_substract) = self.decrease_color_when_selected
-                        && button.id == button_selected
-                    {
-                        color = Color::RGB(button.color.r.saturating_sub(amount_to_substract.0), button.color.g.saturating_sub(amount_to_substract.1), button.color.b.saturating_sub(amount_to_substract.2));
-                    };
-                    self.canvas.set_draw_color(color);
-                    self.draw_rounded_box(button.rect.x(), button.rect.y(), button.rect.width() as i32, button.rect.height() as i32, button.radius, color);
-                }
+                self.canvas.set_clip_rect(*clip_rect);
+                self.draw_button(button, input_handler, (0, 0));
+                self.draw_widget(button, page.id, page_data);
             }
+            self.canvas.set_clip_rect(None);
         }
         // TEXTS
         if let Some(text_elements) = &mut page.texts.clone()
         {
+            let line_counts: Vec<usize> = text_elements.iter().map(|text_spec| text_spec.2.split('\n').count()).collect();
             let mut text_resources = (&mut text_elements.clone(), self.texture_creator, self.ttf_context);
             let rendered_texts = text_resources.generate_text(self.font_path);
             let mut rendered_index = 0usize;
-            for text_spec in text_elements
+            for ((text_spec, line_count), clip_rect) in text_elements.iter().zip(line_counts).zip(&scroll_clips.texts)
             {
+                self.canvas.set_clip_rect(*clip_rect);
                 let text_content = if text_spec.2.is_empty() { " " } else { &text_spec.2 };
                 let font_px = text_spec.0 as f32;
-                let lines: Vec<&str> = text_content.split('\n').collect();
                 let mut first_line_rect: Option<Rect> = None;
-                let mut line_rects: Vec<Rect> = Vec::with_capacity(lines.len());
-                for _ in &lines
+                let mut line_rects: Vec<Rect> = Vec::with_capacity(line_count);
+                for _ in 0..line_count
                 {
                     let (texture, rect) = &rendered_texts[rendered_index];
                     let _ = self.canvas.copy(texture, None, *rect);
@@ -207,20 +272,72 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
                         }
                         if let Some(target_rect) = target_rect_opt
                         {
-                            self.draw_input_overlay(&target_rect, active_input_text, font_px, input_handler);
+                            let (input_text, input_rules) = active_input_rules(page_data, app_state, active_button_id);
+                            let caret_stops = self.draw_input_overlay(&target_rect, active_input_text, font_px, display_caret(&input_rules, input_text, input_handler));
+                            input_handler.set_input_caret_stops(Some((active_button_id, text_caret_stops(&input_rules, input_text, caret_stops))));
                         }
                     }
                 }
             }
+            self.canvas.set_clip_rect(None);
+        }
+        // TEXT BOXES
+        if let Some(text_boxes) = &page.text_boxes.clone()
+        {
+            let placeholders = self.visible_placeholders(app_state, page_data);
+            let mut active_input = None;
+            if app_state.capturing_input.0
+                && let Some(active_button_id) = app_state.capturing_input.1
+                && let Some(button_rect) = self.find_active_button_rect(page, None, active_button_id).or_else(|| persistent_elements.iter().flatten().find_map(|persistent| self.find_active_button_rect(page, Some(persistent), active_button_id)))
+            {
+                active_input = Some((active_button_id, button_rect));
+            }
+            for text_box in text_boxes
+            {
+                match active_input
+                {
+                    Some((active_button_id, button_rect)) if text_box.input == Some(active_button_id) =>
+                    {
+                        // Single line inputs scroll sideways and text areas up and down so the caret stays visible, the overflow is clipped to the button
+                        let previous_scroll = self.input_scroll.filter(|(button_id, _)| *button_id == active_button_id).map_or((0, 0), |(_, scroll)| scroll);
+                        let measurer = self.text_measurer();
+                        let (input_text, input_rules) = active_input_rules(page_data, app_state, active_button_id);
+                        let caret = display_caret(&input_rules, input_text, input_handler);
+                        let caret_index = char_index(&text_box.text, caret.0);
+                        let scroll = (horizontal_scroll(&measurer, text_box, caret_index, previous_scroll.0), vertical_scroll(&measurer, text_box, caret_index, previous_scroll.1));
+                        self.input_scroll = Some((active_button_id, scroll));
+                        let text_box = TextBox { clip_rect: Some(text_box.clip_rect.unwrap_or(button_rect)), ..scrolled_text_box(text_box, scroll) };
+                        self.draw_text_boxes(&[with_placeholder(&placeholders, &text_box)]);
+                        let caret_stops = self.draw_text_box_input_overlay(&text_box, caret);
+                        input_handler.set_input_caret_stops(Some((active_button_id, text_caret_stops(&input_rules, input_text, caret_stops))));
+                    }
+                    _ => self.draw_text_boxes(&[with_placeholder(&placeholders, text_box)])
+                }
+            }
         }
         // IMAGES
         if let Some(images) = &mut page.images
         {
+            // Images that fail to load are skipped, so each texture finds its clip by its rect
+            let image_clips: Vec<(Rect, Option<Rect>)> = images.iter().zip(&scroll_clips.images).map(|((position, size, _), clip_rect)| (Rect::new(position.0, position.1, size.0, size.1), *clip_rect)).collect();
             let mut image_data = (images, self.texture_creator);
             for (image_texture, image_rect) in image_data.generate_image(self.assets_dir)
             {
+                self.canvas.set_clip_rect(image_clips.iter().find(|(rect, _)| *rect == image_rect).and_then(|(_, clip_rect)| *clip_rect));
                 let _ = self.canvas.copy(&image_texture, None, image_rect);
             }
+            self.canvas.set_clip_rect(None);
+        }
+        // SCROLLBARS
+        for (scroll_region, offset) in &scrolled_regions
+        {
+            if let (Some(track_rect), Some(thumb_rect)) = (scroll_region.track_rect(), scroll_region.thumb_rect(*offset as f32))
+            {
+                let thumb_color = scroll_region.scrollbar_color;
+                let radius = SCROLLBAR_WIDTH as i32 / 2 - 1;
+                self.draw_rounded_box(track_rect.x(), track_rect.y(), track_rect.width() as i32, track_rect.height() as i32, radius, Color::RGBA(thumb_color.r, thumb_color.g, thumb_color.b, thumb_color.a / 3));
+                self.draw_rounded_box(thumb_rect.x(), thumb_rect.y(), thumb_rect.width() as i32, thumb_rect.height() as i32, radius, thumb_color);
+            }
         }
 
         if let Some(pe_vec) = &page_data.persistent_elements_to_render
@@ -268,18 +385,7 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
                 {
                     for button in buttons
                     {
-                        if button.enabled
-                        {
-                            let mut color = button.color;
-                            if let Some(button_selected) = input_handler.button_selected
-                                && let Some(amount_to_substract) = self.decrease_color_when_selected
-                                && button.id == button_selected
-                            {
-                                color = Color::RGBA(button.color.r.saturating_sub(amount_to_substract.0), button.color.g.saturating_sub(amount_to_substract.1), button.color.b.saturating_sub(amount_to_substract.2), 255);
-                            };
-                            self.canvas.set_draw_color(color);
-                            self.draw_rounded_box(button.rect.x(), button.rect.y(), button.rect.width() as i32, button.rect.height() as i32, button.radius, color);
-                        }
+                        self.draw_button(button, input_handler, (0, 0));
                     }
                 }
                 // TEXTS
@@ -293,6 +399,11 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
                         });
                     }
                 }
+                // TEXT BOXES
+                if let Some(text_boxes) = &page.text_boxes
+                {
+                    self.draw_text_boxes(text_boxes);
+                }
                 // IMAGES
                 if let Some(images) = &mut page.images
                 {
@@ -399,18 +510,7 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
         {
             for button in buttons
             {
-                if button.enabled
-                {
-                    let mut color = button.color;
-                    if let Some(button_selected) = input_handler.button_selected
-                        && let Some(amount_to_substract) = self.decrease_color_when_selected
-                        && button.id == button_selected
-                    {
-                        color = Color::RGB(button.color.r.saturating_sub(amount_to_substract.0), button.color.g.saturating_sub(amount_to_substract.1), button.color.b.saturating_sub(amount_to_substract.2));
-                    };
-                    self.canvas.set_draw_color(color);
-                    self.draw_rounded_box(button.rect.x() + direction_x, button.rect.y() + direction_y, button.rect.width() as i32, button.rect.height() as i32, button.radius, color);
-                }
+                self.draw_button(button, input_handler, (direction_x, direction_y));
             }
         }
         // TEXTS
@@ -429,6 +529,19 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
                 });
             }
         }
+        // TEXT BOXES
+        if let Some(text_boxes) = &mut page.text_boxes
+        {
+            for text_box in &mut *text_boxes
+            {
+                text_box.rect.offset(direction_x, direction_y);
+                if let Some(clip_rect) = &mut text_box.clip_rect
+                {
+                    clip_rect.offset(direction_x, direction_y);
+                }
+            }
+            self.draw_text_boxes(text_boxes);
+        }
         // IMAGES
         if let Some(images) = &mut page.images
         {
@@ -447,6 +560,74 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
         Ok(())
     }
 
+    /// Draw A Button With The Color Of Its State (Hovered, Pressed, Focused Or Disabled) And Its Border
+    fn draw_button(&mut self, button: &Button<ButtonId>, input_handler: &InputHandler<PageId, ButtonId>, (offset_x, offset_y): (i32, i32))
+    {
+        let color = button.state_color(input_handler.button_selected == Some(button.id), input_handler.button_pressed == Some(button.id), input_handler.focused_button == Some(button.id), self.decrease_color_when_selected);
+        let (x, y, w, h) = (button.rect.x() + offset_x, button.rect.y() + offset_y, button.rect.width() as i32, button.rect.height() as i32);
+        match button.style.and_then(|style| style.border)
+        {
+            Some((border_color, border_width)) =>
+            {
+                let border_width = border_width as i32;
+                let (inner_w, inner_h) = (w - 2 * border_width, h - 2 * border_width);
+                self.draw_rounded_box(x, y, w, h, button.radius, border_color);
+                // A border at least half as wide as the button covers all of it, there is no inner box left to fill
+                if inner_w > 0 && inner_h > 0
+                {
+                    self.draw_rounded_box(x + border_width, y + border_width, inner_w, inner_h, (button.radius - border_width).clamp(0, inner_w.min(inner_h) / 2), color);
+                }
+            }
+            None => self.draw_rounded_box(x, y, w, h, button.radius, color)
+        }
+    }
+
+    /// Draw The Check Mark, Knob Or Fill Of A Button Declared As A Widget Over It
+    fn draw_widget(&mut self, button: &Button<ButtonId>, page_id: PageId, page_data: &PageData<PageId, ButtonId>)
+    {
+        let Some(widget) = page_data.widget_of(page_id, button.id)
+        else
+        {
+            return;
+        };
+        let value = page_data.widget_value(page_id, button.id).unwrap_or(widget.default_value());
+        for (mark_rect, radius, color) in widget.marks(button.rect, button.radius, value)
+        {
+            self.draw_rounded_box(mark_rect.x(), mark_rect.y(), mark_rect.width() as i32, mark_rect.height() as i32, radius, color);
+        }
+    }
+
+    /// Draw The Tooltip Of The Hovered Button Near The Cursor Once It Rested There For tooltip_delay
+    /// (See `InputHandler::tooltip_button`), Hidden While The Button Is Pressed Or A Transition Runs
+    fn draw_tooltip(&mut self, page: &Page<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>, app_state: &AppState<PageId, ButtonId>, input_handler: &InputHandler<PageId, ButtonId>)
+    {
+        let Some(button_id) = input_handler.tooltip_button(self.tooltip_delay).filter(|_| app_state.scene_transition.is_none())
+        else
+        {
+            return;
+        };
+        let buttons = page.buttons.iter().flatten().chain(page_data.forced_persistent_elements.iter().chain(page_data.persistent_elements_to_render.iter()).flatten().flat_map(|persistent| persistent.buttons.iter().flatten()));
+        let Some(tooltip) = buttons.filter(|button| button.id == button_id).find_map(|button| button.tooltip.clone())
+        else
+        {
+            return;
+        };
+
+        let (mouse_x, mouse_y) = app_state.logical_mouse_position(input_handler.mouse_position);
+        let line_sizes: Vec<(u32, u32)> = tooltip.split('\n').map(|line| self.measure_text(if line.is_empty() { " " } else { line }, TOOLTIP_FONT_SIZE)).collect();
+        let text_size = (line_sizes.iter().map(|line_size| line_size.0).max().unwrap_or(0), line_sizes.iter().map(|line_size| line_size.1).sum::<u32>());
+        let tooltip_rect = tooltip_rect((mouse_x as i32, mouse_y as i32), (text_size.0 + 2 * TOOLTIP_PADDING as u32, text_size.1 + 2 * TOOLTIP_PADDING as u32));
+
+        self.draw_rounded_box(tooltip_rect.x(), tooltip_rect.y(), tooltip_rect.width() as i32, tooltip_rect.height() as i32, 4, TOOLTIP_BORDER_COLOR);
+        self.draw_rounded_box(tooltip_rect.x() + 1, tooltip_rect.y() + 1, tooltip_rect.width() as i32 - 2, tooltip_rect.height() as i32 - 2, 3, TOOLTIP_BACKGROUND_COLOR);
+        let mut tooltip_text = vec![(TOOLTIP_FONT_SIZE, (tooltip_rect.x() + TOOLTIP_PADDING, tooltip_rect.y() + TOOLTIP_PADDING), tooltip.to_string(), TOOLTIP_TEXT_COLOR)];
+        let mut text_resources = (&mut tooltip_text, self.texture_creator, self.ttf_context);
+        for (texture, rect) in text_resources.generate_text(self.font_path)
+        {
+            let _ = self.canvas.copy(&texture, None, rect);
+        }
+    }
+
     // ===================
     // Minimal utilities
     // ===================
@@ -472,20 +653,36 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
         }
     }
 
-    /// Find Which Button Is Current Active
+    /// Find The Text Shown For The Active Input (Masked For Password Fields, Like The Pages Get It)
     pub fn find_active_input_text<'p>(&self, data: &'p PageData<PageId, ButtonId>, app: &AppState<PageId, ButtonId>, button_active: ButtonId) -> Option<&'p str>
     {
         let current_page = app.current_page;
-        for (page_id, button_id, string) in &data.vec_user_input
+        for (input_index, (page_id, button_id, string)) in data.vec_user_input.iter().enumerate()
         {
             if *page_id == current_page && *button_id == button_active
             {
-                return Some(string.as_str());
+                return Some(data.vec_user_input_string.get(input_index).unwrap_or(string).as_str());
             }
         }
         None
     }
 
+    /// ButtonId And Placeholder Of Every Empty Input Of The Current Page That Has One
+    fn visible_placeholders(&self, app_state: &AppState<PageId, ButtonId>, page_data: &PageData<PageId, ButtonId>) -> Vec<(ButtonId, String)>
+    {
+        let mut placeholders = Vec::new();
+        for (page_id, button_id, input_rules) in &page_data.input_rules
+        {
+            let input_text = page_data.vec_user_input.iter().find(|(input_page_id, input_button_id, _)| input_page_id == page_id && input_button_id == button_id).map_or("", |(_, _, input_text)| input_text.as_str());
+            if *page_id == app_state.current_page
+                && let Some(placeholder) = input_rules.placeholder_text(input_text)
+            {
+                placeholders.push((*button_id, placeholder.to_string()));
+            }
+        }
+        placeholders
+    }
+
     /// Check If The Button Matches With Other
     pub fn button_matches<T: Copy + Eq>(button: &Button<T>, a: T) -> bool
     {
@@ -519,39 +716,25 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
         None
     }
 
-    /// Draw The Input Box Overlay, like: (Selection, Cursor, Etc...)
-    fn draw_input_overlay(&mut self, text_rect: &Rect, text_content: &str, font_px: f32, input_state: &InputHandler<PageId, ButtonId>)
+    /// Draw The Input Box Overlay, like: (Selection, Cursor, Etc...), Returning Where The Caret Can Be Placed
+    fn draw_input_overlay(&mut self, text_rect: &Rect, text_content: &str, font_px: f32, (cursor_position, text_selection_range): (usize, Option<(usize, usize)>)) -> Vec<CaretStop>
     {
         let horizontal_padding = 0;
         let text_start_x = text_rect.x() + horizontal_padding;
         let font = self.ttf_context.load_font(self.font_path, font_px).expect("Failed to load font for input overlay");
         let text_height = font.height() as u32;
         let baseline_y = text_rect.y();
-        let character_boundaries: Vec<usize> = text_content.char_indices().map(|(i, _)| i).chain(std::iter::once(text_content.len())).collect();
-
-        let measure_text_x = |char_count: usize| -> i32 {
-            let end_index = if char_count < character_boundaries.len() { character_boundaries[char_count] } else { text_content.len() };
-            let substring = &text_content[..end_index];
+        // Cursor and selection are byte indexes on grapheme cluster boundaries
+        let measure_text_x = |byte_index: usize| -> i32 {
+            let substring = &text_content[..snap_to_grapheme(text_content, byte_index)];
             let (width, _) = font.size_of(substring).unwrap_or((0, 0));
             text_start_x + width as i32
         };
 
-        let total_chars = text_content.chars().count();
-        let clamp_to_text = |n: usize| n.min(total_chars);
-
-        let (selection_start, selection_end) = match input_state.text_selection_range
+        let (selection_start, selection_end) = match text_selection_range
         {
-            Some((a, b)) =>
-            {
-                let a = clamp_to_text(a);
-                let b = clamp_to_text(b);
-                (a.min(b), a.max(b))
-            }
-            None =>
-            {
-                let pos = input_state.cursor_position.min(total_chars);
-                (pos, pos)
-            }
+            Some((a, b)) => (a.min(b), a.max(b)),
+            None => (cursor_position, cursor_position)
         };
 
         // Draw selection or caret
@@ -570,5 +753,121 @@ impl<'a, PageId: Copy + Eq, ButtonId: Copy + Eq> Renderer<'a, PageId, ButtonId>
             self.canvas.set_draw_color(Color::RGB(255, 255, 255));
             let _ = self.canvas.fill_rect(Rect::new(caret_x, baseline_y, 2, text_height));
         }
+
+        let line_rect = Rect::new(text_start_x, baseline_y, text_rect.width(), text_height);
+        text_content.grapheme_indices(true).map(|(grapheme_start, _)| grapheme_start).chain([text_content.len()]).map(|byte_index| CaretStop { byte_index, x: measure_text_x(byte_index), line_rect }).collect()
+    }
+
+    /// Draw Every TextBox, One At A Time So Each Gets Its Own Clip Rect
+    fn draw_text_boxes(&mut self, text_boxes: &[TextBox<ButtonId>])
+    {
+        for text_box in text_boxes
+        {
+            self.canvas.set_clip_rect(text_box.clip_rect);
+            let mut single_text_box = vec![text_box.clone()];
+            let mut text_box_resources = (&mut single_text_box, self.texture_creator, self.ttf_context);
+            for (texture, rect) in text_box_resources.generate_text_box(self.font_path)
+            {
+                let _ = self.canvas.copy(&texture, None, rect);
+            }
+        }
+        self.canvas.set_clip_rect(None);
+    }
+
+    /// Draw The Caret Or Selection Of The Active Input Over A TextBox, Following Its Alignment And Wrapped Lines, Returning Where The Caret Can Be Placed
+    fn draw_text_box_input_overlay(&mut self, text_box: &TextBox<ButtonId>, (cursor_position, text_selection_range): (usize, Option<(usize, usize)>)) -> Vec<CaretStop>
+    {
+        self.canvas.set_clip_rect(text_box.clip_rect);
+        let measurer = self.text_measurer();
+        let lines = layout_text_box(&measurer, text_box);
+        // The layout works with char indexes, the InputHandler with byte indexes
+        let (selection_start, selection_end) = match text_selection_range
+        {
+            Some((a, b)) => (char_index(&text_box.text, a.min(b)), char_index(&text_box.text, a.max(b))),
+            None =>
+            {
+                let pos = char_index(&text_box.text, cursor_position);
+                (pos, pos)
+            }
+        };
+
+        if selection_start != selection_end
+        {
+            let selection_color = self.selection_color.unwrap_or((0, 0, 255, 125));
+            self.canvas.set_draw_color(Color::RGBA(selection_color.0, selection_color.1, selection_color.2, selection_color.3));
+            for line in &lines
+            {
+                let start = selection_start.max(line.char_range.0);
+                let end = selection_end.min(line.char_range.1);
+                // Lines where the selection only covers the '\n' still get a thin mark
+                if start > end || (start == end && selection_end <= line.char_range.1)
+                {
+                    continue;
+                }
+                let x1 = caret_x(&measurer, line, text_box.font_size, start);
+                let x2 = caret_x(&measurer, line, text_box.font_size, end);
+                let _ = self.canvas.fill_rect(Rect::new(x1, line.rect.y(), (x2 - x1).max(1) as u32, line.rect.height()));
+            }
+        }
+        else
+        {
+            let line = &lines[line_of_char(&lines, selection_start)];
+            let caret_x = caret_x(&measurer, line, text_box.font_size, selection_start);
+            self.canvas.set_draw_color(Color::RGB(255, 255, 255));
+            let _ = self.canvas.fill_rect(Rect::new(caret_x, line.rect.y(), CARET_WIDTH as u32, line.rect.height()));
+        }
+        self.canvas.set_clip_rect(None);
+        caret_stops(&measurer, text_box)
+    }
+}
+
+/// Real Text And Rules (Default Ones If It Has None) Of The Active Input
+fn active_input_rules<'p, PageId: Copy + Eq, ButtonId: Copy + Eq>(page_data: &'p PageData<PageId, ButtonId>, app_state: &AppState<PageId, ButtonId>, active_button_id: ButtonId) -> (&'p str, InputRules)
+{
+    let is_active = |page_id: &PageId, button_id: &ButtonId| *page_id == app_state.current_page && *button_id == active_button_id;
+    let input_text = page_data.vec_user_input.iter().find(|(page_id, button_id, _)| is_active(page_id, button_id)).map_or("", |(_, _, input_text)| input_text.as_str());
+    let input_rules = page_data.input_rules.iter().find(|(page_id, button_id, _)| is_active(page_id, button_id)).map(|(_, _, input_rules)| input_rules.clone());
+    (input_text, input_rules.unwrap_or_default())
+}
+
+/// Cursor And Selection Of The InputHandler (Indexes In The Real Text) Moved Onto The Displayed Text
+fn display_caret<PageId, ButtonId>(input_rules: &InputRules, input_text: &str, input_handler: &InputHandler<PageId, ButtonId>) -> (usize, Option<(usize, usize)>)
+{
+    (input_rules.display_index(input_text, input_handler.cursor_position), input_handler.text_selection_range.map(|(anchor_index, cursor_index)| (input_rules.display_index(input_text, anchor_index), input_rules.display_index(input_text, cursor_index))))
+}
+
+/// Caret Stops Measured On The Displayed Text Moved Back Onto The Real Text
+fn text_caret_stops(input_rules: &InputRules, input_text: &str, caret_stops: Vec<CaretStop>) -> Vec<CaretStop>
+{
+    caret_stops.into_iter().map(|caret_stop| CaretStop { byte_index: input_rules.text_index(input_text, caret_stop.byte_index), ..caret_stop }).collect()
+}
+
+/// Where A Tooltip Of `tooltip_size` Goes For The Cursor At `mouse_position`: Below And Right Of It,
+/// Flipped To The Other Side When That Would Leave The WINDOW_DEFAULT_SCALE Canvas
+pub fn tooltip_rect(mouse_position: (i32, i32), tooltip_size: (u32, u32)) -> Rect
+{
+    let (canvas_width, canvas_height) = (WINDOW_DEFAULT_SCALE.0 as i32, WINDOW_DEFAULT_SCALE.1 as i32);
+    let (width, height) = (tooltip_size.0.min(WINDOW_DEFAULT_SCALE.0) as i32, tooltip_size.1.min(WINDOW_DEFAULT_SCALE.1) as i32);
+    let place = |cursor: i32, offset: i32, size: i32, canvas_size: i32| {
+        let position = if cursor + offset + size > canvas_size { cursor - size } else { cursor + offset };
+        position.clamp(0, canvas_size - size)
+    };
+    Rect::new(place(mouse_position.0, TOOLTIP_CURSOR_OFFSET.0, width, canvas_width), place(mouse_position.1, TOOLTIP_CURSOR_OFFSET.1, height, canvas_height), width as u32, height as u32)
+}
+
+/// The TextBox Linked To An Empty Input Showing Its Placeholder Instead
+fn with_placeholder<ButtonId: Copy + Eq>(placeholders: &[(ButtonId, String)], text_box: &TextBox<ButtonId>) -> TextBox<ButtonId>
+{
+    let placeholder = placeholders.iter().find(|(button_id, _)| text_box.input == Some(*button_id)).map(|(_, placeholder)| placeholder);
+    match placeholder.filter(|_| text_box.text.is_empty())
+    {
+        Some(placeholder) => TextBox { text: placeholder.to_string(), color: placeholder_color(text_box.color), ..text_box.clone() },
+        None => text_box.clone()
     }
 }
+
+/// Placeholders Are Drawn With Half The Alpha Of The Input Text
+fn placeholder_color(color: Color) -> Color
+{
+    Color::RGBA(color.r, color.g, color.b, color.a / 2)
+}
@@ -1,6 +1,7 @@
 use std::time::Instant;
 
 #[derive(Debug, Clone, PartialEq, Copy)]
+#[cfg_attr(feature = "page-files", derive(serde::Deserialize))]
 pub enum SlidePos
 {
     Up,
@@ -29,7 +30,8 @@ pub struct SceneTransition<PageId>
     pub has_switched: bool,
     pub start_time: Instant,
     pub duration_ms: u32,
-    pub next_page: Option<PageId>
+    pub next_page: Option<PageId>,
+    pub frozen_progress: Option<f32>
 }
 
 impl<PageId: Copy + Eq> SceneTransition<PageId>
@@ -37,7 +39,45 @@ impl<PageId: Copy + Eq> SceneTransition<PageId>
     /// Creates a new transition of a given type and duration.
     pub fn new(transition_type: TransitionType, duration_ms: u32, next_page: Option<PageId>) -> Self
     {
-        Self { active: true, progress: 0.0, transition_type, is_second_stage: false, has_switched: false, start_time: Instant::now(), duration_ms, next_page }
+        Self { active: true, progress: 0.0, transition_type, is_second_stage: false, has_switched: false, start_time: Instant::now(), duration_ms, next_page, frozen_progress: None }
+    }
+
+    /// Freeze the transition at a fixed overall progress (0.0 to 1.0) instead of following the clock.
+    /// Used by snapshot tests to render exact Fade and Slide frames.
+    pub fn freeze_at(&mut self, progress: f32)
+    {
+        self.frozen_progress = Some(progress.clamp(0.0, 1.0));
+    }
+
+    /// Returns `true` when a frozen transition already reached the stage its progress points to,
+    /// meaning the next rendered frame shows exactly that progress.
+    pub fn is_settled(&self) -> bool
+    {
+        let Some(progress) = self.frozen_progress
+        else
+        {
+            return false;
+        };
+        match self.transition_type
+        {
+            TransitionType::Fade(_) if progress < 0.5 => true,
+            TransitionType::Fade(_) | TransitionType::Slide(_, _, _) => self.is_second_stage && self.has_switched,
+            TransitionType::None => true
+        }
+    }
+
+    /// Milliseconds elapsed in the current stage, taken from the frozen progress when set.
+    fn stage_elapsed_ms(&self) -> f32
+    {
+        match self.frozen_progress
+        {
+            Some(progress) =>
+            {
+                let total_elapsed = progress * self.duration_ms as f32;
+                if self.is_second_stage && matches!(self.transition_type, TransitionType::Fade(_)) { total_elapsed - (self.duration_ms as f32) / 2.0 } else { total_elapsed }
+            }
+            None => self.start_time.elapsed().as_millis() as f32
+        }
     }
 
     /// Update progress and return `true` when finished.
@@ -48,7 +88,7 @@ impl<PageId: Copy + Eq> SceneTransition<PageId>
         {
             return true;
         }
-        let elapsed = self.start_time.elapsed().as_millis() as f32;
+        let elapsed = self.stage_elapsed_ms();
 
         match self.transition_type
         {
@@ -0,0 +1,189 @@
+use crate::system::page_system::Page;
+use sdl3::{pixels::Color, rect::Rect};
+use std::time::Duration;
+
+/// Pixels Scrolled By One Mouse Wheel Notch Or Arrow Key
+pub const SCROLL_STEP: f32 = 40.;
+pub const SCROLLBAR_WIDTH: u32 = 10;
+const MIN_THUMB_HEIGHT: u32 = 24;
+/// Share Of The Kinetic Velocity Kept From One Frame To The Next
+const KINETIC_FRICTION: f32 = 0.9;
+/// Kinetic Scrolling Stops Below This Many Pixels Per Frame
+const KINETIC_MIN_VELOCITY: f32 = 0.1;
+/// Frames Per Second The Kinetic Velocity And Friction Are Given For, Whatever The Real Frame Rate
+const KINETIC_FRAME_RATE: f32 = 60.;
+
+/// Area Of A Page That Scrolls And Clips Everything Laid Out Inside Its Content Rect
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct ScrollRegion<ButtonId>
+{
+    /// Key of its scroll state in PageData::scroll_states, it doesn't have to be a button of the page
+    pub id: ButtonId,
+    /// Visible area, the scrolled elements are clipped to it
+    pub rect: Rect,
+    /// Elements starting inside (rect.x, rect.y, rect.w, content_height) move with the scroll
+    pub content_height: u32,
+    pub scrollbar_color: Color,
+    /// Mouse wheel scrolling keeps gliding and slows down instead of stopping right away
+    pub kinetic: bool
+}
+
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct ScrollState
+{
+    pub offset: f32,
+    /// Pixels per 1/60 second left from kinetic scrolling
+    pub velocity: f32
+}
+
+/// Clip Rects Of The Page Elements Moved By scroll_page, In The Same Order As The Page Lists Them
+#[derive(Debug, Default)]
+pub struct ScrollClips
+{
+    pub rects: Vec<Option<Rect>>,
+    pub buttons: Vec<Option<Rect>>,
+    pub texts: Vec<Option<Rect>>,
+    pub images: Vec<Option<Rect>>
+}
+
+impl<ButtonId> ScrollRegion<ButtonId>
+{
+    pub fn content_rect(&self) -> Rect
+    {
+        Rect::new(self.rect.x(), self.rect.y(), self.rect.width(), self.content_height.max(self.rect.height()))
+    }
+
+    /// Furthest The Content Can Scroll, 0 When It Fits
+    pub fn max_offset(&self) -> f32
+    {
+        self.content_height.saturating_sub(self.rect.height()) as f32
+    }
+
+    /// Scrollbar Track Along The Right Edge Of The Region, None When The Content Fits
+    pub fn track_rect(&self) -> Option<Rect>
+    {
+        (self.max_offset() > 0.).then(|| Rect::new(self.rect.right() - SCROLLBAR_WIDTH as i32, self.rect.y(), SCROLLBAR_WIDTH, self.rect.height()))
+    }
+
+    /// Draggable Part Of The Scrollbar, As Tall As The Visible Share Of The Content
+    pub fn thumb_rect(&self, offset: f32) -> Option<Rect>
+    {
+        let track_rect = self.track_rect()?;
+        let thumb_height = ((track_rect.height() as u64 * track_rect.height() as u64 / self.content_height as u64) as u32).clamp(MIN_THUMB_HEIGHT.min(track_rect.height()), track_rect.height());
+        let travel = (track_rect.height() - thumb_height) as f32;
+        let thumb_y = track_rect.y() + (travel * offset.clamp(0., self.max_offset()) / self.max_offset()).round() as i32;
+        Some(Rect::new(track_rect.x(), thumb_y, SCROLLBAR_WIDTH, thumb_height))
+    }
+
+    /// Offset That Puts The Top Of The Thumb At `thumb_y`, To Follow A Dragged Scrollbar
+    pub fn offset_for_thumb(&self, thumb_y: i32) -> f32
+    {
+        let (Some(track_rect), Some(thumb_rect)) = (self.track_rect(), self.thumb_rect(0.))
+        else
+        {
+            return 0.;
+        };
+        let travel = (track_rect.height() - thumb_rect.height()) as f32;
+        if travel <= 0.
+        {
+            return 0.;
+        }
+        ((thumb_y - track_rect.y()) as f32 / travel * self.max_offset()).clamp(0., self.max_offset())
+    }
+}
+
+impl ScrollState
+{
+    /// Scroll By `delta` Pixels, Right Away Or Spread Over The Next Frames When `kinetic`
+    pub fn scroll_by(&mut self, delta: f32, max_offset: f32, kinetic: bool)
+    {
+        if kinetic
+        {
+            // The velocity decays geometrically, so the whole glide still adds up to `delta`
+            self.velocity += delta * (1. - KINETIC_FRICTION);
+            return;
+        }
+        self.velocity = 0.;
+        self.offset = (self.offset + delta).clamp(0., max_offset)
+    }
+
+    /// Advance Kinetic Scrolling By `elapsed`, Stopping At Either End Of The Content.
+    /// The glide is the same whatever the frame rate, one long step moves as far as many short ones
+    pub fn step(&mut self, max_offset: f32, elapsed: Duration)
+    {
+        if self.velocity == 0. || elapsed.is_zero()
+        {
+            return;
+        }
+        // Sum of the geometric decay over the elapsed frames, a fraction of a frame included
+        let friction = KINETIC_FRICTION.powf(elapsed.as_secs_f32() * KINETIC_FRAME_RATE);
+        self.offset = (self.offset + self.velocity * (1. - friction) / (1. - KINETIC_FRICTION)).clamp(0., max_offset);
+        self.velocity *= friction;
+        if self.velocity.abs() < KINETIC_MIN_VELOCITY || self.offset <= 0. || self.offset >= max_offset
+        {
+            self.velocity = 0.
+        }
+    }
+}
+
+/// Where An Element Declared At `rect` Is Shown: Moved Up By The Offset Of The Scroll Region
+/// It Starts In, Along With The Region Rect It Is Clipped To (None Outside Scroll Regions)
+pub fn scroll_placement<ButtonId>(scrolled_regions: &[(ScrollRegion<ButtonId>, i32)], rect: Rect) -> (Rect, Option<Rect>)
+{
+    // Later regions are drawn on top, so they win when regions overlap
+    match scrolled_regions.iter().rev().find(|(scroll_region, _)| scroll_region.content_rect().contains_point((rect.x(), rect.y())))
+    {
+        Some((scroll_region, offset)) => (Rect::new(rect.x(), rect.y() - offset, rect.width(), rect.height()), Some(scroll_region.rect)),
+        None => (rect, None)
+    }
+}
+
+/// Move The Elements Of `page` That Start Inside A Scroll Region By Its Offset, Returning What Each One Has To Be Clipped To.
+/// Text boxes are clipped through their own clip_rect, the ones scrolled completely out of their region are dropped
+pub fn scroll_page<PageId, ButtonId>(page: &mut Page<PageId, ButtonId>, scrolled_regions: &[(ScrollRegion<ButtonId>, i32)]) -> ScrollClips
+{
+    let mut scroll_clips = ScrollClips::default();
+    for (_, (rect, _)) in page.rects.iter_mut().flatten()
+    {
+        let clip_rect;
+        (*rect, clip_rect) = scroll_placement(scrolled_regions, *rect);
+        scroll_clips.rects.push(clip_rect);
+    }
+    for button in page.buttons.iter_mut().flatten()
+    {
+        let clip_rect;
+        (button.rect, clip_rect) = scroll_placement(scrolled_regions, button.rect);
+        scroll_clips.buttons.push(clip_rect);
+    }
+    for (_, position, _, _) in page.texts.iter_mut().flatten()
+    {
+        let (moved_rect, clip_rect) = scroll_placement(scrolled_regions, Rect::new(position.0, position.1, 1, 1));
+        *position = (moved_rect.x(), moved_rect.y());
+        scroll_clips.texts.push(clip_rect);
+    }
+    for ((x, y), size, _) in page.images.iter_mut().flatten()
+    {
+        let (moved_rect, clip_rect) = scroll_placement(scrolled_regions, Rect::new(*x, *y, size.0, size.1));
+        (*x, *y) = (moved_rect.x(), moved_rect.y());
+        scroll_clips.images.push(clip_rect);
+    }
+    if let Some(text_boxes) = &mut page.text_boxes
+    {
+        text_boxes.retain_mut(|text_box| {
+            let (moved_rect, Some(region_rect)) = scroll_placement(scrolled_regions, text_box.rect)
+            else
+            {
+                return true;
+            };
+            let offset = text_box.rect.y() - moved_rect.y();
+            text_box.rect = moved_rect;
+            text_box.clip_rect = match text_box.clip_rect
+            {
+                Some(clip_rect) => Rect::new(clip_rect.x(), clip_rect.y() - offset, clip_rect.width(), clip_rect.height()).intersection(region_rect),
+                None => Some(region_rect)
+            };
+            text_box.clip_rect.is_some()
+        });
+    }
+    scroll_clips
+}
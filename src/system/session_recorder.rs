@@ -0,0 +1,105 @@
+use crate::system::{input_handler::InputEvent, input_source::ScriptedStep};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Debug,
+    fs::File,
+    io::{BufRead, BufReader, LineWriter, Write},
+    path::Path,
+    time::Instant
+};
+
+/// One Recorded Event, Stored As One JSON Object Per Line:
+/// `{"t":1520,"page":"Page1","mouse":[412.5,220.0],"event":{"Text":"hi"}}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedStep
+{
+    /// Milliseconds since the recording started
+    #[serde(rename = "t")]
+    pub timestamp_ms: u64,
+    /// `Debug` name of the page that was active when the event happened
+    pub page: String,
+    #[serde(rename = "mouse")]
+    pub mouse_position: (f32, f32),
+    pub event: InputEvent
+}
+
+impl RecordedStep
+{
+    /// Encode The Step As A Single JSON Line (Without The Trailing Newline)
+    pub fn to_json_line(&self) -> Result<String, String>
+    {
+        serde_json::to_string(self).map_err(|err| format!("Failed to encode session step: {}", err))
+    }
+
+    /// Decode A Line Written By `to_json_line`
+    pub fn from_json_line(line: &str) -> Result<Self, String>
+    {
+        serde_json::from_str(line).map_err(|err| format!("Invalid session step: {}", err))
+    }
+
+    pub fn to_scripted_step(&self) -> ScriptedStep
+    {
+        ScriptedStep::new(self.timestamp_ms, self.mouse_position, self.event.clone())
+    }
+}
+
+/// Writes Every InputEvent Handled By The InputHandler To A JSON Lines File.
+/// Each line is flushed as soon as it is written, so the file stays usable after a crash.
+pub struct SessionRecorder
+{
+    writer: LineWriter<File>,
+    started_at: Instant,
+    last_mouse_position: Option<(f32, f32)>
+}
+
+impl SessionRecorder
+{
+    /// Create (Or Truncate) The Session File At `path`, Timestamps Count From `started_at`
+    pub fn create<P: AsRef<Path>>(path: P, started_at: Instant) -> Result<Self, String>
+    {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent).map_err(|err| format!("Failed to create directory '{}': {}", parent.display(), err))?;
+        }
+        let file = File::create(path).map_err(|err| format!("Failed to create session file '{}': {}", path.display(), err))?;
+        Ok(Self { writer: LineWriter::new(file), started_at, last_mouse_position: None })
+    }
+
+    /// Append One Event Happening At `now`. `InputEvent::None` is only written when the mouse moved,
+    /// so hovering and long presses replay without a line per frame
+    pub fn record<PageId: Debug>(&mut self, page_id: &PageId, now: Instant, mouse_position: (f32, f32), event: &InputEvent) -> Result<(), String>
+    {
+        if *event == InputEvent::None && self.last_mouse_position == Some(mouse_position)
+        {
+            return Ok(());
+        }
+        self.last_mouse_position = Some(mouse_position);
+        let recorded_step = RecordedStep { timestamp_ms: now.saturating_duration_since(self.started_at).as_millis() as u64, page: format!("{:?}", page_id), mouse_position, event: event.clone() };
+        writeln!(self.writer, "{}", recorded_step.to_json_line()?).map_err(|err| format!("Failed to write session step: {}", err))
+    }
+
+    pub fn flush(&mut self) -> Result<(), String>
+    {
+        self.writer.flush().map_err(|err| format!("Failed to flush session file: {}", err))
+    }
+}
+
+/// Read Every Step Of A Session File, Empty Lines Are Skipped
+pub fn load_session<P: AsRef<Path>>(path: P) -> Result<Vec<RecordedStep>, String>
+{
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|err| format!("Failed to open session file '{}': {}", path.display(), err))?;
+    let mut recorded_steps = Vec::new();
+    for (line_index, line) in BufReader::new(file).lines().enumerate()
+    {
+        let line = line.map_err(|err| format!("Failed to read '{}': {}", path.display(), err))?;
+        if line.trim().is_empty()
+        {
+            continue;
+        }
+        recorded_steps.push(RecordedStep::from_json_line(&line).map_err(|err| format!("{}:{}: {}", path.display(), line_index + 1, err))?);
+    }
+    Ok(recorded_steps)
+}
@@ -0,0 +1,212 @@
+use crate::{
+    AppState, Renderer,
+    system::{input_handler::InputHandler, page_system::PageData}
+};
+use std::{
+    env,
+    fmt::Debug,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf}
+};
+
+/// Frames rendered at most while waiting for a frozen transition to reach its stage
+const MAX_SETTLE_FRAMES: usize = 8;
+
+/// A Rendered Frame As Tightly Packed RGBA8 Pixels (4 Bytes Per Pixel, Row By Row)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaFrame
+{
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>
+}
+
+impl RgbaFrame
+{
+    /// Create A Frame From Raw RGBA8 Pixels, The Buffer Must Hold Exactly width * height * 4 Bytes
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Result<Self, String>
+    {
+        let expected_len = width as usize * height as usize * 4;
+        if pixels.len() != expected_len
+        {
+            return Err(format!("RgbaFrame expected {} bytes for {}x{}, got {}", expected_len, width, height, pixels.len()));
+        }
+        Ok(Self { width, height, pixels })
+    }
+
+    /// Returns The (r, g, b, a) Value Of The Pixel At (x, y), If Inside The Frame
+    pub fn pixel(&self, x: u32, y: u32) -> Option<(u8, u8, u8, u8)>
+    {
+        if x >= self.width || y >= self.height
+        {
+            return None;
+        }
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        Some((self.pixels[index], self.pixels[index + 1], self.pixels[index + 2], self.pixels[index + 3]))
+    }
+
+    /// Load A Frame From A PNG File, RGB And Grayscale Images Are Expanded To RGBA
+    pub fn load_png<P: AsRef<Path>>(path: P) -> Result<Self, String>
+    {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|err| format!("Failed to open '{}': {}", path.display(), err))?;
+        let mut decoder = png::Decoder::new(BufReader::new(file));
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+        let mut reader = decoder.read_info().map_err(|err| format!("Failed to read PNG info for '{}': {}", path.display(), err))?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).map_err(|err| format!("Failed to decode PNG '{}': {}", path.display(), err))?;
+        buffer.truncate(info.buffer_size());
+
+        let pixels = match info.color_type
+        {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer.chunks_exact(3).flat_map(|px| [px[0], px[1], px[2], 255]).collect(),
+            png::ColorType::GrayscaleAlpha => buffer.chunks_exact(2).flat_map(|px| [px[0], px[0], px[0], px[1]]).collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|px| [*px, *px, *px, 255]).collect(),
+            png::ColorType::Indexed => return Err(format!("Unexpected indexed PNG after expansion: '{}'", path.display()))
+        };
+        Self::new(info.width, info.height, pixels)
+    }
+
+    /// Compare Against Another Frame, A Pixel Only Counts As Mismatched When One
+    /// Of Its Channels Differs By More Than `tolerance`
+    pub fn compare(&self, other: &RgbaFrame, tolerance: u8) -> SnapshotDiff
+    {
+        if self.width != other.width || self.height != other.height
+        {
+            return SnapshotDiff { size_matches: false, mismatched_pixels: self.pixels.len().max(other.pixels.len()) / 4, max_channel_difference: u8::MAX };
+        }
+        let mut diff = SnapshotDiff { size_matches: true, mismatched_pixels: 0, max_channel_difference: 0 };
+        for (pixel, other_pixel) in self.pixels.chunks_exact(4).zip(other.pixels.chunks_exact(4))
+        {
+            let pixel_difference = pixel.iter().zip(other_pixel).map(|(a, b)| a.abs_diff(*b)).max().unwrap_or(0);
+            diff.max_channel_difference = diff.max_channel_difference.max(pixel_difference);
+            if pixel_difference > tolerance
+            {
+                diff.mismatched_pixels += 1;
+            }
+        }
+        diff
+    }
+
+    /// Write The Frame To A PNG File
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> Result<(), String>
+    {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent).map_err(|err| format!("Failed to create directory '{}': {}", parent.display(), err))?;
+        }
+        let file = File::create(path).map_err(|err| format!("Failed to create '{}': {}", path.display(), err))?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(|err| format!("Failed to write PNG header for '{}': {}", path.display(), err))?;
+        writer.write_image_data(&self.pixels).map_err(|err| format!("Failed to write PNG data for '{}': {}", path.display(), err))
+    }
+}
+
+/// Result Of Comparing Two Frames
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotDiff
+{
+    pub size_matches: bool,
+    pub mismatched_pixels: usize,
+    pub max_channel_difference: u8
+}
+
+/// Configures How Strict A Snapshot Comparison Is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotConfig
+{
+    /// Maximum difference allowed per color channel before a pixel counts as mismatched
+    pub tolerance: u8,
+    /// How many mismatched pixels are accepted before the snapshot fails
+    pub max_mismatched_pixels: usize,
+    /// Overwrite the reference images instead of comparing against them
+    pub update_references: bool
+}
+
+impl Default for SnapshotConfig
+{
+    /// Exact comparison, references are rewritten when the `RPS_UPDATE_SNAPSHOTS=1` env var is set
+    fn default() -> Self
+    {
+        Self { tolerance: 0, max_mismatched_pixels: 0, update_references: env::var("RPS_UPDATE_SNAPSHOTS").is_ok_and(|value| value == "1") }
+    }
+}
+
+/// Compare A Frame With The Reference PNG At `reference_path`.
+/// With `update_references` the frame is written as the new reference, otherwise a missing reference is an error.
+/// On failure the frame is saved next to the reference as `<name>.actual.png`.
+pub fn compare_with_reference<P: AsRef<Path>>(frame: &RgbaFrame, reference_path: P, config: &SnapshotConfig) -> Result<SnapshotDiff, String>
+{
+    let reference_path = reference_path.as_ref();
+    if config.update_references
+    {
+        frame.save_png(reference_path)?;
+        return Ok(SnapshotDiff { size_matches: true, mismatched_pixels: 0, max_channel_difference: 0 });
+    }
+    if !reference_path.exists()
+    {
+        return Err(format!("Snapshot reference '{}' is missing, run with RPS_UPDATE_SNAPSHOTS=1 to create it", reference_path.display()));
+    }
+
+    let reference = RgbaFrame::load_png(reference_path)?;
+    let diff = frame.compare(&reference, config.tolerance);
+    if !diff.size_matches || diff.mismatched_pixels > config.max_mismatched_pixels
+    {
+        let actual_path = actual_frame_path(reference_path);
+        frame.save_png(&actual_path)?;
+        return Err(format!("Snapshot '{}' does not match: {} mismatched pixels (max allowed {}), size matches: {}, max channel difference: {}, actual frame saved to '{}'", reference_path.display(), diff.mismatched_pixels, config.max_mismatched_pixels, diff.size_matches, diff.max_channel_difference, actual_path.display()));
+    }
+    Ok(diff)
+}
+
+/// Build The Current Page, Render It And Compare It With The Reference PNG
+pub fn compare_page_snapshot<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug, P: AsRef<Path>>(renderer: &mut Renderer<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, input_handler: &InputHandler<PageId, ButtonId>, reference_path: P, config: &SnapshotConfig) -> Result<SnapshotDiff, String>
+{
+    page_data.create_current_page(app_state);
+    let frame = renderer.capture_frame(page_data, app_state, input_handler)?;
+    compare_with_reference(&frame, reference_path, config)
+}
+
+/// Freeze The Active Scene Transition At `progress` (0.0 to 1.0) And Capture The Frame Showing It
+pub fn capture_transition_frame<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug>(renderer: &mut Renderer<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, input_handler: &InputHandler<PageId, ButtonId>, progress: f32) -> Result<RgbaFrame, String>
+{
+    let Some(transition) = &mut app_state.scene_transition
+    else
+    {
+        return Err("No active scene transition to capture".to_string());
+    };
+    transition.freeze_at(progress);
+
+    // A transition takes a few frames to reach its stage (start, switch page, draw the new page)
+    for _ in 0..MAX_SETTLE_FRAMES
+    {
+        let settled = app_state.scene_transition.as_ref().is_none_or(|transition| transition.is_settled());
+        page_data.create_current_page(app_state);
+        if settled
+        {
+            return renderer.capture_frame(page_data, app_state, input_handler);
+        }
+        renderer.render(page_data, app_state, input_handler);
+    }
+    Err(format!("Scene transition did not settle at progress {} after {} frames", progress, MAX_SETTLE_FRAMES))
+}
+
+/// Capture The Active Scene Transition At `progress` And Compare It With The Reference PNG
+pub fn compare_transition_snapshot<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug, P: AsRef<Path>>(renderer: &mut Renderer<PageId, ButtonId>, page_data: &mut PageData<PageId, ButtonId>, app_state: &mut AppState<PageId, ButtonId>, input_handler: &InputHandler<PageId, ButtonId>, progress: f32, reference_path: P, config: &SnapshotConfig) -> Result<SnapshotDiff, String>
+{
+    let frame = capture_transition_frame(renderer, page_data, app_state, input_handler, progress)?;
+    compare_with_reference(&frame, reference_path, config)
+}
+
+/// `snapshots/page.png` -> `snapshots/page.actual.png`
+fn actual_frame_path(reference_path: &Path) -> PathBuf
+{
+    let stem = reference_path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+    reference_path.with_file_name(format!("{}.actual.png", stem))
+}
@@ -1,6 +1,7 @@
 use crate::{
     system::page_system::PageData,
-    system::scene_transition::{SceneTransition, TransitionType}
+    system::scene_transition::{SceneTransition, TransitionType},
+    system::window::WINDOW_DEFAULT_SCALE
 };
 use std::fmt::Debug;
 
@@ -103,3 +104,12 @@ impl<PageId: Copy + Eq + Debug, ButtonId: Copy + Eq + Debug> AppState<PageId, Bu
         self.capturing_input.0
     }
 }
+
+impl<PageId, ButtonId> AppState<PageId, ButtonId>
+{
+    /// Mouse Position In The WINDOW_DEFAULT_SCALE Coordinates Pages Are Laid Out In (The Window Can Be Any Size In Stretch Mode)
+    pub fn logical_mouse_position(&self, (mouse_x, mouse_y): (f32, f32)) -> (f32, f32)
+    {
+        if self.stretch_mode_is_on { (mouse_x * (WINDOW_DEFAULT_SCALE.0 as f32 / self.window_size.0 as f32), mouse_y * (WINDOW_DEFAULT_SCALE.1 as f32 / self.window_size.1 as f32)) } else { (mouse_x, mouse_y) }
+    }
+}
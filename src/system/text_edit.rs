@@ -0,0 +1,114 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Clamp `position` To The Text And Move It Back To The Closest Grapheme Cluster Boundary,
+/// So A Stale Or Foreign Byte Index Can Always Be Used To Slice Or Edit The Text
+pub fn snap_to_grapheme(text: &str, position: usize) -> usize
+{
+    if position >= text.len()
+    {
+        return text.len();
+    }
+    text.grapheme_indices(true).map(|(grapheme_start, _)| grapheme_start).take_while(|grapheme_start| *grapheme_start <= position).last().unwrap_or(0)
+}
+
+/// Byte Index Of The Grapheme Cluster Boundary Before `position` (0 At The Start)
+pub fn previous_grapheme(text: &str, position: usize) -> usize
+{
+    let position = snap_to_grapheme(text, position);
+    text[..position].grapheme_indices(true).next_back().map_or(0, |(grapheme_start, _)| grapheme_start)
+}
+
+/// Byte Index Of The Grapheme Cluster Boundary After `position` (The Text Length At The End)
+pub fn next_grapheme(text: &str, position: usize) -> usize
+{
+    let position = snap_to_grapheme(text, position);
+    text[position..].graphemes(true).next().map_or(text.len(), |grapheme| position + grapheme.len())
+}
+
+/// Number Of Chars Before The Byte Index `position`, What The Text Layout Functions Expect
+pub fn char_index(text: &str, position: usize) -> usize
+{
+    text[..snap_to_grapheme(text, position)].chars().count()
+}
+
+/// Byte Index Where The Line Holding `position` Starts
+pub fn line_start(text: &str, position: usize) -> usize
+{
+    text[..snap_to_grapheme(text, position)].rfind('\n').map_or(0, |newline_index| newline_index + 1)
+}
+
+/// Byte Index Of The End Of The Line Holding `position` (The '\n' Or The End Of The Text)
+pub fn line_end(text: &str, position: usize) -> usize
+{
+    let position = snap_to_grapheme(text, position);
+    text[position..].find('\n').map_or(text.len(), |newline_index| position + newline_index)
+}
+
+/// Move `lines` Lines Down (Or Up When Negative) Keeping The Column (In Grapheme Clusters), Going Past
+/// The First Or Last Line Moves To The Start Or End Of The Text Like Most Text Editors
+pub fn move_by_lines(text: &str, position: usize, lines: isize) -> usize
+{
+    let position = snap_to_grapheme(text, position);
+    let mut target_line_start = line_start(text, position);
+    let column = text[target_line_start..position].graphemes(true).count();
+    for _ in 0..lines.unsigned_abs()
+    {
+        if lines < 0
+        {
+            if target_line_start == 0
+            {
+                return 0;
+            }
+            target_line_start = line_start(text, target_line_start - 1);
+        }
+        else
+        {
+            let target_line_end = line_end(text, target_line_start);
+            if target_line_end == text.len()
+            {
+                return text.len();
+            }
+            target_line_start = target_line_end + 1;
+        }
+    }
+    let target_line_end = line_end(text, target_line_start);
+    text[target_line_start..target_line_end].grapheme_indices(true).nth(column).map_or(target_line_end, |(grapheme_start, _)| target_line_start + grapheme_start)
+}
+
+/// Byte Index Where The Word Before `position` Starts, Skipping The Spaces And Punctuation In Between (Ctrl+Left)
+pub fn previous_word_start(text: &str, position: usize) -> usize
+{
+    let position = snap_to_grapheme(text, position);
+    text.split_word_bound_indices().rev().find(|(segment_start, segment)| *segment_start < position && is_word(segment)).map_or(0, |(segment_start, _)| segment_start)
+}
+
+/// Byte Index Where The Word After `position` Ends, Skipping The Spaces And Punctuation In Between (Ctrl+Right)
+pub fn next_word_end(text: &str, position: usize) -> usize
+{
+    let position = snap_to_grapheme(text, position);
+    text.split_word_bound_indices().map(|(segment_start, segment)| (segment_start + segment.len(), segment)).find(|(segment_end, segment)| *segment_end > position && is_word(segment)).map_or(text.len(), |(segment_end, _)| segment_end)
+}
+
+/// Byte Range Of The Word (Or Run Of Spaces And Punctuation) At `position`, What A Double Click Selects.
+/// A position right after a word picks that word rather than what follows it.
+pub fn word_bounds(text: &str, position: usize) -> (usize, usize)
+{
+    let position = snap_to_grapheme(text, position);
+    let segments: Vec<(usize, &str)> = text.split_word_bound_indices().collect();
+    if segments.is_empty()
+    {
+        return (0, 0);
+    }
+    let mut segment_index = segments.iter().position(|(segment_start, segment)| position < segment_start + segment.len()).unwrap_or(segments.len() - 1);
+    if segment_index > 0 && segments[segment_index].0 == position && !is_word(segments[segment_index].1) && is_word(segments[segment_index - 1].1)
+    {
+        segment_index -= 1;
+    }
+    let (segment_start, segment) = segments[segment_index];
+    (segment_start, segment_start + segment.len())
+}
+
+fn is_word(segment: &str) -> bool
+{
+    segment.chars().any(char::is_alphanumeric)
+}
@@ -0,0 +1,324 @@
+use crate::system::page_system::TextBox;
+use sdl3::{
+    rect::Rect,
+    ttf::{Font, Sdl3TtfContext}
+};
+use std::{cell::RefCell, collections::HashMap};
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "page-files", derive(serde::Deserialize))]
+pub enum HorizontalAlign
+{
+    #[default]
+    Left,
+    Center,
+    Right
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "page-files", derive(serde::Deserialize))]
+pub enum VerticalAlign
+{
+    #[default]
+    Top,
+    Middle,
+    Bottom
+}
+
+/// Width In Pixels Of The Input Caret
+pub const CARET_WIDTH: i32 = 2;
+
+/// Measures Text In Pixels, Implemented By FontMeasurer For The Configured Font.
+/// Layout code only depends on this trait, so it can be tested without loading a font.
+pub trait TextMeasurer
+{
+    /// (width, height) of `text` on a single line
+    fn text_size(&self, text: &str, font_size: f64) -> (u32, u32);
+    /// Distance between two consecutive lines
+    fn line_height(&self, font_size: f64) -> u32;
+}
+
+/// TextMeasurer Backed By SDL_ttf, Loading Each Font Size Once
+pub struct FontMeasurer<'a>
+{
+    ttf_context: &'a Sdl3TtfContext,
+    font_path: &'a str,
+    fonts: RefCell<HashMap<u64, Font<'a>>>
+}
+
+impl<'a> FontMeasurer<'a>
+{
+    /// Use The Same ttf_context And font_path Given To The Renderer (From WindowModules)
+    pub fn new(ttf_context: &'a Sdl3TtfContext, font_path: &'a str) -> Self
+    {
+        Self { ttf_context, font_path, fonts: RefCell::new(HashMap::new()) }
+    }
+
+    fn with_font<T>(&self, font_size: f64, use_font: impl FnOnce(&Font<'a>) -> T) -> T
+    {
+        let mut fonts = self.fonts.borrow_mut();
+        let font = fonts.entry(font_size.to_bits()).or_insert_with(|| self.ttf_context.load_font(self.font_path, font_size as f32).expect("Failed to load font for text measurement"));
+        use_font(font)
+    }
+}
+
+impl TextMeasurer for FontMeasurer<'_>
+{
+    fn text_size(&self, text: &str, font_size: f64) -> (u32, u32)
+    {
+        self.with_font(font_size, |font| font.size_of(text).unwrap_or((0, 0)))
+    }
+
+    fn line_height(&self, font_size: f64) -> u32
+    {
+        self.with_font(font_size, |font| font.height().max(0) as u32)
+    }
+}
+
+/// One Line Of A Laid Out TextBox, `char_range` Is The (start, end) Character Index Of The Line In The TextBox Text
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLine
+{
+    pub text: String,
+    pub rect: Rect,
+    pub char_range: (usize, usize)
+}
+
+/// A Place The Caret Can Go: The Byte Index In The Input Text, Its X Position And The Rect Of Its Line On Screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaretStop
+{
+    pub byte_index: usize,
+    pub x: i32,
+    pub line_rect: Rect
+}
+
+/// Split The TextBox Text On `\n` (And On Word Boundaries When `max_width` Is Set) And Place Every Line Inside `text_box.rect` Following Its Alignment
+pub fn layout_text_box<ButtonId>(measurer: &dyn TextMeasurer, text_box: &TextBox<ButtonId>) -> Vec<TextLine>
+{
+    let line_height = measurer.line_height(text_box.font_size);
+    let mut lines = Vec::new();
+    let mut paragraph_start = 0;
+    for paragraph in text_box.text.split('\n')
+    {
+        let paragraph_chars: Vec<char> = paragraph.chars().collect();
+        let line_ranges = match text_box.max_width
+        {
+            Some(max_width) => wrap_paragraph(measurer, &paragraph_chars, text_box.font_size, max_width),
+            None => vec![(0, paragraph_chars.len())]
+        };
+        for (start, end) in line_ranges
+        {
+            lines.push((paragraph_chars[start..end].iter().collect::<String>(), (paragraph_start + start, paragraph_start + end)));
+        }
+        // +1 skips the '\n'
+        paragraph_start += paragraph_chars.len() + 1;
+    }
+
+    // Text taller than the rect starts at its top, so the first lines are never pushed out
+    let free_height = (text_box.rect.height() as i32 - line_height as i32 * lines.len() as i32).max(0);
+    let mut line_y = match text_box.vertical_align
+    {
+        VerticalAlign::Top => text_box.rect.y(),
+        VerticalAlign::Middle => text_box.rect.y() + free_height / 2,
+        VerticalAlign::Bottom => text_box.rect.y() + free_height
+    };
+
+    let mut text_lines = Vec::with_capacity(lines.len());
+    for (line, char_range) in lines
+    {
+        // Spaces left at the end of a wrapped line don't count for the alignment
+        let line_width = visible_width(measurer, &line, text_box.font_size);
+        let line_x = match text_box.horizontal_align
+        {
+            HorizontalAlign::Left => text_box.rect.x(),
+            HorizontalAlign::Center => text_box.rect.x() + (text_box.rect.width() as i32 - line_width as i32) / 2,
+            HorizontalAlign::Right => text_box.rect.x() + text_box.rect.width() as i32 - line_width as i32
+        };
+        text_lines.push(TextLine { text: line, rect: Rect::new(line_x, line_y, line_width, line_height), char_range });
+        line_y += line_height as i32;
+    }
+    text_lines
+}
+
+fn visible_width(measurer: &dyn TextMeasurer, text: &str, font_size: f64) -> u32
+{
+    let trimmed = text.trim_end();
+    if trimmed.is_empty() { 0 } else { measurer.text_size(trimmed, font_size).0 }
+}
+
+/// (start, end) Character Ranges Of The Lines A Paragraph Is Wrapped Into, Each Word Keeps Its Trailing Spaces
+fn wrap_paragraph(measurer: &dyn TextMeasurer, chars: &[char], font_size: f64, max_width: u32) -> Vec<(usize, usize)>
+{
+    let width_of = |start: usize, end: usize| visible_width(measurer, &chars[start..end].iter().collect::<String>(), font_size);
+    let mut line_ranges = Vec::new();
+    let mut line_start = 0;
+    let mut line_end = 0;
+    while line_end < chars.len()
+    {
+        let mut word_end = line_end;
+        while word_end < chars.len() && !chars[word_end].is_whitespace()
+        {
+            word_end += 1;
+        }
+        while word_end < chars.len() && chars[word_end].is_whitespace()
+        {
+            word_end += 1;
+        }
+
+        if width_of(line_start, word_end) <= max_width
+        {
+            line_end = word_end;
+        }
+        else if line_end > line_start
+        {
+            // The word goes to the next line
+            line_ranges.push((line_start, line_end));
+            line_start = line_end;
+        }
+        else
+        {
+            // The word alone is wider than max_width, break it between characters (at least one per line)
+            let mut break_at = line_start + 1;
+            while break_at < word_end && width_of(line_start, break_at + 1) <= max_width
+            {
+                break_at += 1;
+            }
+            line_ranges.push((line_start, break_at));
+            line_start = break_at;
+            line_end = break_at;
+        }
+    }
+    if line_start < chars.len() || line_ranges.is_empty()
+    {
+        line_ranges.push((line_start, chars.len()));
+    }
+    line_ranges
+}
+
+/// X Position Of The Caret Placed Before `char_index` (Index In The Whole TextBox Text) On `line`
+pub fn caret_x(measurer: &dyn TextMeasurer, line: &TextLine, font_size: f64, char_index: usize) -> i32
+{
+    let chars_in_line = char_index.clamp(line.char_range.0, line.char_range.1) - line.char_range.0;
+    let prefix: String = line.text.chars().take(chars_in_line).collect();
+    let prefix_width = if prefix.is_empty() { 0 } else { measurer.text_size(&prefix, font_size).0 };
+    line.rect.x() + prefix_width as i32
+}
+
+/// Caret Stops Of Every Grapheme Cluster Boundary Of A Laid Out TextBox, A Boundary Between Two Wrapped Lines Gets One On Each
+pub fn caret_stops<ButtonId>(measurer: &dyn TextMeasurer, text_box: &TextBox<ButtonId>) -> Vec<CaretStop>
+{
+    let mut boundaries = Vec::new();
+    let mut chars_before = 0;
+    for (grapheme_start, grapheme) in text_box.text.grapheme_indices(true)
+    {
+        boundaries.push((grapheme_start, chars_before));
+        chars_before += grapheme.chars().count();
+    }
+    boundaries.push((text_box.text.len(), chars_before));
+
+    let mut stops = Vec::with_capacity(boundaries.len());
+    for line in layout_text_box(measurer, text_box)
+    {
+        for (byte_index, char_index) in boundaries.iter().filter(|(_, char_index)| (line.char_range.0..=line.char_range.1).contains(char_index))
+        {
+            stops.push(CaretStop { byte_index: *byte_index, x: caret_x(measurer, &line, text_box.font_size, *char_index), line_rect: line.rect });
+        }
+    }
+    stops
+}
+
+/// Byte Index Of The Caret Stop Closest To `point`, Looking At The Nearest Line First And Then At The Nearest X On It
+pub fn caret_stop_at(caret_stops: &[CaretStop], point: (i32, i32)) -> Option<usize>
+{
+    caret_stops
+        .iter()
+        .min_by_key(|stop| {
+            let line_top = stop.line_rect.y();
+            let line_bottom = line_top + stop.line_rect.height() as i32;
+            let distance_y = if point.1 < line_top { line_top - point.1 } else { (point.1 - line_bottom + 1).max(0) };
+            (distance_y, (point.0 - stop.x).abs())
+        })
+        .map(|stop| stop.byte_index)
+}
+
+/// Index Of The Line Holding The Caret At `char_index`, A Caret Between Two Wrapped Lines Stays At The End Of The First
+pub fn line_of_char(lines: &[TextLine], char_index: usize) -> usize
+{
+    lines.iter().position(|line| char_index <= line.char_range.1).unwrap_or(lines.len().saturating_sub(1))
+}
+
+/// Horizontal Scroll (In Pixels) Of A Single Line Input So The Caret At `char_index` Stays Inside `text_box.rect`.
+/// The previous scroll is kept while the caret is still visible, 0 is returned when the text fits, wraps or has many lines.
+pub fn horizontal_scroll<ButtonId>(measurer: &dyn TextMeasurer, text_box: &TextBox<ButtonId>, char_index: usize, previous_scroll: i32) -> i32
+{
+    if text_box.max_width.is_some() || text_box.text.contains('\n')
+    {
+        return 0;
+    }
+    // Keeps room for the 2px wide caret at the end of the text
+    let view_width = text_box.rect.width() as i32 - CARET_WIDTH;
+    let text_width = visible_width(measurer, &text_box.text, text_box.font_size) as i32;
+    if text_width <= view_width
+    {
+        return 0;
+    }
+    let prefix: String = text_box.text.chars().take(char_index).collect();
+    let caret_position = if prefix.is_empty() { 0 } else { measurer.text_size(&prefix, text_box.font_size).0 as i32 };
+    let scroll = if caret_position < previous_scroll
+    {
+        caret_position
+    }
+    else if caret_position - previous_scroll > view_width
+    {
+        caret_position - view_width
+    }
+    else
+    {
+        previous_scroll
+    };
+    scroll.clamp(0, text_width - view_width)
+}
+
+/// Vertical Scroll (In Pixels) Of A Text Area So The Line Holding The Caret At `char_index` Stays Inside `text_box.rect`.
+/// The previous scroll is kept while the caret line is still visible, 0 is returned when every line fits.
+pub fn vertical_scroll<ButtonId>(measurer: &dyn TextMeasurer, text_box: &TextBox<ButtonId>, char_index: usize, previous_scroll: i32) -> i32
+{
+    let lines = layout_text_box(measurer, text_box);
+    let line_height = measurer.line_height(text_box.font_size) as i32;
+    let view_height = text_box.rect.height() as i32;
+    let block_height = line_height * lines.len() as i32;
+    if block_height <= view_height
+    {
+        return 0;
+    }
+    let caret_top = line_height * line_of_char(&lines, char_index) as i32;
+    let scroll = if caret_top < previous_scroll
+    {
+        caret_top
+    }
+    else if caret_top + line_height - previous_scroll > view_height
+    {
+        caret_top + line_height - view_height
+    }
+    else
+    {
+        previous_scroll
+    };
+    scroll.clamp(0, block_height - view_height)
+}
+
+/// Copy Of A TextBox Moved `scroll.0` Pixels To The Left And `scroll.1` Pixels Up, Clipped To `clip_rect` (Or Its Own Rect).
+/// A scrolled axis is aligned to the left or top, so the scroll is measured from the start of the text.
+pub fn scrolled_text_box<ButtonId: Clone>(text_box: &TextBox<ButtonId>, scroll: (i32, i32)) -> TextBox<ButtonId>
+{
+    if scroll == (0, 0)
+    {
+        return text_box.clone();
+    }
+    let rect = text_box.rect;
+    let horizontal_align = if scroll.0 != 0 { HorizontalAlign::Left } else { text_box.horizontal_align };
+    let vertical_align = if scroll.1 != 0 { VerticalAlign::Top } else { text_box.vertical_align };
+    TextBox { rect: Rect::new(rect.x() - scroll.0, rect.y() - scroll.1, rect.width() + scroll.0 as u32, rect.height() + scroll.1 as u32), horizontal_align, vertical_align, clip_rect: Some(text_box.clip_rect.unwrap_or(rect)), ..text_box.clone() }
+}
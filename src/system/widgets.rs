@@ -0,0 +1,162 @@
+use sdl3::{pixels::Color, rect::Rect};
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum WidgetKind
+{
+    Checkbox,
+    Toggle,
+    /// Checking a radio unchecks the others of the same group on its page
+    Radio(u32),
+    /// Dragged, clicked or moved with Left/Right between min and max, in multiples of step (0 for a continuous slider)
+    Slider
+    {
+        min: f32,
+        max: f32,
+        step: f32
+    },
+    /// Shows a value from 0 to 1 set by the app with PageData::set_widget_value, it ignores clicks and keys
+    ProgressBar
+}
+
+/// Extra Behavior And Drawing Of A Button, Declared In Page::has_widgets Like Inputs Are In has_userinput
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Widget
+{
+    pub kind: WidgetKind,
+    /// Check mark, knob and fill color, the button color is used for the box or track
+    pub accent_color: Color
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum WidgetValue
+{
+    /// Checkboxes, toggles and radios
+    Checked(bool),
+    /// Sliders and progress bars
+    Number(f32)
+}
+
+impl WidgetValue
+{
+    pub fn is_checked(&self) -> bool
+    {
+        matches!(self, WidgetValue::Checked(true))
+    }
+
+    pub fn number(&self) -> f32
+    {
+        match self
+        {
+            WidgetValue::Number(number) => *number,
+            WidgetValue::Checked(checked) => *checked as u8 as f32
+        }
+    }
+}
+
+impl Widget
+{
+    /// Unchecked, Or At The Minimum Of A Slider Or Progress Bar
+    pub fn default_value(&self) -> WidgetValue
+    {
+        match self.kind
+        {
+            WidgetKind::Checkbox | WidgetKind::Toggle | WidgetKind::Radio(_) => WidgetValue::Checked(false),
+            WidgetKind::Slider { min, .. } => WidgetValue::Number(min),
+            WidgetKind::ProgressBar => WidgetValue::Number(0.)
+        }
+    }
+
+    /// The Closest Value This Widget Can Hold: Numbers Are Clamped (And Snapped To The Slider Step) And Checkboxes Stay Booleans
+    pub fn normalize(&self, value: WidgetValue) -> WidgetValue
+    {
+        match self.kind
+        {
+            WidgetKind::Checkbox | WidgetKind::Toggle | WidgetKind::Radio(_) => WidgetValue::Checked(value.is_checked() || value.number() >= 0.5),
+            WidgetKind::Slider { min, max, step } =>
+            {
+                let number = value.number().clamp(min, max);
+                WidgetValue::Number(if step > 0. { (min + ((number - min) / step).round() * step).min(max) } else { number })
+            }
+            WidgetKind::ProgressBar => WidgetValue::Number(value.number().clamp(0., 1.))
+        }
+    }
+
+    /// How Much Of The Track Is Filled (0 To 1) For Sliders And Progress Bars
+    pub fn fraction(&self, value: WidgetValue) -> f32
+    {
+        match self.kind
+        {
+            WidgetKind::Slider { min, max, .. } if max > min => ((value.number() - min) / (max - min)).clamp(0., 1.),
+            WidgetKind::ProgressBar => value.number().clamp(0., 1.),
+            _ => 0.
+        }
+    }
+
+    /// Slider Value For The Mouse At `mouse_x` Over A Slider Drawn In `rect`
+    pub fn value_at(&self, rect: Rect, mouse_x: f32) -> WidgetValue
+    {
+        match self.kind
+        {
+            WidgetKind::Slider { min, max, .. } =>
+            {
+                let fraction = ((mouse_x - rect.x() as f32) / rect.width() as f32).clamp(0., 1.);
+                self.normalize(WidgetValue::Number(min + fraction * (max - min)))
+            }
+            _ => self.default_value()
+        }
+    }
+
+    /// The Value One Left/Right Key Press Away From `value`, A Step Or 1% Of A Continuous Slider
+    pub fn nudged(&self, value: WidgetValue, forward: bool) -> WidgetValue
+    {
+        match self.kind
+        {
+            WidgetKind::Slider { min, max, step } =>
+            {
+                let step = if step > 0. { step } else { (max - min) / 100. };
+                self.normalize(WidgetValue::Number(value.number() + if forward { step } else { -step }))
+            }
+            _ => value
+        }
+    }
+
+    /// What Is Drawn Over The Button (Shown At `rect` With `radius`) For `value`: The Check Mark, Toggle Knob Or Slider And Progress Fill,
+    /// Each With Its Radius And Color (accent_color, Dimmed For What Is Off Or Behind The Knob)
+    pub fn marks(&self, rect: Rect, radius: i32, value: WidgetValue) -> Vec<(Rect, i32, Color)>
+    {
+        let dimmed_color = Color::RGBA(self.accent_color.r, self.accent_color.g, self.accent_color.b, self.accent_color.a / 3);
+        let (x, y, w, h) = (rect.x(), rect.y(), rect.width() as i32, rect.height() as i32);
+        let fill_width = (w as f32 * self.fraction(value)).round() as i32;
+        match self.kind
+        {
+            WidgetKind::Checkbox | WidgetKind::Radio(_) if value.is_checked() =>
+            {
+                let inset = w.min(h) / 4;
+                let mark_radius = if matches!(self.kind, WidgetKind::Radio(_)) { (w.min(h) - 2 * inset) / 2 } else { inset / 2 };
+                vec![(Rect::new(x + inset, y + inset, (w - 2 * inset) as u32, (h - 2 * inset) as u32), mark_radius, self.accent_color)]
+            }
+            WidgetKind::Checkbox | WidgetKind::Radio(_) => Vec::new(),
+            WidgetKind::Toggle =>
+            {
+                let padding = (h / 8).max(2);
+                let knob_size = h - 2 * padding;
+                let knob_x = if value.is_checked() { x + w - padding - knob_size } else { x + padding };
+                vec![(Rect::new(knob_x, y + padding, knob_size as u32, knob_size as u32), knob_size / 2, if value.is_checked() { self.accent_color } else { dimmed_color })]
+            }
+            WidgetKind::Slider { .. } =>
+            {
+                let knob_width = (h / 2).clamp(4, w.max(4));
+                let knob_x = (x + fill_width - knob_width / 2).clamp(x, x + w - knob_width);
+                let mut marks = Vec::new();
+                if fill_width > 0
+                {
+                    marks.push((Rect::new(x, y, fill_width as u32, h as u32), radius.min(fill_width / 2), dimmed_color));
+                }
+                marks.push((Rect::new(knob_x, y, knob_width as u32, h as u32), radius.min(knob_width / 2), self.accent_color));
+                marks
+            }
+            WidgetKind::ProgressBar if fill_width > 0 => vec![(Rect::new(x, y, fill_width as u32, h as u32), radius.min(fill_width / 2), self.accent_color)],
+            WidgetKind::ProgressBar => Vec::new()
+        }
+    }
+}
@@ -121,6 +121,17 @@ pub fn create_window(window_config: WindowConfig) -> WindowModules
     canvas.set_blend_mode(sdl3::render::BlendMode::Blend);
     WindowModules { sdl_init, canvas, event_pump, texture_creator, ttf_context, font_path, clipboard_system, stretch_mode_status }
 }
+/// Create The Window Modules Without Needing A Display, Using SDL's "offscreen" Video Driver And
+/// The Software Renderer. The Window Is Forced To WINDOW_DEFAULT_SCALE, So Captured Frames Map 1:1 To
+/// The Logical Canvas, Useful For CI, Servers And Snapshot Tests
+pub fn create_headless_window(window_config: WindowConfig) -> WindowModules
+{
+    // Hints Must Be Set Before SDL Is Initialized
+    sdl3::hint::set("SDL_VIDEO_DRIVER", "offscreen");
+    sdl3::hint::set("SDL_RENDER_DRIVER", "software");
+    create_window(WindowConfig { start_window_size: WINDOW_DEFAULT_SCALE, window_minimum_size: WINDOW_DEFAULT_SCALE, resizable: false, ..window_config })
+}
+
 pub fn get_monitor_refresh_rate() -> u64
 {
     let display_infos = DisplayInfo::all().unwrap();
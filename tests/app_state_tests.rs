@@ -1,8 +1,11 @@
 use rust_page_system::system::{
-    input_handler::InputHandler,
-    page_system::{Button, Page, PageData},
+    edit_history::{EditHistory, EditKind, EditSnapshot},
+    input_handler::{InputEvent, InputHandler},
+    input_source::MemoryClipboard,
+    page_system::{Button, ButtonStyle, Page, PageData},
     renderer::Renderer,
-    state::AppState
+    state::AppState,
+    text_layout::CaretStop
 };
 use sdl3::pixels::Color;
 use sdl3::rect::Rect;
@@ -26,7 +29,8 @@ enum TestPage
 enum TestButton
 {
     A,
-    B
+    B,
+    C
 }
 
 fn create_state() -> (AppState<TestPage, TestButton>, PageData<TestPage, TestButton>)
@@ -121,7 +125,7 @@ fn page_data_adds_unique_user_inputs_without_duplicates()
 {
     let (_application_state, mut page_data) = create_state();
 
-    let mut single_input_page = Page { has_persistent_elements: None, has_userinput: Some(vec![(TestPage::Home, TestButton::A)]), id: TestPage::Home, background_color: None, rects: None, buttons: None, texts: None, images: None };
+    let mut single_input_page = Page { has_userinput: Some(vec![(TestPage::Home, TestButton::A)]), ..Page::new(TestPage::Home) };
 
     page_data.push_vec_user_input_per_page(&mut single_input_page);
     assert_eq!(page_data.vec_user_input.len(), 1);
@@ -148,9 +152,9 @@ fn page_data_button_detection_within_bounds()
 {
     let (application_state, mut page_data) = create_state();
 
-    let clickable_button = Button { enabled: true, color: Color::RGB(255, 0, 0), rect: Rect::new(10, 10, 100, 50), radius: 4, id: TestButton::A, has_transition: None };
+    let clickable_button = Button { radius: 4, ..Button::new(TestButton::A, Color::RGB(255, 0, 0), Rect::new(10, 10, 100, 50)) };
 
-    let page_with_button = Page { has_persistent_elements: None, has_userinput: None, id: TestPage::Home, background_color: None, rects: None, buttons: Some(vec![clickable_button]), texts: None, images: None };
+    let page_with_button = Page { buttons: Some(vec![clickable_button]), ..Page::new(TestPage::Home) };
 
     page_data.page_to_render = Some(page_with_button);
     assert_eq!(page_data.page_button_at(&application_state, 50.0, 30.0), Some(TestButton::A));
@@ -161,14 +165,33 @@ fn page_data_button_detection_out_of_bounds_returns_none()
 {
     let (application_state, mut page_data) = create_state();
 
-    let button = Button { enabled: true, color: Color::RGB(0, 0, 0), rect: Rect::new(10, 10, 40, 40), radius: 0, id: TestButton::A, has_transition: None };
+    let button = Button::new(TestButton::A, Color::RGB(0, 0, 0), Rect::new(10, 10, 40, 40));
 
-    let page = Page { has_persistent_elements: None, has_userinput: None, id: TestPage::Home, background_color: None, rects: None, buttons: Some(vec![button]), texts: None, images: None };
+    let page = Page { buttons: Some(vec![button]), ..Page::new(TestPage::Home) };
 
     page_data.page_to_render = Some(page);
     assert_eq!(page_data.page_button_at(&application_state, 300.0, 300.0), None);
 }
 
+#[test]
+fn button_state_color_follows_style_then_fallbacks()
+{
+    let button = Button::new(TestButton::A, Color::RGB(100, 100, 100), Rect::new(0, 0, 10, 10));
+    let decrease = Some((10, 10, 10));
+
+    assert_eq!(button.state_color(false, false, false, decrease), Color::RGB(100, 100, 100));
+    assert_eq!(button.state_color(true, false, false, decrease), Color::RGB(90, 90, 90));
+    assert_eq!(button.state_color(true, true, false, decrease), Color::RGB(80, 80, 80));
+    assert_eq!(button.state_color(false, false, true, None), Color::RGB(100, 100, 100));
+    // Disabled buttons are greyed out instead of hidden
+    assert_eq!(Button { enabled: false, color: Color::RGB(200, 0, 0), ..button.clone() }.state_color(false, false, false, decrease), Color::RGB(60, 60, 60));
+
+    let styled = Button { style: Some(ButtonStyle { hover_color: Some(Color::RGB(1, 2, 3)), focused_color: Some(Color::RGB(4, 5, 6)), ..ButtonStyle::default() }), ..button };
+    assert_eq!(styled.state_color(true, false, true, decrease), Color::RGB(1, 2, 3));
+    assert_eq!(styled.state_color(false, false, true, decrease), Color::RGB(4, 5, 6));
+    assert_eq!(styled.state_color(true, true, false, decrease), Color::RGB(80, 80, 80));
+}
+
 #[test]
 fn page_data_page_history_truncates_to_ten()
 {
@@ -309,6 +332,360 @@ fn input_handler_insert_text_respects_cursor_positionition()
     assert_eq!(page_data.vec_user_input[0].2, "hi there");
 }
 
+#[test]
+fn input_handler_edits_multi_byte_text_by_grapheme()
+{
+    let (mut application_state, mut page_data) = create_state();
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, String::new()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+
+    let mut input_handler = create_input_handler();
+    for text in ["ç", "日", "e\u{301}", "👍🏽", "🇧🇷"]
+    {
+        input_handler.insert_text(text, &application_state, &mut page_data, false);
+    }
+    assert_eq!(input_handler.cursor_position, page_data.vec_user_input[0].2.len());
+
+    // Each step crosses a whole grapheme cluster, whatever its byte length
+    input_handler.move_cursor(false, false, &application_state, &mut page_data);
+    input_handler.move_cursor(false, false, &application_state, &mut page_data);
+    input_handler.backspace(&mut application_state, &mut page_data);
+    assert_eq!(page_data.vec_user_input[0].2, "ç日👍🏽🇧🇷");
+    input_handler.move_cursor(true, true, &application_state, &mut page_data);
+    input_handler.insert_text("!", &application_state, &mut page_data, false);
+    assert_eq!(page_data.vec_user_input[0].2, "ç日!🇧🇷");
+}
+
+#[test]
+fn input_handler_recovers_from_cursor_inside_a_character()
+{
+    let (mut application_state, mut page_data) = create_state();
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, "aé".into()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+
+    let mut input_handler = create_input_handler();
+    // Byte 2 is in the middle of 'é', it is treated as the boundary before it
+    input_handler.cursor_position = 2;
+    input_handler.backspace(&mut application_state, &mut page_data);
+    assert_eq!(page_data.vec_user_input[0].2, "é");
+    input_handler.text_selection_range = Some((1, 99));
+    input_handler.insert_text("o", &application_state, &mut page_data, false);
+    assert_eq!(page_data.vec_user_input[0].2, "o");
+}
+
+#[test]
+fn input_handler_moves_and_selects_by_word()
+{
+    let (mut application_state, mut page_data) = create_state();
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, "hello, big  world".into()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+
+    let mut input_handler = create_input_handler();
+    input_handler.move_cursor_by_word(true, false, &application_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 5);
+    input_handler.move_cursor_by_word(true, false, &application_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 10);
+    input_handler.move_cursor_by_word(true, true, &application_state, &page_data);
+    assert_eq!(input_handler.text_selection_range, Some((10, 17)));
+    input_handler.move_cursor_by_word(false, false, &application_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 12);
+    input_handler.move_cursor_by_word(false, false, &application_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 7);
+}
+
+#[test]
+fn input_handler_deletes_by_word_and_forward()
+{
+    let (mut application_state, mut page_data) = create_state();
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, "one two three".into()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+
+    let mut input_handler = create_input_handler();
+    input_handler.cursor_position = 13;
+    input_handler.delete_word(false, &application_state, &mut page_data);
+    assert_eq!(page_data.vec_user_input[0].2, "one two ");
+    input_handler.cursor_position = 3;
+    input_handler.delete_word(true, &application_state, &mut page_data);
+    assert_eq!(page_data.vec_user_input[0].2, "one ");
+    input_handler.cursor_position = 0;
+    input_handler.delete(&application_state, &mut page_data);
+    assert_eq!(page_data.vec_user_input[0].2, "ne ");
+    assert_eq!(input_handler.cursor_position, 0);
+}
+
+#[test]
+fn input_handler_moves_cursor_by_lines_keeping_column()
+{
+    let (mut application_state, mut page_data) = create_state();
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, "first\nab\nthird".into()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+
+    let mut input_handler = create_input_handler();
+    input_handler.cursor_position = 4; // "firs|t"
+    input_handler.move_cursor_by_lines(1, false, &application_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 8); // shorter line, "ab|"
+    input_handler.move_cursor_by_lines(1, true, &application_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 11); // column 2 of the line above is kept, "th|ird"
+    assert_eq!(input_handler.text_selection_range, Some((8, 11)));
+    input_handler.move_cursor_by_lines(-10, false, &application_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 0);
+    input_handler.move_cursor_by_lines(10, false, &application_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 14);
+}
+
+#[test]
+fn input_handler_home_and_end_move_within_the_line()
+{
+    let (mut application_state, mut page_data) = create_state();
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, "first\nsecond".into()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+
+    let mut input_handler = create_input_handler();
+    input_handler.cursor_position = 8;
+    input_handler.move_cursor_to_line_edge(false, false, &application_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 6);
+    input_handler.move_cursor_to_line_edge(true, true, &application_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 12);
+    assert_eq!(input_handler.text_selection_range, Some((6, 12)));
+}
+
+#[test]
+fn input_handler_submit_inserts_new_line_only_in_text_areas()
+{
+    let (mut application_state, mut page_data) = create_state();
+    let mut clipboard = MemoryClipboard::default();
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, _: &TestButton, _: &mut PageData<TestPage, TestButton>| {};
+
+    page_data.page_to_render = Some(Page { has_userinput: Some(vec![(TestPage::Home, TestButton::A), (TestPage::Home, TestButton::B)]), has_text_area: Some(vec![(TestPage::Home, TestButton::A)]), ..Page::new(TestPage::Home) });
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, "note".into()));
+    page_data.vec_user_input.push((TestPage::Home, TestButton::B, "name".into()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+
+    let mut input_handler = create_input_handler();
+    input_handler.cursor_position = 4;
+    input_handler.process_event(InputEvent::Submit, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[0].2, "note\n");
+    assert!(application_state.capturing_input.0);
+
+    application_state.capturing_input = (true, Some(TestButton::B));
+    input_handler.process_event(InputEvent::Submit, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[1].2, "name");
+    assert!(!application_state.capturing_input.0);
+}
+
+#[test]
+fn input_handler_undo_and_redo_coalesce_typing_runs()
+{
+    let (mut application_state, mut page_data) = create_state();
+    let mut clipboard = MemoryClipboard::default();
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, _: &TestButton, _: &mut PageData<TestPage, TestButton>| {};
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, String::new()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+
+    let mut input_handler = create_input_handler();
+    for event in [InputEvent::Text("h".into()), InputEvent::Text("e".into()), InputEvent::Text("y".into()), InputEvent::CursorLeft(false), InputEvent::Text("!".into()), InputEvent::Backspace, InputEvent::Backspace]
+    {
+        input_handler.process_event(event, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    }
+    assert_eq!(page_data.vec_user_input[0].2, "hy");
+
+    input_handler.process_event(InputEvent::Undo, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[0].2, "he!y");
+    assert_eq!(input_handler.cursor_position, 3);
+    input_handler.process_event(InputEvent::Undo, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[0].2, "hey");
+    assert_eq!(input_handler.cursor_position, 2);
+    input_handler.process_event(InputEvent::Undo, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[0].2, "");
+    assert_eq!(input_handler.cursor_position, 0);
+
+    input_handler.process_event(InputEvent::Redo, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[0].2, "hey");
+    assert_eq!(input_handler.cursor_position, 2);
+
+    // A new edit drops what was left to redo
+    input_handler.process_event(InputEvent::Text("s".into()), &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    input_handler.process_event(InputEvent::Redo, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[0].2, "hesy");
+}
+
+#[test]
+fn input_handler_undo_history_is_per_field_and_restores_selection()
+{
+    let (mut application_state, mut page_data) = create_state();
+    let mut clipboard = MemoryClipboard::default();
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, _: &TestButton, _: &mut PageData<TestPage, TestButton>| {};
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, "alpha".into()));
+    page_data.vec_user_input.push((TestPage::Home, TestButton::B, "beta".into()));
+
+    let mut input_handler = create_input_handler();
+    application_state.capturing_input = (true, Some(TestButton::A));
+    input_handler.text_selection_range = Some((0, 5));
+    input_handler.cursor_position = 5;
+    input_handler.process_event(InputEvent::Text("x".into()), &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+
+    application_state.capturing_input = (true, Some(TestButton::B));
+    input_handler.cursor_position = 4;
+    input_handler.process_event(InputEvent::Backspace, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[1].2, "bet");
+
+    application_state.capturing_input = (true, Some(TestButton::A));
+    input_handler.process_event(InputEvent::Undo, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[0].2, "alpha");
+    assert_eq!(page_data.vec_user_input[1].2, "bet");
+    assert_eq!(input_handler.text_selection_range, Some((0, 5)));
+}
+
+#[test]
+fn input_handler_edits_that_change_nothing_keep_the_redo_steps()
+{
+    let (mut application_state, mut page_data) = create_state();
+    let mut clipboard = MemoryClipboard::default();
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, _: &TestButton, _: &mut PageData<TestPage, TestButton>| {};
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, String::new()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+
+    let mut input_handler = create_input_handler();
+    input_handler.process_event(InputEvent::Text("ab".into()), &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    input_handler.process_event(InputEvent::Undo, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!((page_data.vec_user_input[0].2.as_str(), input_handler.cursor_position), ("", 0));
+
+    // Backspace at the start, Paste with an empty clipboard and Cut without a selection do nothing
+    for no_op_event in [InputEvent::Backspace, InputEvent::Paste, InputEvent::Cut]
+    {
+        input_handler.process_event(no_op_event, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    }
+    assert!(!input_handler.edit_history.can_undo(TestPage::Home, TestButton::A));
+
+    input_handler.process_event(InputEvent::Redo, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[0].2, "ab");
+}
+
+#[test]
+fn input_handler_undo_history_respects_max_depth()
+{
+    let (mut application_state, mut page_data) = create_state();
+    let mut clipboard = MemoryClipboard::default();
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, _: &TestButton, _: &mut PageData<TestPage, TestButton>| {};
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, String::new()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+
+    let mut input_handler = create_input_handler();
+    input_handler.edit_history.max_depth = 2;
+    for _ in 0..4
+    {
+        input_handler.process_event(InputEvent::Text("a".into()), &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+        input_handler.process_event(InputEvent::CursorRight(false), &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    }
+    assert_eq!(page_data.vec_user_input[0].2, "aaaa");
+    for _ in 0..4
+    {
+        input_handler.process_event(InputEvent::Undo, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    }
+    assert_eq!(page_data.vec_user_input[0].2, "aa");
+}
+
+#[test]
+fn edit_history_redo_respects_max_depth()
+{
+    let snapshot = |text: &str| EditSnapshot { text: text.to_string(), cursor_position: text.len(), text_selection_range: None };
+    let mut edit_history = EditHistory::new(3);
+    for text in ["", "a", "ab"]
+    {
+        edit_history.record(TestPage::Home, TestButton::A, snapshot(text), EditKind::Other);
+    }
+    assert_eq!(edit_history.undo(TestPage::Home, TestButton::A, snapshot("abc")), Some(snapshot("ab")));
+    assert_eq!(edit_history.undo(TestPage::Home, TestButton::A, snapshot("ab")), Some(snapshot("a")));
+
+    // Redoing after the depth was lowered keeps only the newest step
+    edit_history.max_depth = 1;
+    edit_history.redo(TestPage::Home, TestButton::A, snapshot("a"));
+    edit_history.redo(TestPage::Home, TestButton::A, snapshot("ab"));
+    assert_eq!(edit_history.undo(TestPage::Home, TestButton::A, snapshot("abc")), Some(snapshot("ab")));
+    assert!(!edit_history.can_undo(TestPage::Home, TestButton::A));
+}
+
+#[test]
+fn input_handler_places_caret_and_selects_with_the_mouse()
+{
+    let (mut application_state, mut page_data) = create_state();
+    let mut clipboard = MemoryClipboard::default();
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, _: &TestButton, _: &mut PageData<TestPage, TestButton>| {};
+
+    page_data.vec_user_input.push((TestPage::Home, TestButton::A, "one two".into()));
+    application_state.capturing_input = (true, Some(TestButton::A));
+    // What the Renderer saves for a 10px monospace font at (100, 100)
+    let mut input_handler = create_input_handler();
+    input_handler.set_input_caret_stops(Some((TestButton::A, (0..=7).map(|byte_index| CaretStop { byte_index, x: 100 + 10 * byte_index as i32, line_rect: Rect::new(100, 100, 70, 20) }).collect())));
+    input_handler.button_selected = Some(TestButton::A);
+    input_handler.hovered_button = Some(TestButton::A);
+    let mut mouse_event = |input_handler: &mut InputHandler<TestPage, TestButton>, input_event: InputEvent, mouse_position: (f32, f32)| {
+        input_handler.mouse_position = mouse_position;
+        input_handler.process_event(input_event, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    };
+
+    mouse_event(&mut input_handler, InputEvent::Click, (134., 110.));
+    assert_eq!((input_handler.cursor_position, input_handler.text_selection_range), (3, None));
+    mouse_event(&mut input_handler, InputEvent::Drag, (152., 110.));
+    assert_eq!((input_handler.cursor_position, input_handler.text_selection_range), (5, Some((3, 5))));
+    mouse_event(&mut input_handler, InputEvent::MouseUp, (152., 110.));
+    mouse_event(&mut input_handler, InputEvent::Drag, (170., 110.));
+    assert_eq!(input_handler.text_selection_range, Some((3, 5)));
+
+    mouse_event(&mut input_handler, InputEvent::DoubleClick, (112., 110.));
+    assert_eq!((input_handler.cursor_position, input_handler.text_selection_range), (3, Some((0, 3))));
+    mouse_event(&mut input_handler, InputEvent::TripleClick, (112., 110.));
+    assert_eq!(input_handler.text_selection_range, Some((0, 7)));
+
+    // Over another button a double click is a click, the input keeps its text unselected
+    input_handler.hovered_button = Some(TestButton::B);
+    mouse_event(&mut input_handler, InputEvent::DoubleClick, (112., 110.));
+    assert_eq!((input_handler.button_pressed, input_handler.text_selection_range), (Some(TestButton::B), None));
+}
+
+#[test]
+fn input_handler_tab_cycles_focus_and_enter_activates()
+{
+    let (mut application_state, mut page_data) = create_state();
+    let mut clipboard = MemoryClipboard::default();
+    let activated = std::cell::RefCell::new(Vec::new());
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, button_id: &TestButton, _: &mut PageData<TestPage, TestButton>| activated.borrow_mut().push(*button_id);
+
+    let button = |id, enabled, tab_index| Button { enabled, tab_index, ..Button::new(id, Color::RGB(0, 0, 0), Rect::new(0, 0, 10, 10)) };
+    page_data.page_to_render = Some(Page { buttons: Some(vec![button(TestButton::A, true, None), button(TestButton::B, false, None), button(TestButton::C, true, Some(1))]), ..Page::new(TestPage::Home) });
+    // Tab indexes come first and disabled buttons are skipped
+    assert_eq!(page_data.focus_order(), vec![TestButton::C, TestButton::A]);
+
+    let mut input_handler = create_input_handler();
+    input_handler.process_event(InputEvent::FocusNext, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(input_handler.focused_button, Some(TestButton::C));
+    input_handler.process_event(InputEvent::FocusNext, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    input_handler.process_event(InputEvent::FocusNext, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(input_handler.focused_button, Some(TestButton::C));
+    input_handler.process_event(InputEvent::FocusPrevious, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(input_handler.focused_button, Some(TestButton::A));
+
+    input_handler.process_event(InputEvent::Submit, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    input_handler.process_event(InputEvent::Activate, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(*activated.borrow(), vec![TestButton::A, TestButton::A]);
+
+    // Tab leaves the input being typed in
+    application_state.capturing_input = (true, Some(TestButton::C));
+    input_handler.process_event(InputEvent::FocusNext, &mut clipboard, &mut page_data, &mut application_state, &mut button_action);
+    assert_eq!(application_state.capturing_input, (false, None));
+    assert_eq!(input_handler.focused_button, Some(TestButton::A));
+}
+
 //
 // ==========================================================
 // Renderer private utilities (button matching)
@@ -318,7 +695,7 @@ fn input_handler_insert_text_respects_cursor_positionition()
 #[test]
 fn renderer_button_matches_returns_correct_boolean()
 {
-    let test_button = Button { enabled: true, color: Color::RGB(255, 255, 255), rect: Rect::new(0, 0, 10, 10), radius: 0, id: TestButton::A, has_transition: None };
+    let test_button = Button::new(TestButton::A, Color::RGB(255, 255, 255), Rect::new(0, 0, 10, 10));
 
     assert!(Renderer::<TestPage, TestButton>::button_matches(&test_button, TestButton::A));
     assert!(!Renderer::<TestPage, TestButton>::button_matches(&test_button, TestButton::B));
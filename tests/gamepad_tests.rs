@@ -0,0 +1,146 @@
+use rust_page_system::system::{
+    gamepad::GamepadNavigation,
+    input_handler::{InputEvent, InputHandler},
+    input_source::MemoryClipboard,
+    page_system::{Button, Page, PageData},
+    state::AppState
+};
+use sdl3::{
+    event::Event,
+    gamepad::{Axis, Button as GamepadButton},
+    pixels::Color,
+    rect::Rect,
+    sys::{
+        gamepad::{SDL_GAMEPAD_AXIS_COUNT, SDL_GAMEPAD_BUTTON_COUNT, SDL_GAMEPAD_BUTTON_DPAD_DOWN},
+        joystick::{SDL_AttachVirtualJoystick, SDL_CloseJoystick, SDL_DetachVirtualJoystick, SDL_JOYSTICK_TYPE_GAMEPAD, SDL_OpenJoystick, SDL_SetJoystickVirtualButton, SDL_VirtualJoystickDesc}
+    }
+};
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    Menu
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestButton
+{
+    Play,
+    Options,
+    Quit,
+    Credits
+}
+
+fn button_down(button: GamepadButton) -> Event
+{
+    Event::ControllerButtonDown { timestamp: 0, which: 0, button }
+}
+
+fn stick(axis: Axis, value: i16) -> Event
+{
+    Event::ControllerAxisMotion { timestamp: 0, which: 0, axis, value }
+}
+
+/// Play, Options and Quit in a column, Credits to the right of Options
+fn menu_page_data() -> (AppState<TestPage, TestButton>, PageData<TestPage, TestButton>)
+{
+    let app_state = AppState::new(TestPage::Menu, (1920, 1080), true);
+    let mut page_data = PageData::new(&app_state);
+    let button = |id, rect| Button::new(id, Color::RGB(0, 0, 0), rect);
+    let buttons = vec![button(TestButton::Play, Rect::new(100, 100, 200, 50)), button(TestButton::Options, Rect::new(100, 200, 200, 50)), button(TestButton::Quit, Rect::new(100, 300, 200, 50)), button(TestButton::Credits, Rect::new(600, 180, 200, 50))];
+    page_data.page_to_render = Some(Page { buttons: Some(buttons), ..Page::new(TestPage::Menu) });
+    (app_state, page_data)
+}
+
+//
+// ==========================================================
+// GamepadNavigation tests
+// ==========================================================
+//
+
+#[test]
+fn gamepad_buttons_translate_to_navigation_events()
+{
+    let mut navigation = GamepadNavigation::default();
+
+    assert_eq!(navigation.translate(&button_down(GamepadButton::DPadUp), true), Some(InputEvent::NavigateUp));
+    assert_eq!(navigation.translate(&button_down(GamepadButton::DPadRight), true), Some(InputEvent::NavigateRight));
+    assert_eq!(navigation.translate(&button_down(GamepadButton::South), true), Some(InputEvent::Activate));
+    assert_eq!(navigation.translate(&button_down(GamepadButton::East), true), Some(InputEvent::Back));
+    assert_eq!(navigation.translate(&button_down(GamepadButton::RightShoulder), true), Some(InputEvent::Front));
+    // History buttons follow enable_rollback_pages like the mouse side buttons
+    assert_eq!(navigation.translate(&button_down(GamepadButton::LeftShoulder), false), None);
+}
+
+#[test]
+fn gamepad_stick_moves_once_per_push()
+{
+    let mut navigation = GamepadNavigation::default();
+
+    assert_eq!(navigation.translate(&stick(Axis::LeftY, 30000), true), Some(InputEvent::NavigateDown));
+    assert_eq!(navigation.translate(&stick(Axis::LeftY, 32767), true), None);
+    assert_eq!(navigation.translate(&stick(Axis::LeftY, 2000), true), None);
+    assert_eq!(navigation.translate(&stick(Axis::LeftY, 30000), true), Some(InputEvent::NavigateDown));
+    assert_eq!(navigation.translate(&stick(Axis::LeftX, -30000), true), Some(InputEvent::NavigateLeft));
+    assert_eq!(navigation.translate(&stick(Axis::RightX, 30000), true), None);
+}
+
+#[test]
+fn virtual_gamepad_is_opened_and_polled_into_navigation_events()
+{
+    let sdl = sdl3::init().unwrap();
+    let mut event_pump = sdl.event_pump().unwrap();
+    let mut input_handler: InputHandler<TestPage, TestButton> = InputHandler::new(true);
+    input_handler.enable_gamepads(&sdl).unwrap();
+
+    let desc = SDL_VirtualJoystickDesc { r#type: SDL_JOYSTICK_TYPE_GAMEPAD.0 as u16, naxes: SDL_GAMEPAD_AXIS_COUNT.0 as u16, nbuttons: SDL_GAMEPAD_BUTTON_COUNT.0 as u16, ..Default::default() };
+    let joystick_id = unsafe { SDL_AttachVirtualJoystick(&desc) };
+    assert_ne!(joystick_id, 0);
+    // The added event opens the gamepad, without it SDL would not send its buttons
+    assert_eq!(input_handler.poll(&mut event_pump), InputEvent::None);
+
+    let joystick = unsafe { SDL_OpenJoystick(joystick_id) };
+    assert!(!joystick.is_null());
+    assert!(unsafe { SDL_SetJoystickVirtualButton(joystick, SDL_GAMEPAD_BUTTON_DPAD_DOWN.0, true) });
+    assert_eq!(input_handler.poll(&mut event_pump), InputEvent::NavigateDown);
+
+    unsafe {
+        SDL_CloseJoystick(joystick);
+        SDL_DetachVirtualJoystick(joystick_id);
+    }
+}
+
+//
+// ==========================================================
+// Spatial navigation tests
+// ==========================================================
+//
+
+#[test]
+fn navigation_moves_focus_to_the_closest_button_that_way()
+{
+    let (mut app_state, mut page_data) = menu_page_data();
+    let mut clipboard = MemoryClipboard::default();
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, _: &TestButton, _: &mut PageData<TestPage, TestButton>| {};
+    let mut input_handler: InputHandler<TestPage, TestButton> = InputHandler::new(true);
+
+    // Nothing focused yet, the first button is picked
+    input_handler.process_event(InputEvent::NavigateDown, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(input_handler.focused_button, Some(TestButton::Play));
+    input_handler.process_event(InputEvent::NavigateDown, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(input_handler.focused_button, Some(TestButton::Options));
+    input_handler.process_event(InputEvent::NavigateRight, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(input_handler.focused_button, Some(TestButton::Credits));
+    // Nothing further right
+    input_handler.process_event(InputEvent::NavigateRight, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(input_handler.focused_button, Some(TestButton::Credits));
+
+    assert_eq!(page_data.focus_towards(Some(TestButton::Credits), (-1, 0)), Some(TestButton::Options));
+    assert_eq!(page_data.focus_towards(Some(TestButton::Play), (0, -1)), Some(TestButton::Play));
+}
@@ -0,0 +1,105 @@
+use rust_page_system::system::{
+    input_handler::InputHandler,
+    page_system::{Button, Page, PageData},
+    renderer::{Renderer, RendererConfig},
+    snapshot::RgbaFrame,
+    state::AppState,
+    window::{WINDOW_DEFAULT_SCALE, WindowConfig, create_headless_window}
+};
+use sdl3::{pixels::Color, rect::Rect};
+use std::rc::Rc;
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    Home
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestButton
+{
+    Square,
+    Rounded
+}
+
+const BACKGROUND_COLOR: Color = Color::RGB(30, 30, 60);
+const RECT_COLOR: Color = Color::RGB(200, 40, 40);
+const SQUARE_BUTTON_COLOR: Color = Color::RGB(40, 160, 80);
+const ROUNDED_BUTTON_COLOR: Color = Color::RGB(40, 80, 200);
+const TEXT_COLOR: Color = Color::RGB(255, 255, 255);
+
+fn rgba(color: Color) -> Option<(u8, u8, u8, u8)>
+{
+    Some((color.r, color.g, color.b, 255))
+}
+
+/// A rect, a square button with a white text on it and a rounded button
+fn home_page() -> Page<TestPage, TestButton>
+{
+    let rects = vec![(RECT_COLOR, (Rect::new(100, 100, 300, 200), 0))];
+    let buttons = vec![Button::new(TestButton::Square, SQUARE_BUTTON_COLOR, Rect::new(600, 100, 500, 150)), Button::new(TestButton::Rounded, ROUNDED_BUTTON_COLOR, Rect::new(600, 400, 400, 120)).with_radius(30)];
+    let texts = vec![(48., (620, 120), "Headless".to_string(), TEXT_COLOR)];
+    Page { background_color: Some(BACKGROUND_COLOR), rects: Some(rects), buttons: Some(buttons), texts: Some(texts), ..Page::new(TestPage::Home) }
+}
+
+fn headless_window_config() -> WindowConfig<'static>
+{
+    WindowConfig { window_title: "Headless Test".to_string(), icon: (None, None), start_window_size: (800, 600), window_minimum_size: (800, 600), resizable: true, centered: false, different_sdl_presentation_mode: None, font: ("DejaVu Sans".to_string(), None) }
+}
+
+/// Render home_page in a headless window and read the frame back
+fn capture_home_page() -> RgbaFrame
+{
+    let window_modules = create_headless_window(headless_window_config());
+    let mut app_state = AppState::new(TestPage::Home, WINDOW_DEFAULT_SCALE, window_modules.stretch_mode_status);
+    let mut page_data = PageData::new(&app_state);
+    let input_handler = InputHandler::new(false);
+    let renderer_config = RendererConfig { canvas: window_modules.canvas, texture_creator: &window_modules.texture_creator, ttf_context: &window_modules.ttf_context, font_path: &window_modules.font_path, decrease_color_when_selected: None, selection_color: None, assets_dir: None };
+    let mut renderer = Renderer::new(renderer_config);
+
+    page_data.populate_rps_data(Some(vec![Rc::new(home_page)]), None);
+    page_data.create_current_page(&mut app_state);
+    renderer.capture_frame(&page_data, &mut app_state, &input_handler).unwrap()
+}
+
+//
+// ==========================================================
+// Headless rendering tests
+// ==========================================================
+//
+
+#[test]
+fn headless_window_renders_rects_buttons_and_text()
+{
+    let frame = capture_home_page();
+
+    // The window size is forced to the logical canvas size, so frame pixels are page pixels
+    assert_eq!((frame.width, frame.height), WINDOW_DEFAULT_SCALE);
+    assert_eq!(frame.pixel(10, 10), rgba(BACKGROUND_COLOR));
+    assert_eq!(frame.pixel(1900, 1060), rgba(BACKGROUND_COLOR));
+
+    assert_eq!(frame.pixel(100, 100), rgba(RECT_COLOR));
+    assert_eq!(frame.pixel(250, 200), rgba(RECT_COLOR));
+    assert_eq!(frame.pixel(399, 299), rgba(RECT_COLOR));
+    assert_eq!(frame.pixel(400, 300), rgba(BACKGROUND_COLOR));
+
+    assert_eq!(frame.pixel(1090, 240), rgba(SQUARE_BUTTON_COLOR));
+    assert_eq!(frame.pixel(600, 249), rgba(SQUARE_BUTTON_COLOR));
+
+    // The rounded corners leave the background visible, the middle of the edges is filled
+    assert_eq!(frame.pixel(800, 460), rgba(ROUNDED_BUTTON_COLOR));
+    assert_eq!(frame.pixel(600, 460), rgba(ROUNDED_BUTTON_COLOR));
+    assert_eq!(frame.pixel(601, 401), rgba(BACKGROUND_COLOR));
+    assert_eq!(frame.pixel(998, 518), rgba(BACKGROUND_COLOR));
+
+    // The glyphs depend on the installed font, so only check that white text covers part of the button
+    let text_pixels = (620..1000).flat_map(|x| (120..240).map(move |y| (x, y))).filter(|&(x, y)| frame.pixel(x, y) == rgba(TEXT_COLOR)).count();
+    assert!(text_pixels > 100, "expected the text to be drawn on the button, found {} text pixels", text_pixels);
+    assert!(text_pixels < 380 * 120 / 2, "expected the text to leave the button visible, found {} text pixels", text_pixels);
+}
@@ -0,0 +1,189 @@
+use rust_page_system::system::{
+    input_handler::{InputEvent, InputHandler},
+    input_rules::{CharFilter, InputRules},
+    input_source::MemoryClipboard,
+    page_system::{Page, PageData},
+    state::AppState
+};
+use std::rc::Rc;
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    Login
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestButton
+{
+    Pin,
+    Password
+}
+
+fn login_page() -> Page<TestPage, TestButton>
+{
+    let pin_rules = InputRules { max_length: Some(4), char_filter: Some(CharFilter::Digits), ..InputRules::default() };
+    let password_rules = InputRules { validators: vec![Rc::new(|text: &str| if text.chars().count() >= 6 { Ok(()) } else { Err("Too short".to_string()) })], ..InputRules::password() };
+    Page { has_userinput: Some(vec![(TestPage::Login, TestButton::Pin), (TestPage::Login, TestButton::Password)]), has_input_rules: Some(vec![(TestPage::Login, TestButton::Pin, pin_rules), (TestPage::Login, TestButton::Password, password_rules)]), ..Page::new(TestPage::Login) }
+}
+
+fn create_login_state() -> (AppState<TestPage, TestButton>, PageData<TestPage, TestButton>)
+{
+    let mut app_state = AppState::new(TestPage::Login, (1920, 1080), true);
+    let mut page_data = PageData::new(&app_state);
+    page_data.populate_rps_data(Some(vec![Rc::new(login_page)]), None);
+    page_data.create_current_page(&mut app_state);
+    (app_state, page_data)
+}
+
+//
+// ==========================================================
+// InputRules tests
+// ==========================================================
+//
+
+#[test]
+fn input_rules_filter_and_cut_inserted_text()
+{
+    let rules = InputRules { max_length: Some(4), char_filter: Some(CharFilter::Hex), ..InputRules::default() };
+
+    assert_eq!(rules.accepted_insert("", (0, 0), "a1-zF9f"), "a1F9");
+    assert_eq!(rules.accepted_insert("abc", (3, 3), "def"), "d");
+    // Replacing a selection frees its room
+    assert_eq!(rules.accepted_insert("abcd", (1, 3), "123"), "12");
+    assert_eq!(rules.accepted_insert("abcd", (4, 4), "1"), "");
+}
+
+#[test]
+fn input_rules_validate_in_order()
+{
+    let rules = InputRules { max_length: Some(3), char_filter: Some(CharFilter::Digits), validators: vec![Rc::new(|text: &str| if text.starts_with('0') { Err("Leading zero".to_string()) } else { Ok(()) })], ..InputRules::default() };
+
+    assert_eq!(rules.validate("123"), Ok(()));
+    assert_eq!(rules.validate("1234"), Err("Longer than 3 characters".to_string()));
+    assert_eq!(rules.validate("1a"), Err("'a' is not allowed".to_string()));
+    assert_eq!(rules.validate("012"), Err("Leading zero".to_string()));
+}
+
+#[test]
+fn input_rules_mask_maps_positions_between_real_and_displayed_text()
+{
+    let rules = InputRules::password();
+
+    assert_eq!(rules.display_text("pé👍🏽"), "•••");
+    // 'é' is 2 bytes, '👍🏽' is 8 and '•' is 3
+    assert_eq!(rules.display_index("pé👍🏽", 3), 6);
+    assert_eq!(rules.text_index("pé👍🏽", 6), 3);
+    assert_eq!(rules.text_index("pé👍🏽", 9), 11);
+    assert_eq!(InputRules::default().display_index("pé", 1), 1);
+}
+
+#[test]
+fn input_rules_placeholder_only_shows_on_empty_text()
+{
+    let rules = InputRules { placeholder: Some("Name".to_string()), ..InputRules::default() };
+
+    assert_eq!(rules.placeholder_text(""), Some("Name"));
+    assert_eq!(rules.placeholder_text("N"), None);
+    assert_eq!(InputRules::default().placeholder_text(""), None);
+}
+
+#[cfg(feature = "regex-validation")]
+#[test]
+fn regex_validator_checks_the_whole_pattern()
+{
+    use rust_page_system::system::input_rules::regex_validator;
+
+    let rules = InputRules { validators: vec![regex_validator(r"^[^@\s]+@[^@\s]+$", "Not an email").unwrap()], ..InputRules::default() };
+
+    assert_eq!(rules.validate("me@example.com"), Ok(()));
+    assert_eq!(rules.validate("me at example"), Err("Not an email".to_string()));
+    assert!(regex_validator("(", "Broken").is_err());
+}
+
+//
+// ==========================================================
+// Rules applied to inputs
+// ==========================================================
+//
+
+#[test]
+fn inputs_follow_their_rules_while_typing()
+{
+    let (mut app_state, mut page_data) = create_login_state();
+    let mut input_handler: InputHandler<TestPage, TestButton> = InputHandler::new(false);
+
+    app_state.capturing_input = (true, Some(TestButton::Pin));
+    input_handler.insert_text("12a3", &app_state, &mut page_data, false);
+    input_handler.insert_text("456", &app_state, &mut page_data, false);
+    input_handler.insert_text("x", &app_state, &mut page_data, false);
+    assert_eq!(page_data.vec_user_input[0].2, "1234");
+    assert_eq!(input_handler.cursor_position, 4);
+    assert!(page_data.is_input_valid(TestPage::Login, TestButton::Pin));
+
+    input_handler.cursor_position = 0;
+    app_state.capturing_input = (true, Some(TestButton::Password));
+    input_handler.insert_text("hunter", &app_state, &mut page_data, false);
+    input_handler.backspace(&mut app_state, &mut page_data);
+    assert_eq!(page_data.vec_user_input[1].2, "hunte");
+    assert_eq!(page_data.vec_user_input_string[1], "•••••");
+    assert_eq!(page_data.input_error(TestPage::Login, TestButton::Password), Some("Too short".to_string()));
+
+    // Masked inputs can't be copied
+    let mut clipboard = MemoryClipboard::default();
+    input_handler.text_selection_range = Some((0, 5));
+    input_handler.copy(Some(&mut clipboard), &app_state, &mut page_data, false);
+    assert_eq!(clipboard.text, "");
+}
+
+#[test]
+fn rejected_text_keeps_the_redo_steps()
+{
+    let (mut app_state, mut page_data) = create_login_state();
+    let mut input_handler: InputHandler<TestPage, TestButton> = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, _: &TestButton, _: &mut PageData<TestPage, TestButton>| {};
+    app_state.capturing_input = (true, Some(TestButton::Pin));
+
+    input_handler.process_event(InputEvent::Text("1".to_string()), &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    input_handler.process_event(InputEvent::Undo, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    // A letter in the digits only input is dropped, it's not an undo step
+    input_handler.process_event(InputEvent::Text("a".to_string()), &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[0].2, "");
+    assert!(!input_handler.edit_history.can_undo(TestPage::Login, TestButton::Pin));
+
+    input_handler.process_event(InputEvent::Redo, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(page_data.vec_user_input[0].2, "1");
+}
+
+#[test]
+fn masked_inputs_are_a_single_word()
+{
+    let (mut app_state, mut page_data) = create_login_state();
+    let mut input_handler: InputHandler<TestPage, TestButton> = InputHandler::new(false);
+    app_state.capturing_input = (true, Some(TestButton::Password));
+    input_handler.insert_text("open sesame now", &app_state, &mut page_data, false);
+
+    // Word moves and deletions jump over the spaces that the bullets hide
+    input_handler.cursor_position = 7;
+    input_handler.move_cursor_by_word(false, false, &app_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 0);
+    input_handler.move_cursor_by_word(true, true, &app_state, &page_data);
+    assert_eq!((input_handler.cursor_position, input_handler.text_selection_range), (15, Some((0, 15))));
+    input_handler.text_selection_range = None;
+    input_handler.delete_word(false, &app_state, &mut page_data);
+    assert_eq!(page_data.vec_user_input[1].2, "");
+
+    // Unmasked inputs still move by word
+    app_state.capturing_input = (true, Some(TestButton::Pin));
+    page_data.vec_user_input[0].2 = "12 34".to_string();
+    input_handler.cursor_position = 5;
+    input_handler.move_cursor_by_word(false, false, &app_state, &page_data);
+    assert_eq!(input_handler.cursor_position, 3);
+}
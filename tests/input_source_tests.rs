@@ -0,0 +1,258 @@
+use rust_page_system::system::{
+    clock::ManualClock,
+    input_handler::{ButtonEvent, ButtonEventKind, InputEvent, InputHandler},
+    input_source::{InputSource, MemoryClipboard, ScriptedInput, ScriptedStep},
+    page_system::{Button, Page, PageData},
+    state::AppState
+};
+use sdl3::{pixels::Color, rect::Rect};
+use std::{rc::Rc, time::Duration};
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    Home
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestButton
+{
+    Input
+}
+
+const INPUT_BUTTON_CENTER: (f32, f32) = (300., 225.);
+
+fn home_page() -> Page<TestPage, TestButton>
+{
+    let input_button = Button::new(TestButton::Input, Color::RGB(203, 166, 247), Rect::new(100, 200, 400, 50));
+    Page { has_userinput: Some(vec![(TestPage::Home, TestButton::Input)]), buttons: Some(vec![input_button]), ..Page::new(TestPage::Home) }
+}
+
+fn create_linked_state() -> (AppState<TestPage, TestButton>, PageData<TestPage, TestButton>)
+{
+    let app_state = AppState::new(TestPage::Home, (1920, 1080), true);
+    let mut page_data = PageData::new(&app_state);
+    page_data.populate_rps_data(Some(vec![Rc::new(home_page)]), None);
+    (app_state, page_data)
+}
+
+fn button_action(app_state: &mut AppState<TestPage, TestButton>, button_id: &TestButton, _page_data: &mut PageData<TestPage, TestButton>)
+{
+    if !app_state.capturing_input.0
+    {
+        app_state.capturing_input = (true, Some(*button_id));
+    }
+}
+
+fn step(event: InputEvent) -> ScriptedStep
+{
+    ScriptedStep::new(0, INPUT_BUTTON_CENTER, event)
+}
+
+//
+// ==========================================================
+// ScriptedInput tests
+// ==========================================================
+//
+
+#[test]
+fn scripted_input_delivers_steps_in_order_then_none()
+{
+    let input_handler: InputHandler<TestPage, TestButton> = InputHandler::new(false);
+    let mut script = ScriptedInput::new(vec![ScriptedStep::new(0, (1., 2.), InputEvent::Click), ScriptedStep::new(10, (3., 4.), InputEvent::Submit)]);
+
+    assert_eq!(InputSource::<TestPage, TestButton>::mouse_position(&mut script), (1., 2.));
+    assert_eq!(script.next_event(&input_handler), InputEvent::Click);
+    assert_eq!(InputSource::<TestPage, TestButton>::mouse_position(&mut script), (3., 4.));
+    assert_eq!(script.next_event(&input_handler), InputEvent::Submit);
+    assert!(script.is_finished());
+    assert_eq!(script.next_event(&input_handler), InputEvent::None);
+    assert_eq!(InputSource::<TestPage, TestButton>::mouse_position(&mut script), (3., 4.));
+}
+
+#[test]
+fn scripted_realtime_input_waits_for_timestamp()
+{
+    let input_handler: InputHandler<TestPage, TestButton> = InputHandler::new(false);
+    let mut script = ScriptedInput::realtime(vec![ScriptedStep::new(60_000, (0., 0.), InputEvent::Click)]);
+
+    assert_eq!(script.next_event(&input_handler), InputEvent::None);
+    assert_eq!(script.remaining_steps(), 1);
+}
+
+//
+// ==========================================================
+// Full flows without a window
+// ==========================================================
+//
+
+#[test]
+fn scripted_flow_click_type_select_all_cut_escape()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+    let mut script = ScriptedInput::new(vec![step(InputEvent::Click), step(InputEvent::MouseUp), step(InputEvent::Text("hello".to_string())), step(InputEvent::SelectAll), step(InputEvent::Cut), step(InputEvent::ExitCapturingInput)]);
+
+    input_handler.play_script(&mut script, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+
+    assert_eq!(page_data.vec_user_input, vec![(TestPage::Home, TestButton::Input, String::new())]);
+    assert_eq!(clipboard.text, "hello");
+    assert_eq!(app_state.capturing_input, (false, None));
+}
+
+#[test]
+fn scripted_flow_types_edits_and_pastes()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard { text: "!".to_string() };
+    let mut script = ScriptedInput::new(vec![step(InputEvent::Click), step(InputEvent::MouseUp), step(InputEvent::Text("abc".to_string())), step(InputEvent::Backspace), step(InputEvent::CursorLeft(false)), step(InputEvent::Paste), step(InputEvent::Submit)]);
+
+    input_handler.play_script(&mut script, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+
+    assert_eq!(page_data.vec_user_input[0].2, "a!b");
+    assert_eq!(page_data.vec_user_input_string, vec!["a!b"]);
+    assert!(!app_state.capturing_input.0);
+}
+
+#[test]
+fn scripted_click_outside_buttons_does_not_start_capturing()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+    let mut script = ScriptedInput::new(vec![ScriptedStep::new(0, (1500., 900.), InputEvent::Click), ScriptedStep::new(0, (1500., 900.), InputEvent::Text("ignored".to_string()))]);
+
+    input_handler.play_script(&mut script, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+
+    assert_eq!(app_state.capturing_input, (false, None));
+    assert_eq!(page_data.vec_user_input[0].2, "");
+    assert_eq!(input_handler.mouse_position, (1500., 900.));
+}
+
+#[test]
+fn space_that_activates_an_input_is_not_typed_into_it()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    input_handler.focused_button = Some(TestButton::Input);
+    let mut clipboard = MemoryClipboard::default();
+    let space = || step(InputEvent::Text(" ".to_string()));
+    let mut script = ScriptedInput::new(vec![step(InputEvent::Activate), space(), step(InputEvent::Text("a".to_string())), space(), step(InputEvent::Activate), space()]);
+
+    input_handler.play_script(&mut script, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+
+    // Once capturing, Space only types
+    assert_eq!(page_data.vec_user_input[0].2, "a  ");
+    assert!(app_state.capturing_input.0);
+}
+
+#[test]
+fn scripted_quit_stops_the_script_without_exiting()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+    let mut script = ScriptedInput::new(vec![step(InputEvent::Click), step(InputEvent::Quit), step(InputEvent::Text("after quit".to_string()))]);
+
+    assert!(input_handler.play_script(&mut script, &mut clipboard, &mut page_data, &mut app_state, &mut button_action));
+    assert_eq!(script.remaining_steps(), 1);
+    assert_eq!(page_data.vec_user_input[0].2, "");
+
+    // Quit is ignored while every event is disabled
+    app_state.all_events_disable = true;
+    assert!(!input_handler.process_event(InputEvent::Quit, &mut clipboard, &mut page_data, &mut app_state, &mut button_action));
+}
+
+//
+// ==========================================================
+// Button events
+// ==========================================================
+//
+
+fn button_event_kinds(input_handler: &mut InputHandler<TestPage, TestButton>) -> Vec<ButtonEventKind>
+{
+    input_handler.take_button_events().iter().map(|button_event| button_event.kind).collect()
+}
+
+#[test]
+fn button_events_follow_hover_press_release_and_drag_off()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+    let outside = (1500., 900.);
+    let mut script = ScriptedInput::new(vec![step(InputEvent::None), step(InputEvent::Click), step(InputEvent::MouseUp), ScriptedStep::new(0, outside, InputEvent::None)]);
+
+    input_handler.play_script(&mut script, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    let button_events = input_handler.take_button_events();
+
+    assert_eq!(button_events.iter().map(|button_event| button_event.kind).collect::<Vec<_>>(), vec![ButtonEventKind::HoverEnter, ButtonEventKind::Press, ButtonEventKind::Click, ButtonEventKind::HoverLeave]);
+    assert_eq!(button_events[1], ButtonEvent { button_id: TestButton::Input, kind: ButtonEventKind::Press, mouse_position: INPUT_BUTTON_CENTER });
+    assert_eq!(button_events[3].mouse_position, outside);
+
+    // Released away from the button, the press is cancelled instead of clicking
+    let mut script = ScriptedInput::new(vec![step(InputEvent::Click), ScriptedStep::new(0, outside, InputEvent::Drag), ScriptedStep::new(0, outside, InputEvent::MouseUp)]);
+    input_handler.play_script(&mut script, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(button_event_kinds(&mut input_handler), vec![ButtonEventKind::HoverEnter, ButtonEventKind::Press, ButtonEventKind::HoverLeave, ButtonEventKind::PressCancelled]);
+    assert_eq!(input_handler.button_pressed, None);
+}
+
+#[test]
+fn button_action_runs_on_release_inside_the_button()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+
+    input_handler.play_script(&mut ScriptedInput::new(vec![step(InputEvent::Click)]), &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(app_state.capturing_input, (false, None));
+
+    // Dragging off before releasing cancels it
+    input_handler.play_script(&mut ScriptedInput::new(vec![ScriptedStep::new(0, (1500., 900.), InputEvent::Drag), ScriptedStep::new(0, (1500., 900.), InputEvent::MouseUp)]), &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(app_state.capturing_input, (false, None));
+
+    input_handler.play_script(&mut ScriptedInput::new(vec![step(InputEvent::Click), step(InputEvent::MouseUp)]), &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(app_state.capturing_input, (true, Some(TestButton::Input)));
+}
+
+#[test]
+fn long_press_waits_for_the_injected_clock()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let clock = ManualClock::default();
+    input_handler.set_clock(clock.clone());
+    let mut clipboard = MemoryClipboard::default();
+
+    input_handler.play_script(&mut ScriptedInput::new(vec![step(InputEvent::Click)]), &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    clock.advance(input_handler.long_press_duration - Duration::from_millis(1));
+    input_handler.play_script(&mut ScriptedInput::new(vec![step(InputEvent::None)]), &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(button_event_kinds(&mut input_handler), vec![ButtonEventKind::HoverEnter, ButtonEventKind::Press]);
+
+    clock.advance(Duration::from_millis(1));
+    input_handler.play_script(&mut ScriptedInput::new(vec![step(InputEvent::None)]), &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    assert_eq!(button_event_kinds(&mut input_handler), vec![ButtonEventKind::LongPress]);
+}
+
+#[test]
+fn button_events_report_long_press_once_right_click_and_double_click()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    input_handler.long_press_duration = Duration::ZERO;
+    let mut clipboard = MemoryClipboard::default();
+    let mut script = ScriptedInput::new(vec![step(InputEvent::Click), step(InputEvent::None), step(InputEvent::None), step(InputEvent::MouseUp), step(InputEvent::RightClick), step(InputEvent::DoubleClick)]);
+
+    input_handler.play_script(&mut script, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+
+    assert_eq!(button_event_kinds(&mut input_handler), vec![ButtonEventKind::HoverEnter, ButtonEventKind::Press, ButtonEventKind::LongPress, ButtonEventKind::Click, ButtonEventKind::RightClick, ButtonEventKind::DoubleClick]);
+    assert!(input_handler.take_button_events().is_empty());
+}
@@ -0,0 +1,119 @@
+use rust_page_system::system::{
+    input_handler::InputEvent,
+    keymap::{KeyCombo, Keymap, KeymapMatch, Trigger}
+};
+use sdl3::{
+    keyboard::{Keycode, Mod},
+    mouse::MouseButton
+};
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    Editor,
+    Viewer
+}
+
+fn keys(key_combos: &[KeyCombo]) -> Vec<KeyCombo>
+{
+    key_combos.to_vec()
+}
+
+//
+// ==========================================================
+// KeyCombo tests
+// ==========================================================
+//
+
+#[test]
+fn key_combo_parses_and_reads_events_the_same_way()
+{
+    assert_eq!(KeyCombo::parse("Ctrl+Shift+Z"), Ok(KeyCombo::new(Keycode::Z).ctrl().shift()));
+    assert_eq!(KeyCombo::parse("alt + left"), Ok(KeyCombo::new(Keycode::Left).alt()));
+    assert_eq!(KeyCombo::from_event(Keycode::Z, Mod::RCTRLMOD | Mod::LSHIFTMOD | Mod::CAPSMOD), KeyCombo::new(Keycode::Z).ctrl().shift());
+    assert!(KeyCombo::parse("Hyper+Z").is_err());
+    assert!(KeyCombo::parse("Ctrl+").is_err());
+}
+
+//
+// ==========================================================
+// Keymap tests
+// ==========================================================
+//
+
+#[test]
+fn default_keymap_has_the_desktop_shortcuts()
+{
+    let keymap: Keymap<TestPage> = Keymap::default();
+
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Z).ctrl()])), KeymapMatch::Event(InputEvent::Undo));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Z).ctrl().shift()])), KeymapMatch::Event(InputEvent::Redo));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Left).ctrl().shift()])), KeymapMatch::Event(InputEvent::WordLeft(true)));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Left).alt()])), KeymapMatch::Event(InputEvent::Back));
+    assert_eq!(keymap.resolve_mouse(None, MouseButton::X2), Some(InputEvent::Front));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::K)])), KeymapMatch::NoMatch);
+}
+
+#[test]
+fn keymap_ignores_modifiers_nothing_is_bound_to()
+{
+    let keymap: Keymap<TestPage> = Keymap::default();
+
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Return).shift()])), KeymapMatch::Event(InputEvent::Submit));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Backspace).alt()])), KeymapMatch::Event(InputEvent::Backspace));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Escape).shift()])), KeymapMatch::Event(InputEvent::ExitCapturingInput));
+    // Exact bindings still win
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Backspace).ctrl()])), KeymapMatch::Event(InputEvent::DeleteWordBackward));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::K).ctrl()])), KeymapMatch::NoMatch);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn keymap_overrides_load_from_config_text()
+{
+    let mut keymap: Keymap<TestPage> = Keymap::default();
+    let config = "# Editor shortcuts\nCtrl+K, Ctrl+C = Copy\nShift+Home = Home(false)\nMouseMiddle = Paste\nCtrl+Y = None\n";
+
+    assert_eq!(keymap.apply_overrides(config), Ok(()));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Home).shift()])), KeymapMatch::Event(InputEvent::Home(false)));
+    assert_eq!(keymap.resolve_mouse(None, MouseButton::Middle), Some(InputEvent::Paste));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Y).ctrl()])), KeymapMatch::NoMatch);
+
+    // An invalid line is reported and nothing is applied
+    assert_eq!(keymap.apply_overrides("Ctrl+Z = Copy\nCtrl+Z = Teleport"), Err("Line 2: Unknown or unbindable event 'Teleport'".to_string()));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[KeyCombo::new(Keycode::Z).ctrl()])), KeymapMatch::Event(InputEvent::Undo));
+    assert!(keymap.apply_overrides("Ctrl+Z = Submit(true)").is_err());
+    assert!(keymap.apply_overrides("Ctrl+Z = Home").is_err());
+    assert_eq!(keymap.apply_overrides("Ctrl+Z = Click"), Err("Line 1: Unknown or unbindable event 'Click'".to_string()));
+}
+
+#[test]
+fn keymap_chords_wait_for_their_last_key()
+{
+    let mut keymap: Keymap<TestPage> = Keymap::empty();
+    let ctrl_k = KeyCombo::new(Keycode::K).ctrl();
+    keymap.bind(Trigger::Keys(vec![ctrl_k, KeyCombo::new(Keycode::C).ctrl()]), InputEvent::Copy);
+
+    assert_eq!(keymap.resolve_keys(None, &keys(&[ctrl_k])), KeymapMatch::Pending);
+    assert_eq!(keymap.resolve_keys(None, &keys(&[ctrl_k, KeyCombo::new(Keycode::C).ctrl()])), KeymapMatch::Event(InputEvent::Copy));
+    assert_eq!(keymap.resolve_keys(None, &keys(&[ctrl_k, KeyCombo::new(Keycode::V).ctrl()])), KeymapMatch::NoMatch);
+}
+
+#[test]
+fn keymap_page_bindings_win_on_their_page()
+{
+    let mut keymap: Keymap<TestPage> = Keymap::default();
+    keymap.bind_on_page(TestPage::Viewer, Trigger::key(KeyCombo::new(Keycode::Left)), InputEvent::Back);
+    keymap.bind_on_page(TestPage::Viewer, Trigger::Mouse(MouseButton::X1), InputEvent::None);
+
+    assert_eq!(keymap.resolve_keys(Some(TestPage::Viewer), &keys(&[KeyCombo::new(Keycode::Left)])), KeymapMatch::Event(InputEvent::Back));
+    assert_eq!(keymap.resolve_keys(Some(TestPage::Editor), &keys(&[KeyCombo::new(Keycode::Left)])), KeymapMatch::Event(InputEvent::CursorLeft(false)));
+    assert_eq!(keymap.resolve_mouse(Some(TestPage::Viewer), MouseButton::X1), None);
+    assert_eq!(keymap.resolve_mouse(Some(TestPage::Editor), MouseButton::X1), Some(InputEvent::Back));
+}
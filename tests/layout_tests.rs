@@ -0,0 +1,111 @@
+use rust_page_system::misc::layout::{Align, Justify, LayoutNode, Padding, Size};
+use sdl3::rect::Rect;
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestKey
+{
+    First,
+    Second,
+    Third
+}
+
+fn fixed_leaf(key: TestKey, width: u32, height: u32) -> LayoutNode<TestKey>
+{
+    LayoutNode::leaf(key, Size::Fixed(width), Size::Fixed(height))
+}
+
+//
+// ==========================================================
+// Stack tests
+// ==========================================================
+//
+
+#[test]
+fn row_places_fixed_children_with_padding_and_gap()
+{
+    let row = LayoutNode { padding: Padding::all(10), gap: 5, ..LayoutNode::row(vec![fixed_leaf(TestKey::First, 100, 40), fixed_leaf(TestKey::Second, 50, 40)]) };
+
+    let layout = row.resolve(Rect::new(0, 0, 1920, 1080));
+
+    assert_eq!(layout.rect(&TestKey::First), Some(Rect::new(10, 10, 100, 40)));
+    assert_eq!(layout.rect(&TestKey::Second), Some(Rect::new(115, 10, 50, 40)));
+    assert_eq!(layout.rect(&TestKey::Third), None);
+}
+
+#[test]
+fn column_shares_free_space_between_fill_children_by_weight()
+{
+    let column = LayoutNode { gap: 10, ..LayoutNode::column(vec![fixed_leaf(TestKey::First, 100, 100), LayoutNode::leaf(TestKey::Second, Size::Fill(1), Size::Fill(1)), LayoutNode::leaf(TestKey::Third, Size::Fill(1), Size::Fill(2))]) };
+
+    let layout = column.resolve(Rect::new(0, 0, 400, 1000));
+
+    assert_eq!(layout.rect(&TestKey::Second), Some(Rect::new(0, 110, 400, 293)));
+    // The last Fill child takes the rounding leftovers
+    assert_eq!(layout.rect(&TestKey::Third), Some(Rect::new(0, 413, 400, 587)));
+}
+
+#[test]
+fn percent_sizes_are_relative_to_the_content_area()
+{
+    let row = LayoutNode { padding: Padding::symmetric(100, 0), ..LayoutNode::row(vec![LayoutNode::leaf(TestKey::First, Size::Percent(25.), Size::Percent(50.))]) };
+
+    let layout = row.resolve(Rect::new(0, 0, 1000, 200));
+
+    assert_eq!(layout.rect(&TestKey::First), Some(Rect::new(100, 0, 200, 100)));
+}
+
+//
+// ==========================================================
+// Alignment tests
+// ==========================================================
+//
+
+#[test]
+fn justify_center_end_and_space_between()
+{
+    let children = vec![fixed_leaf(TestKey::First, 100, 10), fixed_leaf(TestKey::Second, 100, 10)];
+    let area = Rect::new(0, 0, 500, 10);
+
+    let centered = LayoutNode { justify: Justify::Center, gap: 100, ..LayoutNode::row(children.clone()) }.resolve(area);
+    let at_end = LayoutNode { justify: Justify::End, ..LayoutNode::row(children.clone()) }.resolve(area);
+    let spread = LayoutNode { justify: Justify::SpaceBetween, ..LayoutNode::row(children) }.resolve(area);
+
+    assert_eq!(centered.rect(&TestKey::First).unwrap().x(), 100);
+    assert_eq!(centered.rect(&TestKey::Second).unwrap().x(), 300);
+    assert_eq!(at_end.rect(&TestKey::Second).unwrap().x(), 400);
+    assert_eq!(spread.rect(&TestKey::First).unwrap().x(), 0);
+    assert_eq!(spread.rect(&TestKey::Second).unwrap().x(), 400);
+}
+
+#[test]
+fn align_places_children_on_cross_axis_and_fill_stretches()
+{
+    let area = Rect::new(0, 0, 300, 100);
+
+    let centered = LayoutNode { align: Align::Center, ..LayoutNode::row(vec![fixed_leaf(TestKey::First, 50, 40), LayoutNode::leaf(TestKey::Second, Size::Fixed(50), Size::Fill(1))]) }.resolve(area);
+    let at_end = LayoutNode { align: Align::End, ..LayoutNode::row(vec![fixed_leaf(TestKey::First, 50, 40)]) }.resolve(area);
+
+    assert_eq!(centered.rect(&TestKey::First), Some(Rect::new(0, 30, 50, 40)));
+    assert_eq!(centered.rect(&TestKey::Second), Some(Rect::new(50, 0, 50, 100)));
+    assert_eq!(at_end.rect(&TestKey::First), Some(Rect::new(0, 60, 50, 40)));
+}
+
+#[test]
+fn nested_containers_resolve_inside_their_parent_rect()
+{
+    let sidebar = LayoutNode { width: Size::Fixed(200), padding: Padding::all(20), gap: 10, ..LayoutNode::column(vec![fixed_leaf(TestKey::First, 160, 50), fixed_leaf(TestKey::Second, 160, 50)]) };
+    let content = LayoutNode { key: Some(TestKey::Third), ..LayoutNode::column(Vec::new()) };
+    let root = LayoutNode::row(vec![sidebar, LayoutNode::spacer(Size::Fixed(20), Size::Fill(1)), content]);
+
+    let layout = root.resolve(Rect::new(0, 100, 1920, 980));
+
+    assert_eq!(layout.rect(&TestKey::First), Some(Rect::new(20, 120, 160, 50)));
+    assert_eq!(layout.rect(&TestKey::Second), Some(Rect::new(20, 180, 160, 50)));
+    assert_eq!(layout.rect(&TestKey::Third), Some(Rect::new(220, 100, 1700, 980)));
+}
@@ -0,0 +1,386 @@
+#![cfg(feature = "page-files")]
+
+use rust_page_system::{
+    impl_id_from_name,
+    system::{
+        page_file::{PageFile, PageFileFormat},
+        page_system::{ButtonStyle, Page, PageData},
+        scene_transition::{SlidePos, TransitionType},
+        state::AppState,
+        text_layout::{HorizontalAlign, VerticalAlign}
+    }
+};
+use sdl3::{pixels::Color, rect::Rect};
+use std::{
+    env,
+    fs::{self, File},
+    path::Path,
+    rc::Rc,
+    time::{Duration, UNIX_EPOCH}
+};
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    Home,
+    Header
+}
+impl_id_from_name!(TestPage { Home, Header });
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestButton
+{
+    Name,
+    Next
+}
+impl_id_from_name!(TestButton { Name, Next });
+
+const HOME_RON: &str = r#"(
+    pages: [
+        (
+            id: "Home",
+            persistent_elements: ["Header"],
+            user_input: ["Name"],
+            background_color: Some([30, 30, 46]),
+            buttons: [
+                (id: "Name", color: [203, 166, 247], rect: (100, 200, 400, 50), radius: 5, style: Some((hover_color: Some([180, 140, 230]), border: Some(([255, 255, 255], 2)))), tooltip: Some("First and last name")),
+                (id: "Next", color: [137, 180, 250], rect: (100, 300, 400, 50), enabled: false, transition: Some(Slide(Left, 5000))),
+            ],
+            texts: [
+                (size: 18.0, position: (100, 170), text: "Your Name", color: [205, 214, 244]),
+                (size: 25.0, position: (115, 235), color: [0, 0, 0], user_input: Some(0)),
+            ],
+            images: [(position: (10, 10), size: (64, 64), path: "logo.bmp")],
+        ),
+    ],
+    persistent_elements: [
+        (id: "Header", rects: [(color: [49, 50, 68, 200], rect: (0, 0, 1920, 100))]),
+    ],
+)"#;
+
+const HOME_TOML: &str = r#"
+[[pages]]
+id = "Home"
+persistent_elements = ["Header"]
+user_input = ["Name"]
+background_color = [30, 30, 46]
+
+[[pages.buttons]]
+id = "Name"
+color = [203, 166, 247]
+rect = [100, 200, 400, 50]
+radius = 5
+style = { hover_color = [180, 140, 230], border = [[255, 255, 255], 2] }
+tooltip = "First and last name"
+
+[[pages.buttons]]
+id = "Next"
+color = [137, 180, 250]
+rect = [100, 300, 400, 50]
+enabled = false
+transition = { Slide = ["Left", 5000] }
+
+[[pages.texts]]
+size = 18.0
+position = [100, 170]
+text = "Your Name"
+color = [205, 214, 244]
+
+[[pages.texts]]
+size = 25.0
+position = [115, 235]
+color = [0, 0, 0]
+user_input = 0
+
+[[pages.images]]
+position = [10, 10]
+size = [64, 64]
+path = "logo.bmp"
+
+[[persistent_elements]]
+id = "Header"
+rects = [{ color = [49, 50, 68, 200], rect = [0, 0, 1920, 100] }]
+"#;
+
+const HOME_JSON: &str = r#"{
+    "pages": [{
+        "id": "Home",
+        "persistent_elements": ["Header"],
+        "user_input": ["Name"],
+        "background_color": [30, 30, 46],
+        "buttons": [
+            { "id": "Name", "color": [203, 166, 247], "rect": [100, 200, 400, 50], "radius": 5, "style": { "hover_color": [180, 140, 230], "border": [[255, 255, 255], 2] }, "tooltip": "First and last name" },
+            { "id": "Next", "color": [137, 180, 250], "rect": [100, 300, 400, 50], "enabled": false, "transition": { "Slide": ["Left", 5000] } }
+        ],
+        "texts": [
+            { "size": 18.0, "position": [100, 170], "text": "Your Name", "color": [205, 214, 244] },
+            { "size": 25.0, "position": [115, 235], "color": [0, 0, 0], "user_input": 0 }
+        ],
+        "images": [{ "position": [10, 10], "size": [64, 64], "path": "logo.bmp" }]
+    }],
+    "persistent_elements": [
+        { "id": "Header", "rects": [{ "color": [49, 50, 68, 200], "rect": [0, 0, 1920, 100] }] }
+    ]
+}"#;
+
+fn home_page_file() -> PageFile
+{
+    PageFile::parse(HOME_RON, PageFileFormat::Ron).unwrap()
+}
+
+//
+// ==========================================================
+// Parsing tests
+// ==========================================================
+//
+
+#[test]
+fn ron_toml_and_json_describe_the_same_page()
+{
+    let ron_file = home_page_file();
+
+    assert_eq!(PageFile::parse(HOME_TOML, PageFileFormat::Toml).unwrap(), ron_file);
+    assert_eq!(PageFile::parse(HOME_JSON, PageFileFormat::Json).unwrap(), ron_file);
+}
+
+#[test]
+fn page_file_load_picks_format_from_extension()
+{
+    let directory = env::temp_dir().join("rps_page_file_tests");
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join("home.toml"), HOME_TOML).unwrap();
+    fs::write(directory.join("home.txt"), HOME_TOML).unwrap();
+
+    assert_eq!(PageFile::load(directory.join("home.toml")).unwrap(), home_page_file());
+    assert!(PageFile::load(directory.join("home.txt")).is_err());
+}
+
+#[test]
+fn page_file_rejects_unknown_fields()
+{
+    assert!(PageFile::parse(r#"{ "pages": [{ "id": "Home", "colour": [0, 0, 0] }] }"#, PageFileFormat::Json).is_err());
+}
+
+//
+// ==========================================================
+// Building pages tests
+// ==========================================================
+//
+
+#[test]
+fn page_file_builds_page_with_resolved_ids()
+{
+    let page_builders = home_page_file().input_linked_pages::<TestPage, TestButton>().unwrap();
+    let page = page_builders[0](&mut vec!["Ada".to_string()]);
+
+    assert_eq!(page.id, TestPage::Home);
+    assert_eq!(page.background_color, Some(Color::RGB(30, 30, 46)));
+    assert_eq!(page.has_userinput, Some(vec![(TestPage::Home, TestButton::Name)]));
+    let buttons = page.buttons.unwrap();
+    assert_eq!(buttons[0].rect, Rect::new(100, 200, 400, 50));
+    assert_eq!(buttons[0].radius, 5);
+    assert!(buttons[0].enabled);
+    assert_eq!(buttons[0].style, Some(ButtonStyle { hover_color: Some(Color::RGB(180, 140, 230)), border: Some((Color::RGB(255, 255, 255), 2)), ..ButtonStyle::default() }));
+    assert_eq!(buttons[0].tooltip.as_deref(), Some("First and last name"));
+    assert!(!buttons[1].enabled);
+    assert_eq!(buttons[1].has_transition, Some(TransitionType::Slide(0., SlidePos::Left, 5000)));
+    assert_eq!(page.texts.unwrap()[1].2, "Ada");
+    assert_eq!(page.images.unwrap()[0], ((10, 10), (64, 64), "logo.bmp".to_string()));
+    assert!(page.rects.is_none());
+
+    let persistent_elements = page.has_persistent_elements.unwrap();
+    assert_eq!(persistent_elements[0].0, TestPage::Header);
+    assert_eq!(persistent_elements[0].1().rects.unwrap()[0].0, Color::RGBA(49, 50, 68, 200));
+}
+
+#[test]
+fn page_file_text_boxes_are_aligned_and_filled_with_user_input()
+{
+    let page_file = PageFile::parse(r#"{ "pages": [{ "id": "Home", "user_input": ["Name"], "text_boxes": [{ "size": 20.0, "rect": [100, 200, 400, 50], "color": [0, 0, 0], "horizontal_align": "Center", "vertical_align": "Middle", "user_input": 0, "input": "Name" }] }] }"#, PageFileFormat::Json).unwrap();
+    let page = page_file.input_linked_pages::<TestPage, TestButton>().unwrap()[0](&mut vec!["Ada".to_string()]);
+
+    let text_box = &page.text_boxes.unwrap()[0];
+    assert_eq!(text_box.text, "Ada");
+    assert_eq!(text_box.rect, Rect::new(100, 200, 400, 50));
+    assert_eq!((text_box.horizontal_align, text_box.vertical_align), (HorizontalAlign::Center, VerticalAlign::Middle));
+    assert_eq!(text_box.input, Some(TestButton::Name));
+
+    let not_an_input = PageFile::parse(r#"{ "pages": [{ "id": "Home", "text_boxes": [{ "size": 20.0, "rect": [100, 200, 400, 50], "color": [0, 0, 0], "input": "Name" }] }] }"#, PageFileFormat::Json).unwrap();
+    assert!(not_an_input.input_linked_pages::<TestPage, TestButton>().err().unwrap().contains("not in its user_input"));
+}
+
+#[test]
+fn page_file_input_rules_are_applied_to_the_page()
+{
+    let page_file = PageFile::parse(
+        r#"
+[[pages]]
+id = "Home"
+user_input = ["Name", "Next"]
+
+[[pages.input_rules]]
+id = "Name"
+max_length = 4
+char_filter = "Digits"
+mask = "*"
+placeholder = "PIN"
+"#,
+        PageFileFormat::Toml
+    )
+    .unwrap();
+    let page = page_file.input_linked_pages::<TestPage, TestButton>().unwrap()[0](&mut Vec::new());
+
+    let input_rules = page.has_input_rules.unwrap();
+    assert_eq!(input_rules.len(), 1);
+    let (page_id, button_id, rules) = &input_rules[0];
+    assert_eq!((*page_id, *button_id), (TestPage::Home, TestButton::Name));
+    assert_eq!(rules.accepted_insert("", (0, 0), "1a2345"), "1234");
+    assert_eq!(rules.display_text("12"), "**");
+    assert_eq!(rules.placeholder_text(""), Some("PIN"));
+
+    let mut not_an_input = page_file.clone();
+    not_an_input.pages[0].user_input = vec!["Next".to_string()];
+    assert!(not_an_input.input_linked_pages::<TestPage, TestButton>().err().unwrap().contains("not in its user_input"));
+}
+
+#[test]
+fn page_file_input_rules_regex_needs_the_feature()
+{
+    let page_file = PageFile::parse(r#"(pages: [(id: "Home", user_input: ["Name"], input_rules: [(id: "Name", regex: Some(("^[0-9]+$", "Digits only")))])])"#, PageFileFormat::Ron).unwrap();
+    let page_builders = page_file.input_linked_pages::<TestPage, TestButton>();
+
+    #[cfg(feature = "regex-validation")]
+    {
+        let page = page_builders.unwrap()[0](&mut Vec::new());
+        let rules = &page.has_input_rules.unwrap()[0].2;
+        assert_eq!(rules.validate("42"), Ok(()));
+        assert_eq!(rules.validate("4x"), Err("Digits only".to_string()));
+    }
+    #[cfg(not(feature = "regex-validation"))]
+    assert!(page_builders.err().unwrap().contains("regex-validation"));
+}
+
+#[test]
+fn page_file_reports_unknown_names()
+{
+    let mut unknown_button = home_page_file();
+    unknown_button.pages[0].buttons[0].id = "Submit".to_string();
+    let mut unknown_persistent = home_page_file();
+    unknown_persistent.pages[0].persistent_elements = vec!["Footer".to_string()];
+
+    assert!(unknown_button.input_linked_pages::<TestPage, TestButton>().err().unwrap().contains("'Submit'"));
+    assert!(unknown_persistent.input_linked_pages::<TestPage, TestButton>().err().unwrap().contains("'Footer'"));
+}
+
+#[test]
+fn page_file_pages_feed_populate_rps_data()
+{
+    let mut app_state = AppState::new(TestPage::Home, (1920, 1080), true);
+    let mut page_data = PageData::new(&app_state);
+
+    page_data.populate_rps_data(None, Some(home_page_file().input_linked_pages().unwrap()));
+    page_data.create_current_page(&mut app_state);
+    page_data.vec_user_input[0].2 = "Grace".to_string();
+    page_data.update_vec_user_input_string();
+    page_data.create_current_page(&mut app_state);
+
+    assert_eq!(page_data.vec_user_input, vec![(TestPage::Home, TestButton::Name, "Grace".to_string())]);
+    assert_eq!(page_data.page_to_render.unwrap().texts.unwrap()[1].2, "Grace");
+}
+
+//
+// ==========================================================
+// Hot reload tests
+// ==========================================================
+//
+
+fn write_page_file(path: &Path, contents: &str, seconds_after_epoch: u64)
+{
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+    // Explicit mtimes so the tests don't depend on the filesystem timestamp resolution
+    File::options().write(true).open(path).unwrap().set_modified(UNIX_EPOCH + Duration::from_secs(seconds_after_epoch)).unwrap();
+}
+
+fn create_watched_state(path: &Path) -> (AppState<TestPage, TestButton>, PageData<TestPage, TestButton>)
+{
+    let mut app_state = AppState::new(TestPage::Home, (1920, 1080), true);
+    let mut page_data = PageData::new(&app_state);
+    page_data.load_page_files(&[path]).unwrap();
+    page_data.create_current_page(&mut app_state);
+    page_data.vec_user_input[0].2 = "Ada".to_string();
+    page_data.update_vec_user_input_string();
+    (app_state, page_data)
+}
+
+#[test]
+fn changed_page_file_is_reloaded_keeping_user_input()
+{
+    let path = env::temp_dir().join("rps_page_file_tests").join("hot_reload_keeps_input.ron");
+    write_page_file(&path, HOME_RON, 1_000);
+    let (mut app_state, mut page_data) = create_watched_state(&path);
+
+    assert!(!page_data.reload_changed_page_files().unwrap());
+
+    write_page_file(&path, &HOME_RON.replace("Your Name", "Your Full Name"), 2_000);
+    assert!(page_data.reload_changed_page_files().unwrap());
+    page_data.create_current_page(&mut app_state);
+
+    let texts = page_data.page_to_render.as_ref().unwrap().texts.clone().unwrap();
+    assert_eq!(texts[0].2, "Your Full Name");
+    assert_eq!(texts[1].2, "Ada");
+    assert_eq!(page_data.vec_user_input, vec![(TestPage::Home, TestButton::Name, "Ada".to_string())]);
+    assert_eq!(page_data.page_w_input_linked.len(), 1);
+}
+
+#[test]
+fn broken_page_file_keeps_previous_pages()
+{
+    let path = env::temp_dir().join("rps_page_file_tests").join("hot_reload_broken.ron");
+    write_page_file(&path, HOME_RON, 1_000);
+    let (mut app_state, mut page_data) = create_watched_state(&path);
+
+    write_page_file(&path, "(pages: [(id: \"Home\",", 2_000);
+    assert!(page_data.reload_changed_page_files().is_err());
+    // The error is reported once, not on every poll
+    assert!(!page_data.reload_changed_page_files().unwrap());
+    page_data.create_current_page(&mut app_state);
+
+    assert_eq!(page_data.page_to_render.as_ref().unwrap().texts.clone().unwrap()[0].2, "Your Name");
+}
+
+#[test]
+fn page_removed_from_file_is_unlinked()
+{
+    let path = env::temp_dir().join("rps_page_file_tests").join("hot_reload_removed.json");
+    write_page_file(&path, HOME_JSON, 1_000);
+    let (_, mut page_data) = create_watched_state(&path);
+
+    write_page_file(&path, "{}", 2_000);
+
+    assert!(page_data.reload_changed_page_files().unwrap());
+    assert!(page_data.page_w_input_linked.is_empty());
+    assert!(page_data.watched_page_files[0].page_ids.is_empty());
+}
+
+#[test]
+fn page_file_replaces_a_code_page_with_the_same_id()
+{
+    let path = env::temp_dir().join("rps_page_file_tests").join("replaces_code_page.ron");
+    write_page_file(&path, HOME_RON, 1_000);
+    let mut app_state: AppState<TestPage, TestButton> = AppState::new(TestPage::Home, (1920, 1080), true);
+    let mut page_data = PageData::new(&app_state);
+    page_data.populate_rps_data(Some(vec![Rc::new(|| Page::new(TestPage::Home)), Rc::new(|| Page::new(TestPage::Header))]), None);
+
+    page_data.load_page_files(&[&path]).unwrap();
+    page_data.create_current_page(&mut app_state);
+
+    assert_eq!(page_data.page_linked.iter().map(|(page_id, _)| *page_id).collect::<Vec<_>>(), vec![TestPage::Header]);
+    assert_eq!(page_data.page_to_render.as_ref().unwrap().texts.clone().unwrap()[0].2, "Your Name");
+}
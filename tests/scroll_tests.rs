@@ -0,0 +1,236 @@
+use rust_page_system::system::{
+    clock::ManualClock,
+    input_handler::{InputEvent, InputHandler},
+    input_source::{MemoryClipboard, ScriptedInput, ScriptedStep},
+    page_system::{Button, Page, PageData, TextBox},
+    scroll::{SCROLL_STEP, ScrollRegion, scroll_page},
+    state::AppState
+};
+use sdl3::{pixels::Color, rect::Rect};
+use std::{cell::Cell, time::Duration};
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    List
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestButton
+{
+    Title,
+    Item,
+    ListRegion
+}
+
+/// 200px tall region showing 600px of content, with its scrollbar on x 490..500
+fn list_region(kinetic: bool) -> ScrollRegion<TestButton>
+{
+    ScrollRegion { id: TestButton::ListRegion, rect: Rect::new(100, 100, 400, 200), content_height: 600, scrollbar_color: Color::RGB(200, 200, 200), kinetic }
+}
+
+fn button(id: TestButton, rect: Rect) -> Button<TestButton>
+{
+    Button::new(id, Color::RGB(0, 0, 0), rect)
+}
+
+/// Title above the region, Item near the bottom of its content
+fn list_page(kinetic: bool) -> Page<TestPage, TestButton>
+{
+    let buttons = vec![button(TestButton::Title, Rect::new(100, 20, 200, 50)), button(TestButton::Item, Rect::new(120, 500, 200, 50))];
+    Page { has_scroll_regions: Some(vec![list_region(kinetic)]), buttons: Some(buttons), ..Page::new(TestPage::List) }
+}
+
+fn list_page_data(kinetic: bool) -> (AppState<TestPage, TestButton>, PageData<TestPage, TestButton>)
+{
+    let app_state = AppState::new(TestPage::List, (1920, 1080), true);
+    let mut page_data = PageData::new(&app_state);
+    page_data.page_to_render = Some(list_page(kinetic));
+    (app_state, page_data)
+}
+
+fn offset(page_data: &PageData<TestPage, TestButton>) -> f32
+{
+    page_data.scroll_offset(TestPage::List, TestButton::ListRegion)
+}
+
+//
+// ==========================================================
+// ScrollRegion geometry
+// ==========================================================
+//
+
+#[test]
+fn scrollbar_thumb_follows_the_offset_and_the_visible_share()
+{
+    let scroll_region = list_region(false);
+
+    assert_eq!(scroll_region.max_offset(), 400.);
+    assert_eq!(scroll_region.track_rect(), Some(Rect::new(490, 100, 10, 200)));
+    assert_eq!(scroll_region.thumb_rect(0.), Some(Rect::new(490, 100, 10, 66)));
+    assert_eq!(scroll_region.thumb_rect(400.), Some(Rect::new(490, 234, 10, 66)));
+    assert_eq!(scroll_region.offset_for_thumb(167), 200.);
+    assert_eq!(scroll_region.offset_for_thumb(1000), 400.);
+
+    let fitting_region = ScrollRegion { content_height: 150, ..scroll_region };
+    assert_eq!(fitting_region.max_offset(), 0.);
+    assert_eq!(fitting_region.thumb_rect(0.), None);
+}
+
+#[test]
+fn scroll_page_moves_and_clips_only_the_region_content()
+{
+    let mut page = list_page(false);
+    page.texts = Some(vec![(18., (120, 510), "Item".to_string(), Color::RGB(0, 0, 0)), (18., (100, 30), "Title".to_string(), Color::RGB(0, 0, 0))]);
+    page.text_boxes = Some(vec![TextBox::new("Gone", 18., Color::RGB(0, 0, 0), Rect::new(120, 120, 100, 30)).bounded(), TextBox::new("Shown", 18., Color::RGB(0, 0, 0), Rect::new(120, 460, 100, 30))]);
+
+    let scroll_clips = scroll_page(&mut page, &[(list_region(false), 350)]);
+
+    let buttons = page.buttons.unwrap();
+    assert_eq!(buttons[0].rect, Rect::new(100, 20, 200, 50));
+    assert_eq!(buttons[1].rect, Rect::new(120, 150, 200, 50));
+    assert_eq!(scroll_clips.buttons, vec![None, Some(Rect::new(100, 100, 400, 200))]);
+    assert_eq!(page.texts.unwrap().iter().map(|text| text.1).collect::<Vec<_>>(), vec![(120, 160), (100, 30)]);
+    assert_eq!(scroll_clips.texts, vec![Some(Rect::new(100, 100, 400, 200)), None]);
+    // A text box clipped to its own rect is dropped once that rect leaves the region
+    let text_boxes = page.text_boxes.unwrap();
+    assert_eq!(text_boxes.len(), 1);
+    assert_eq!((text_boxes[0].rect, text_boxes[0].clip_rect), (Rect::new(120, 110, 100, 30), Some(Rect::new(100, 100, 400, 200))));
+}
+
+//
+// ==========================================================
+// Hit testing and input
+// ==========================================================
+//
+
+#[test]
+fn page_button_at_hits_scrolled_buttons_only_inside_their_region()
+{
+    let (app_state, mut page_data) = list_page_data(false);
+
+    // Declared at y 500, below the visible part of the region
+    assert_eq!(page_data.page_button_at(&app_state, 130., 510.), None);
+    assert_eq!(page_data.page_button_at(&app_state, 130., 160.), None);
+
+    page_data.set_scroll_offset(TestPage::List, &list_region(false), 350.);
+    assert_eq!(page_data.page_button_at(&app_state, 130., 160.), Some(TestButton::Item));
+    assert_eq!(page_data.page_button_at(&app_state, 130., 510.), None);
+    assert_eq!(page_data.page_button_at(&app_state, 110., 30.), Some(TestButton::Title));
+    // The scrollbar sits above the region content
+    assert_eq!(page_data.page_button_at(&app_state, 495., 160.), None);
+}
+
+#[test]
+fn wheel_keys_and_scrollbar_drag_scroll_the_region_under_the_mouse()
+{
+    let (mut app_state, mut page_data) = list_page_data(false);
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+    let clicked = Cell::new(false);
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, _: &TestButton, _: &mut PageData<TestPage, TestButton>| clicked.set(true);
+    let mut process = |input_handler: &mut InputHandler<TestPage, TestButton>, page_data: &mut PageData<TestPage, TestButton>, input_event: InputEvent| input_handler.process_event(input_event, &mut clipboard, page_data, &mut app_state, &mut button_action);
+
+    input_handler.mouse_position = (200., 150.);
+    process(&mut input_handler, &mut page_data, InputEvent::Wheel(-1.));
+    assert_eq!(offset(&page_data), SCROLL_STEP);
+    process(&mut input_handler, &mut page_data, InputEvent::CursorDown(false));
+    assert_eq!(offset(&page_data), 2. * SCROLL_STEP);
+    process(&mut input_handler, &mut page_data, InputEvent::End(false));
+    assert_eq!(offset(&page_data), 400.);
+    process(&mut input_handler, &mut page_data, InputEvent::Home(false));
+    assert_eq!(offset(&page_data), 0.);
+
+    // Outside the region the wheel does nothing
+    input_handler.mouse_position = (800., 150.);
+    process(&mut input_handler, &mut page_data, InputEvent::Wheel(-1.));
+    assert_eq!(offset(&page_data), 0.);
+
+    // Clicking the track below the thumb jumps there, then dragging moves it back up
+    input_handler.mouse_position = (495., 290.);
+    process(&mut input_handler, &mut page_data, InputEvent::Click);
+    assert_eq!(offset(&page_data), 400.);
+    input_handler.mouse_position = (495., 100.);
+    process(&mut input_handler, &mut page_data, InputEvent::Drag);
+    assert_eq!(offset(&page_data), 0.);
+    process(&mut input_handler, &mut page_data, InputEvent::MouseUp);
+    input_handler.mouse_position = (495., 290.);
+    process(&mut input_handler, &mut page_data, InputEvent::Drag);
+    assert_eq!(offset(&page_data), 0.);
+    assert!(!clicked.get());
+}
+
+#[test]
+fn kinetic_wheel_scroll_glides_over_the_next_frames()
+{
+    let (mut app_state, mut page_data) = list_page_data(true);
+    let mut input_handler = InputHandler::new(false);
+    input_handler.mouse_position = (200., 150.);
+    let frame = Duration::from_secs(1) / 60;
+
+    input_handler.process_event(InputEvent::Wheel(-1.), &mut MemoryClipboard::default(), &mut page_data, &mut app_state, &mut |_, _, _| {});
+    assert_eq!(offset(&page_data), 0.);
+    page_data.step_kinetic_scroll(frame);
+    let first_frame = offset(&page_data);
+    assert!(first_frame > 0. && first_frame < SCROLL_STEP);
+    for _ in 0..100
+    {
+        page_data.step_kinetic_scroll(frame);
+    }
+    assert!(offset(&page_data) > SCROLL_STEP - 2. && offset(&page_data) <= SCROLL_STEP);
+}
+
+#[test]
+fn kinetic_scroll_follows_the_clock_not_the_calls()
+{
+    let (mut app_state, mut page_data) = list_page_data(true);
+    let (mut other_app_state, mut other_page_data) = list_page_data(true);
+    let mut input_handler = InputHandler::new(false);
+    let mut other_input_handler = InputHandler::new(false);
+    let clock = ManualClock::default();
+    let other_clock = ManualClock::default();
+    input_handler.set_clock(clock.clone());
+    other_input_handler.set_clock(other_clock.clone());
+    let mut clipboard = MemoryClipboard::default();
+    let wheel = || ScriptedInput::new(vec![ScriptedStep::new(0, (200., 150.), InputEvent::Wheel(-1.))]);
+    let idle = || ScriptedInput::new(vec![ScriptedStep::new(0, (200., 150.), InputEvent::None)]);
+
+    input_handler.handle_input(&mut wheel(), &mut clipboard, &mut page_data, &mut app_state, &mut |_, _, _| {});
+    other_input_handler.handle_input(&mut wheel(), &mut clipboard, &mut other_page_data, &mut other_app_state, &mut |_, _, _| {});
+    // Without time passing nothing moves, however often handle_input runs
+    input_handler.handle_input(&mut idle(), &mut clipboard, &mut page_data, &mut app_state, &mut |_, _, _| {});
+    assert_eq!(offset(&page_data), 0.);
+
+    // Two frames at 60 fps glide as far as four at 120 fps
+    for _ in 0..2
+    {
+        clock.advance(Duration::from_secs(1) / 60);
+        input_handler.handle_input(&mut idle(), &mut clipboard, &mut page_data, &mut app_state, &mut |_, _, _| {});
+    }
+    for _ in 0..4
+    {
+        other_clock.advance(Duration::from_secs(1) / 120);
+        other_input_handler.handle_input(&mut idle(), &mut clipboard, &mut other_page_data, &mut other_app_state, &mut |_, _, _| {});
+    }
+    assert!(offset(&page_data) > 0.);
+    assert!((offset(&page_data) - offset(&other_page_data)).abs() < 0.01);
+}
+
+#[test]
+fn focusing_a_scrolled_button_scrolls_it_into_view()
+{
+    let (mut app_state, mut page_data) = list_page_data(false);
+    let mut input_handler = InputHandler::new(false);
+
+    input_handler.move_focus(false, &mut app_state, &mut page_data);
+    assert_eq!((input_handler.focused_button, offset(&page_data)), (Some(TestButton::Title), 0.));
+    input_handler.move_focus(false, &mut app_state, &mut page_data);
+    // Item ends at y 550 and the region at y 300
+    assert_eq!((input_handler.focused_button, offset(&page_data)), (Some(TestButton::Item), 250.));
+}
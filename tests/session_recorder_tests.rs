@@ -0,0 +1,250 @@
+#![cfg(feature = "serde")]
+
+use rust_page_system::system::{
+    clock::ManualClock,
+    input_handler::{ButtonEventKind, InputEvent, InputHandler},
+    input_source::{MemoryClipboard, ScriptedInput, ScriptedStep},
+    page_system::{Button, Page, PageData},
+    session_recorder::{RecordedStep, load_session},
+    state::AppState
+};
+use sdl3::{pixels::Color, rect::Rect};
+use std::{env, path::PathBuf, rc::Rc, time::Duration};
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    Home,
+    Settings
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestButton
+{
+    Input,
+    GoSettings
+}
+
+const INPUT_BUTTON_CENTER: (f32, f32) = (300., 225.);
+const GO_SETTINGS_BUTTON_CENTER: (f32, f32) = (300., 425.);
+
+fn home_page() -> Page<TestPage, TestButton>
+{
+    let input_button = Button::new(TestButton::Input, Color::RGB(203, 166, 247), Rect::new(100, 200, 400, 50));
+    let go_settings_button = Button::new(TestButton::GoSettings, Color::RGB(137, 180, 250), Rect::new(100, 400, 400, 50));
+    Page { has_userinput: Some(vec![(TestPage::Home, TestButton::Input)]), buttons: Some(vec![input_button, go_settings_button]), ..Page::new(TestPage::Home) }
+}
+
+fn settings_page() -> Page<TestPage, TestButton>
+{
+    Page::new(TestPage::Settings)
+}
+
+fn create_linked_state() -> (AppState<TestPage, TestButton>, PageData<TestPage, TestButton>)
+{
+    let app_state = AppState::new(TestPage::Home, (1920, 1080), true);
+    let mut page_data = PageData::new(&app_state);
+    page_data.populate_rps_data(Some(vec![Rc::new(home_page), Rc::new(settings_page)]), None);
+    (app_state, page_data)
+}
+
+fn button_action(app_state: &mut AppState<TestPage, TestButton>, button_id: &TestButton, page_data: &mut PageData<TestPage, TestButton>)
+{
+    if app_state.capturing_input.0
+    {
+        return;
+    }
+    match button_id
+    {
+        TestButton::Input => app_state.capturing_input = (true, Some(*button_id)),
+        TestButton::GoSettings => app_state.change_current_page(page_data, TestPage::Settings, button_id)
+    }
+}
+
+fn session_path(name: &str) -> PathBuf
+{
+    env::temp_dir().join("rps_session_tests").join(name)
+}
+
+//
+// ==========================================================
+// JSON Lines encoding tests
+// ==========================================================
+//
+
+#[test]
+fn recorded_step_round_trips_every_event_kind()
+{
+    let events = vec![
+        InputEvent::Click,
+        InputEvent::DoubleClick,
+        InputEvent::TripleClick,
+        InputEvent::Drag,
+        InputEvent::MouseUp,
+        InputEvent::Text("say \"hi\"\\\n\tçé🙂".to_string()),
+        InputEvent::Backspace,
+        InputEvent::Delete,
+        InputEvent::DeleteWordBackward,
+        InputEvent::DeleteWordForward,
+        InputEvent::Submit,
+        InputEvent::Front,
+        InputEvent::Back,
+        InputEvent::Paste,
+        InputEvent::Copy,
+        InputEvent::Cut,
+        InputEvent::Undo,
+        InputEvent::Redo,
+        InputEvent::SelectAll,
+        InputEvent::DeleteAll,
+        InputEvent::CursorLeft(true),
+        InputEvent::CursorRight(false),
+        InputEvent::WordLeft(true),
+        InputEvent::WordRight(false),
+        InputEvent::CursorUp(true),
+        InputEvent::CursorDown(false),
+        InputEvent::Home(true),
+        InputEvent::End(false),
+        InputEvent::PageUp(false),
+        InputEvent::PageDown(true),
+        InputEvent::ExitCapturingInput,
+        InputEvent::FocusNext,
+        InputEvent::FocusPrevious,
+        InputEvent::Activate,
+        InputEvent::NavigateUp,
+        InputEvent::NavigateDown,
+        InputEvent::NavigateLeft,
+        InputEvent::NavigateRight,
+        InputEvent::RightClick,
+        InputEvent::Wheel(-1.5),
+        InputEvent::Quit,
+    ];
+
+    for event in events
+    {
+        let recorded_step = RecordedStep { timestamp_ms: 1520, page: "Home".to_string(), mouse_position: (412.5, -3.25), event };
+        let json_line = recorded_step.to_json_line().unwrap();
+
+        assert!(!json_line.contains('\n'));
+        assert_eq!(RecordedStep::from_json_line(&json_line).unwrap(), recorded_step);
+    }
+}
+
+#[test]
+fn recorded_step_rejects_malformed_lines()
+{
+    assert!(RecordedStep::from_json_line("{\"t\":1,\"page\":\"Home\",\"mouse\":[0,0],\"event\":\"Jump\"}").is_err());
+    assert!(RecordedStep::from_json_line("{\"t\":1,\"page\":\"Home\",\"event\":\"Click\"}").is_err());
+    assert!(RecordedStep::from_json_line("{\"t\":1,\"page\":\"Home\"").is_err());
+    assert!(RecordedStep::from_json_line("not json").is_err());
+}
+
+//
+// ==========================================================
+// Record and replay tests
+// ==========================================================
+//
+
+#[test]
+fn recording_writes_one_line_per_handled_event()
+{
+    let path = session_path("one_line_per_event.jsonl");
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+    let mut script = ScriptedInput::new(vec![ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::Click), ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::None), ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::Text("ab".to_string()))]);
+
+    input_handler.start_recording(&path).unwrap();
+    input_handler.play_script(&mut script, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    input_handler.stop_recording();
+
+    let recorded_steps = load_session(&path).unwrap();
+    assert!(!input_handler.is_recording());
+    assert_eq!(recorded_steps.len(), 2);
+    assert_eq!(recorded_steps[0].page, "Home");
+    assert_eq!(recorded_steps[0].mouse_position, INPUT_BUTTON_CENTER);
+    assert_eq!(recorded_steps[1].event, InputEvent::Text("ab".to_string()));
+}
+
+#[test]
+fn recording_keeps_mouse_moves_and_replays_long_presses()
+{
+    let path = session_path("mouse_moves_and_long_press.jsonl");
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let clock = ManualClock::default();
+    input_handler.set_clock(clock.clone());
+    let mut clipboard = MemoryClipboard::default();
+
+    input_handler.start_recording(&path).unwrap();
+    input_handler.play_script(&mut ScriptedInput::new(vec![ScriptedStep::new(0, (1500., 900.), InputEvent::None), ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::None), ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::None), ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::Click)]), &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    clock.advance(Duration::from_millis(600));
+    input_handler.play_script(&mut ScriptedInput::new(vec![ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::MouseUp)]), &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+    input_handler.stop_recording();
+
+    // The mouse standing still isn't written
+    let recorded_steps = load_session(&path).unwrap();
+    assert_eq!(recorded_steps.iter().map(|recorded_step| (recorded_step.timestamp_ms, recorded_step.event.clone())).collect::<Vec<_>>(), vec![(0, InputEvent::None), (0, InputEvent::None), (0, InputEvent::Click), (600, InputEvent::MouseUp)]);
+
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    input_handler.replay_session(&recorded_steps, &mut clipboard, &mut page_data, &mut app_state, &mut button_action).unwrap();
+    let button_event_kinds: Vec<ButtonEventKind> = input_handler.take_button_events().iter().map(|button_event| button_event.kind).collect();
+    assert_eq!(button_event_kinds, vec![ButtonEventKind::HoverEnter, ButtonEventKind::Press, ButtonEventKind::LongPress, ButtonEventKind::Click]);
+}
+
+#[test]
+fn replaying_recorded_session_reproduces_final_state()
+{
+    let path = session_path("reproduces_final_state.jsonl");
+    let steps = vec![ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::Click), ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::MouseUp), ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::Text("hello".to_string())), ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::CursorLeft(true)), ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::Cut), ScriptedStep::new(0, INPUT_BUTTON_CENTER, InputEvent::Submit), ScriptedStep::new(0, GO_SETTINGS_BUTTON_CENTER, InputEvent::Click), ScriptedStep::new(0, GO_SETTINGS_BUTTON_CENTER, InputEvent::MouseUp)];
+    let (mut recorded_app_state, mut recorded_page_data) = create_linked_state();
+    let mut recording_handler = InputHandler::new(false);
+    let mut recorded_clipboard = MemoryClipboard::default();
+    recording_handler.start_recording(&path).unwrap();
+    recording_handler.play_script(&mut ScriptedInput::new(steps), &mut recorded_clipboard, &mut recorded_page_data, &mut recorded_app_state, &mut button_action);
+    recording_handler.stop_recording();
+
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+    let replayed_steps = input_handler.replay_session(&load_session(&path).unwrap(), &mut clipboard, &mut page_data, &mut app_state, &mut button_action).unwrap();
+
+    assert_eq!(replayed_steps, 8);
+    assert_eq!(app_state.current_page, TestPage::Settings);
+    assert_eq!(page_data.vec_user_input, recorded_page_data.vec_user_input);
+    assert_eq!(page_data.vec_user_input[0].2, "hell");
+    assert_eq!(clipboard, recorded_clipboard);
+}
+
+#[test]
+fn replay_stops_at_recorded_quit_without_exiting()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+    let recorded_steps = vec![RecordedStep { timestamp_ms: 0, page: "Home".to_string(), mouse_position: INPUT_BUTTON_CENTER, event: InputEvent::Quit }, RecordedStep { timestamp_ms: 5, page: "Home".to_string(), mouse_position: INPUT_BUTTON_CENTER, event: InputEvent::Click }];
+
+    let replayed_steps = input_handler.replay_session(&recorded_steps, &mut clipboard, &mut page_data, &mut app_state, &mut button_action).unwrap();
+
+    assert_eq!(replayed_steps, 0);
+    assert_eq!(app_state.capturing_input, (false, None));
+}
+
+#[test]
+fn replay_fails_when_page_diverges()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let mut clipboard = MemoryClipboard::default();
+    let recorded_steps = vec![RecordedStep { timestamp_ms: 0, page: "Home".to_string(), mouse_position: INPUT_BUTTON_CENTER, event: InputEvent::Click }, RecordedStep { timestamp_ms: 5, page: "Settings".to_string(), mouse_position: INPUT_BUTTON_CENTER, event: InputEvent::Click }];
+
+    let result = input_handler.replay_session(&recorded_steps, &mut clipboard, &mut page_data, &mut app_state, &mut button_action);
+
+    assert!(result.unwrap_err().contains("step 2"));
+}
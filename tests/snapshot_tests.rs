@@ -0,0 +1,267 @@
+use rust_page_system::system::{
+    input_handler::InputHandler,
+    page_system::{Button, Page, PageData},
+    renderer::{Renderer, RendererConfig},
+    scene_transition::{SceneTransition, SlidePos, TransitionType},
+    snapshot::{RgbaFrame, SnapshotConfig, capture_transition_frame, compare_page_snapshot, compare_with_reference},
+    state::AppState,
+    window::{WINDOW_DEFAULT_SCALE, WindowConfig, create_headless_window}
+};
+use sdl3::{pixels::Color, rect::Rect};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Mutex
+};
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    Home,
+    Next
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestButton
+{
+    FadeToNext,
+    SlideToNext,
+    Back
+}
+
+/// Each headless test starts its own SDL, one at a time
+static SDL_LOCK: Mutex<()> = Mutex::new(());
+
+/// No text, so the references don't depend on the installed fonts
+fn home_page() -> Page<TestPage, TestButton>
+{
+    let rects = vec![(Color::RGB(200, 40, 40), (Rect::new(100, 100, 300, 200), 0)), (Color::RGB(230, 180, 40), (Rect::new(100, 400, 300, 200), 40))];
+    let buttons = vec![Button::new(TestButton::FadeToNext, Color::RGB(40, 160, 80), Rect::new(600, 100, 400, 120)).with_transition(TransitionType::Fade(0.)), Button::new(TestButton::SlideToNext, Color::RGB(40, 80, 200), Rect::new(600, 400, 400, 120)).with_radius(30).with_transition(TransitionType::Slide(0., SlidePos::Left, 1920))];
+    Page { background_color: Some(Color::RGB(30, 30, 60)), rects: Some(rects), buttons: Some(buttons), ..Page::new(TestPage::Home) }
+}
+
+fn next_page() -> Page<TestPage, TestButton>
+{
+    let buttons = vec![Button::new(TestButton::Back, Color::RGB(180, 60, 160), Rect::new(1200, 700, 500, 200)).with_radius(60)];
+    Page { background_color: Some(Color::RGB(220, 220, 210)), buttons: Some(buttons), ..Page::new(TestPage::Next) }
+}
+
+fn reference_path(name: &str) -> PathBuf
+{
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("snapshots").join(name)
+}
+
+/// Open a headless window on the Home page and hand the modules to `check`
+fn with_headless_home_page(check: impl FnOnce(&mut Renderer<TestPage, TestButton>, &mut PageData<TestPage, TestButton>, &mut AppState<TestPage, TestButton>, &InputHandler<TestPage, TestButton>))
+{
+    let _sdl_guard = SDL_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let window_config = WindowConfig { window_title: "Snapshot Test".to_string(), icon: (None, None), start_window_size: WINDOW_DEFAULT_SCALE, window_minimum_size: WINDOW_DEFAULT_SCALE, resizable: false, centered: false, different_sdl_presentation_mode: None, font: ("DejaVu Sans".to_string(), None) };
+    let window_modules = create_headless_window(window_config);
+    let mut app_state = AppState::new(TestPage::Home, WINDOW_DEFAULT_SCALE, window_modules.stretch_mode_status);
+    let mut page_data = PageData::new(&app_state);
+    let input_handler = InputHandler::new(false);
+    let renderer_config = RendererConfig { canvas: window_modules.canvas, texture_creator: &window_modules.texture_creator, ttf_context: &window_modules.ttf_context, font_path: &window_modules.font_path, decrease_color_when_selected: None, selection_color: None, assets_dir: None };
+    let mut renderer = Renderer::new(renderer_config);
+
+    page_data.populate_rps_data(Some(vec![Rc::new(home_page), Rc::new(next_page)]), None);
+    page_data.create_current_page(&mut app_state);
+    check(&mut renderer, &mut page_data, &mut app_state, &input_handler);
+}
+
+//
+// ==========================================================
+// Helpers
+// ==========================================================
+//
+
+fn solid_frame(width: u32, height: u32, rgba: (u8, u8, u8, u8)) -> RgbaFrame
+{
+    let pixels = (0..width * height).flat_map(|_| [rgba.0, rgba.1, rgba.2, rgba.3]).collect();
+    RgbaFrame::new(width, height, pixels).unwrap()
+}
+
+fn exact_config() -> SnapshotConfig
+{
+    SnapshotConfig { tolerance: 0, max_mismatched_pixels: 0, update_references: false }
+}
+
+//
+// ==========================================================
+// RgbaFrame tests
+// ==========================================================
+//
+
+#[test]
+fn rgba_frame_rejects_buffer_with_wrong_length()
+{
+    assert!(RgbaFrame::new(2, 2, vec![0; 15]).is_err());
+    assert!(RgbaFrame::new(2, 2, vec![0; 16]).is_ok());
+}
+
+#[test]
+fn rgba_frame_pixel_reads_expected_value_and_bounds()
+{
+    let frame = solid_frame(3, 2, (10, 20, 30, 255));
+
+    assert_eq!(frame.pixel(2, 1), Some((10, 20, 30, 255)));
+    assert_eq!(frame.pixel(3, 0), None);
+    assert_eq!(frame.pixel(0, 2), None);
+}
+
+#[test]
+fn rgba_frame_save_png_creates_file()
+{
+    let output_path = env::temp_dir().join("rps_snapshot_tests").join("save_png_creates_file.png");
+    let _ = fs::remove_file(&output_path);
+
+    solid_frame(4, 4, (255, 0, 0, 255)).save_png(&output_path).unwrap();
+
+    assert!(output_path.exists());
+}
+
+#[test]
+fn rgba_frame_png_round_trip_keeps_pixels()
+{
+    let output_path = env::temp_dir().join("rps_snapshot_tests").join("round_trip.png");
+    let mut frame = solid_frame(3, 3, (1, 2, 3, 255));
+    frame.pixels[4..8].copy_from_slice(&[200, 100, 50, 128]);
+
+    frame.save_png(&output_path).unwrap();
+
+    assert_eq!(RgbaFrame::load_png(&output_path).unwrap(), frame);
+}
+
+//
+// ==========================================================
+// Snapshot comparison tests
+// ==========================================================
+//
+
+#[test]
+fn snapshot_compare_respects_tolerance()
+{
+    let reference = solid_frame(2, 2, (100, 100, 100, 255));
+    let close_frame = solid_frame(2, 2, (103, 98, 100, 255));
+
+    assert_eq!(close_frame.compare(&reference, 3).mismatched_pixels, 0);
+    assert_eq!(close_frame.compare(&reference, 2).mismatched_pixels, 4);
+    assert_eq!(close_frame.compare(&reference, 0).max_channel_difference, 3);
+}
+
+#[test]
+fn snapshot_compare_reports_size_mismatch()
+{
+    let diff = solid_frame(2, 2, (0, 0, 0, 255)).compare(&solid_frame(3, 2, (0, 0, 0, 255)), 255);
+
+    assert!(!diff.size_matches);
+}
+
+#[test]
+fn snapshot_missing_reference_fails_unless_updating()
+{
+    let reference_path = env::temp_dir().join("rps_snapshot_tests").join("missing_reference.png");
+    let actual_path = env::temp_dir().join("rps_snapshot_tests").join("missing_reference.actual.png");
+    let _ = fs::remove_file(&reference_path);
+    let _ = fs::remove_file(&actual_path);
+
+    assert!(compare_with_reference(&solid_frame(2, 2, (5, 5, 5, 255)), &reference_path, &exact_config()).is_err());
+    assert!(!reference_path.exists());
+
+    let update_config = SnapshotConfig { update_references: true, ..exact_config() };
+    assert!(compare_with_reference(&solid_frame(2, 2, (5, 5, 5, 255)), &reference_path, &update_config).is_ok());
+    assert!(reference_path.exists());
+
+    assert!(compare_with_reference(&solid_frame(2, 2, (5, 5, 5, 255)), &reference_path, &exact_config()).is_ok());
+    assert!(compare_with_reference(&solid_frame(2, 2, (90, 5, 5, 255)), &reference_path, &exact_config()).is_err());
+    assert!(actual_path.exists());
+}
+
+//
+// ==========================================================
+// Frozen SceneTransition tests
+// ==========================================================
+//
+
+#[test]
+fn frozen_fade_first_half_darkens_without_switching()
+{
+    let mut transition: SceneTransition<u8> = SceneTransition::new(TransitionType::Fade(0.), 500, Some(1));
+    transition.freeze_at(0.25);
+
+    assert!(!transition.update());
+    assert_eq!(transition.transition_type, TransitionType::Fade(0.5));
+    assert!(!transition.is_second_stage);
+    assert!(transition.is_settled());
+}
+
+#[test]
+fn frozen_fade_second_half_enters_second_stage()
+{
+    let mut transition: SceneTransition<u8> = SceneTransition::new(TransitionType::Fade(0.), 500, Some(1));
+    transition.freeze_at(0.75);
+
+    transition.update();
+    assert!(transition.is_second_stage);
+    assert!(!transition.is_settled());
+
+    transition.update();
+    assert_eq!(transition.transition_type, TransitionType::Fade(0.5));
+}
+
+#[test]
+fn frozen_slide_uses_exact_progress()
+{
+    let mut transition: SceneTransition<u8> = SceneTransition::new(TransitionType::Slide(0., SlidePos::Left, 1000), 500, Some(1));
+    transition.freeze_at(0.4);
+
+    assert!(!transition.update());
+    assert_eq!(transition.transition_type, TransitionType::Slide(0.4, SlidePos::Left, 1000));
+}
+
+//
+// ==========================================================
+// Headless page and transition snapshots
+// ==========================================================
+//
+
+#[test]
+fn page_snapshot_matches_reference()
+{
+    with_headless_home_page(|renderer, page_data, app_state, input_handler| {
+        compare_page_snapshot(renderer, page_data, app_state, input_handler, reference_path("home_page.png"), &SnapshotConfig::default()).unwrap();
+    });
+}
+
+#[test]
+fn fade_transition_snapshots_match_references()
+{
+    with_headless_home_page(|renderer, page_data, app_state, input_handler| {
+        app_state.change_current_page(page_data, TestPage::Next, &TestButton::FadeToNext);
+
+        // First half darkens the outgoing page, second half brightens the incoming one
+        let darkening_frame = capture_transition_frame(renderer, page_data, app_state, input_handler, 0.25).unwrap();
+        compare_with_reference(&darkening_frame, reference_path("fade_25.png"), &SnapshotConfig::default()).unwrap();
+        let brightening_frame = capture_transition_frame(renderer, page_data, app_state, input_handler, 0.75).unwrap();
+        compare_with_reference(&brightening_frame, reference_path("fade_75.png"), &SnapshotConfig::default()).unwrap();
+        assert_eq!(app_state.current_page, TestPage::Next);
+    });
+}
+
+#[test]
+fn slide_transition_snapshot_matches_reference()
+{
+    with_headless_home_page(|renderer, page_data, app_state, input_handler| {
+        app_state.change_current_page(page_data, TestPage::Next, &TestButton::SlideToNext);
+
+        let frame = capture_transition_frame(renderer, page_data, app_state, input_handler, 0.5).unwrap();
+        compare_with_reference(&frame, reference_path("slide_50.png"), &SnapshotConfig::default()).unwrap();
+        assert_eq!(app_state.current_page, TestPage::Next);
+    });
+}
@@ -0,0 +1,205 @@
+use rust_page_system::system::{
+    page_system::TextBox,
+    text_layout::{HorizontalAlign, TextLine, TextMeasurer, VerticalAlign, caret_stop_at, caret_stops, caret_x, horizontal_scroll, layout_text_box, line_of_char, scrolled_text_box, vertical_scroll}
+};
+use sdl3::{pixels::Color, rect::Rect};
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+/// Every character is `font_size / 2` pixels wide and lines are `font_size` pixels tall
+struct MonospaceMeasurer;
+impl TextMeasurer for MonospaceMeasurer
+{
+    fn text_size(&self, text: &str, font_size: f64) -> (u32, u32)
+    {
+        (text.chars().count() as u32 * (font_size / 2.) as u32, font_size as u32)
+    }
+
+    fn line_height(&self, font_size: f64) -> u32
+    {
+        font_size as u32
+    }
+}
+
+fn text_box(text: &str, horizontal_align: HorizontalAlign, vertical_align: VerticalAlign) -> TextBox
+{
+    TextBox { horizontal_align, vertical_align, ..TextBox::new(text, 20.0, Color::RGB(255, 255, 255), Rect::new(100, 100, 200, 100)) }
+}
+
+//
+// ==========================================================
+// Alignment tests
+// ==========================================================
+//
+
+#[test]
+fn text_box_aligns_line_horizontally()
+{
+    let left = layout_text_box(&MonospaceMeasurer, &text_box("abcd", HorizontalAlign::Left, VerticalAlign::Top));
+    let center = layout_text_box(&MonospaceMeasurer, &text_box("abcd", HorizontalAlign::Center, VerticalAlign::Top));
+    let right = layout_text_box(&MonospaceMeasurer, &text_box("abcd", HorizontalAlign::Right, VerticalAlign::Top));
+
+    assert_eq!(left[0].rect, Rect::new(100, 100, 40, 20));
+    assert_eq!(center[0].rect, Rect::new(180, 100, 40, 20));
+    assert_eq!(right[0].rect, Rect::new(260, 100, 40, 20));
+}
+
+#[test]
+fn text_box_aligns_block_of_lines_vertically()
+{
+    let middle = layout_text_box(&MonospaceMeasurer, &text_box("ab\ncd", HorizontalAlign::Left, VerticalAlign::Middle));
+    let bottom = layout_text_box(&MonospaceMeasurer, &text_box("ab\ncd", HorizontalAlign::Left, VerticalAlign::Bottom));
+
+    assert_eq!((middle[0].rect.y(), middle[1].rect.y()), (130, 150));
+    assert_eq!((bottom[0].rect.y(), bottom[1].rect.y()), (160, 180));
+}
+
+#[test]
+fn text_box_lines_keep_their_character_ranges()
+{
+    let lines = layout_text_box(&MonospaceMeasurer, &text_box("ab\n\nçde", HorizontalAlign::Center, VerticalAlign::Top));
+
+    assert_eq!(lines.iter().map(|line| line.char_range).collect::<Vec<_>>(), vec![(0, 2), (3, 3), (4, 7)]);
+    // Empty lines still take a full line height
+    assert_eq!(lines[1], TextLine { text: String::new(), rect: Rect::new(200, 120, 0, 20), char_range: (3, 3) });
+}
+
+//
+// ==========================================================
+// Caret tests
+// ==========================================================
+//
+
+#[test]
+fn caret_follows_alignment_and_lines()
+{
+    let lines = layout_text_box(&MonospaceMeasurer, &text_box("abcd\nef", HorizontalAlign::Right, VerticalAlign::Top));
+
+    assert_eq!(line_of_char(&lines, 2), 0);
+    assert_eq!(caret_x(&MonospaceMeasurer, &lines[0], 20.0, 2), 280);
+    // The end of a line belongs to that line, the next index starts the following one
+    assert_eq!(line_of_char(&lines, 4), 0);
+    assert_eq!(line_of_char(&lines, 5), 1);
+    assert_eq!(caret_x(&MonospaceMeasurer, &lines[1], 20.0, 5), 280);
+    assert_eq!(caret_x(&MonospaceMeasurer, &lines[1], 20.0, 99), 300);
+    assert_eq!(line_of_char(&lines, 99), 1);
+}
+
+#[test]
+fn caret_stops_map_points_to_the_closest_byte_index()
+{
+    // 'é' takes two bytes, so the stops of the first line are at bytes 0, 1 and 3
+    let stops = caret_stops(&MonospaceMeasurer, &text_box("aé\nb", HorizontalAlign::Left, VerticalAlign::Top));
+
+    assert_eq!(stops.iter().map(|stop| (stop.byte_index, stop.x, stop.line_rect.y())).collect::<Vec<_>>(), vec![(0, 100, 100), (1, 110, 100), (3, 120, 100), (4, 100, 120), (5, 110, 120)]);
+    assert_eq!(caret_stop_at(&stops, (113, 105)), Some(1));
+    assert_eq!(caret_stop_at(&stops, (116, 105)), Some(3));
+    assert_eq!(caret_stop_at(&stops, (104, 130)), Some(4));
+    // Points outside the text go to the closest line, then the closest caret on it
+    assert_eq!(caret_stop_at(&stops, (500, 500)), Some(5));
+    assert_eq!(caret_stop_at(&stops, (0, 0)), Some(0));
+    assert_eq!(caret_stop_at(&[], (0, 0)), None);
+}
+
+//
+// ==========================================================
+// Wrapping tests
+// ==========================================================
+//
+
+#[test]
+fn text_box_wraps_on_word_boundaries()
+{
+    // 10px per character, 8 characters per line
+    let lines = layout_text_box(&MonospaceMeasurer, &TextBox { max_width: Some(80), ..text_box("hello big world", HorizontalAlign::Left, VerticalAlign::Top) });
+
+    assert_eq!(lines.iter().map(|line| line.text.as_str()).collect::<Vec<_>>(), vec!["hello ", "big ", "world"]);
+    assert_eq!(lines.iter().map(|line| line.char_range).collect::<Vec<_>>(), vec![(0, 6), (6, 10), (10, 15)]);
+    // Trailing spaces don't count for the line width
+    assert_eq!(lines[0].rect, Rect::new(100, 100, 50, 20));
+    assert_eq!(lines[2].rect.y(), 140);
+}
+
+#[test]
+fn text_box_breaks_long_words_between_characters()
+{
+    let lines = layout_text_box(&MonospaceMeasurer, &TextBox { max_width: Some(40), ..text_box("abcdefghij\nxy", HorizontalAlign::Left, VerticalAlign::Top) });
+
+    assert_eq!(lines.iter().map(|line| line.text.as_str()).collect::<Vec<_>>(), vec!["abcd", "efgh", "ij", "xy"]);
+    assert_eq!(lines.iter().map(|line| line.char_range).collect::<Vec<_>>(), vec![(0, 4), (4, 8), (8, 10), (11, 13)]);
+}
+
+#[test]
+fn caret_follows_wrapped_lines()
+{
+    let lines = layout_text_box(&MonospaceMeasurer, &TextBox { max_width: Some(80), ..text_box("hello big world", HorizontalAlign::Left, VerticalAlign::Top) });
+
+    assert_eq!(line_of_char(&lines, 7), 1);
+    assert_eq!(caret_x(&MonospaceMeasurer, &lines[1], 20.0, 7), 110);
+    assert_eq!(line_of_char(&lines, 15), 2);
+    assert_eq!(caret_x(&MonospaceMeasurer, &lines[2], 20.0, 15), 150);
+}
+
+//
+// ==========================================================
+// Horizontal scroll tests
+// ==========================================================
+//
+
+#[test]
+fn single_line_input_scrolls_to_keep_caret_visible()
+{
+    // 30 characters (300px) in a 200px wide box, 198px are left for the text next to the caret
+    let input = text_box(&"a".repeat(30), HorizontalAlign::Left, VerticalAlign::Top);
+
+    assert_eq!(horizontal_scroll(&MonospaceMeasurer, &input, 5, 0), 0);
+    assert_eq!(horizontal_scroll(&MonospaceMeasurer, &input, 30, 0), 102);
+    // Moving the caret left keeps the scroll until the caret reaches the left edge
+    assert_eq!(horizontal_scroll(&MonospaceMeasurer, &input, 25, 102), 102);
+    assert_eq!(horizontal_scroll(&MonospaceMeasurer, &input, 8, 102), 80);
+    // Deleting text never leaves empty space after it
+    let shorter_input = text_box(&"a".repeat(25), HorizontalAlign::Left, VerticalAlign::Top);
+    assert_eq!(horizontal_scroll(&MonospaceMeasurer, &shorter_input, 25, 102), 52);
+}
+
+#[test]
+fn fitting_wrapped_or_multi_line_text_does_not_scroll()
+{
+    let long_text = "a".repeat(30);
+
+    assert_eq!(horizontal_scroll(&MonospaceMeasurer, &text_box("short", HorizontalAlign::Center, VerticalAlign::Top), 5, 40), 0);
+    assert_eq!(horizontal_scroll(&MonospaceMeasurer, &TextBox { max_width: Some(200), ..text_box(&long_text, HorizontalAlign::Left, VerticalAlign::Top) }, 30, 0), 0);
+    assert_eq!(horizontal_scroll(&MonospaceMeasurer, &text_box(&format!("{}\nb", long_text), HorizontalAlign::Left, VerticalAlign::Top), 30, 0), 0);
+}
+
+#[test]
+fn scrolled_text_box_lines_up_with_the_caret()
+{
+    let input = text_box(&"a".repeat(30), HorizontalAlign::Right, VerticalAlign::Top);
+    let scrolled = scrolled_text_box(&input, (102, 0));
+    let lines = layout_text_box(&MonospaceMeasurer, &scrolled);
+
+    assert_eq!(scrolled.clip_rect, Some(input.rect));
+    assert_eq!(lines[0].rect.x(), -2);
+    assert_eq!(caret_x(&MonospaceMeasurer, &lines[0], 20.0, 30), 298);
+}
+
+#[test]
+fn text_area_scrolls_vertically_to_the_caret_line()
+{
+    // 8 lines of 20px in a 100px tall box
+    let text_area = text_box("0\n1\n2\n3\n4\n5\n6\n7", HorizontalAlign::Left, VerticalAlign::Middle);
+
+    assert_eq!(vertical_scroll(&MonospaceMeasurer, &text_area, 0, 0), 0);
+    assert_eq!(vertical_scroll(&MonospaceMeasurer, &text_area, 14, 0), 60);
+    assert_eq!(vertical_scroll(&MonospaceMeasurer, &text_area, 6, 60), 60);
+    assert_eq!(vertical_scroll(&MonospaceMeasurer, &text_area, 2, 60), 20);
+
+    // Overflowing text starts at the top whatever the alignment, scrolled text moves up
+    let lines = layout_text_box(&MonospaceMeasurer, &scrolled_text_box(&text_area, (0, 60)));
+    assert_eq!(lines[3].rect.y(), 100);
+}
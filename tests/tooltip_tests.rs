@@ -0,0 +1,127 @@
+use rust_page_system::system::{
+    clock::ManualClock,
+    input_handler::{InputEvent, InputHandler},
+    input_source::{MemoryClipboard, ScriptedInput, ScriptedStep},
+    page_system::{Button, Page, PageData},
+    renderer::tooltip_rect,
+    state::AppState,
+    window::WINDOW_DEFAULT_SCALE
+};
+use sdl3::{pixels::Color, rect::Rect};
+use std::{rc::Rc, time::Duration};
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    Home
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestButton
+{
+    Save,
+    Load
+}
+
+const SAVE_BUTTON_CENTER: (f32, f32) = (150., 125.);
+const LOAD_BUTTON_CENTER: (f32, f32) = (350., 125.);
+const TOOLTIP_DELAY: Duration = Duration::from_millis(600);
+
+fn home_page() -> Page<TestPage, TestButton>
+{
+    let save_button = Button::new(TestButton::Save, Color::RGB(203, 166, 247), Rect::new(100, 100, 100, 50)).with_tooltip("Save the file");
+    let load_button = Button::new(TestButton::Load, Color::RGB(137, 180, 250), Rect::new(300, 100, 100, 50)).with_tooltip("Load a file");
+    Page { buttons: Some(vec![save_button, load_button]), ..Page::new(TestPage::Home) }
+}
+
+fn create_linked_state() -> (AppState<TestPage, TestButton>, PageData<TestPage, TestButton>)
+{
+    let app_state = AppState::new(TestPage::Home, (1920, 1080), true);
+    let mut page_data = PageData::new(&app_state);
+    page_data.populate_rps_data(Some(vec![Rc::new(home_page)]), None);
+    (app_state, page_data)
+}
+
+fn play(input_handler: &mut InputHandler<TestPage, TestButton>, app_state: &mut AppState<TestPage, TestButton>, page_data: &mut PageData<TestPage, TestButton>, mouse_position: (f32, f32), event: InputEvent)
+{
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, _: &TestButton, _: &mut PageData<TestPage, TestButton>| {};
+    input_handler.play_script(&mut ScriptedInput::new(vec![ScriptedStep::new(0, mouse_position, event)]), &mut MemoryClipboard::default(), page_data, app_state, &mut button_action);
+}
+
+//
+// ==========================================================
+// Tooltip placement
+// ==========================================================
+//
+
+#[test]
+fn tooltip_goes_below_and_right_of_the_cursor()
+{
+    assert_eq!(tooltip_rect((100, 200), (150, 40)), Rect::new(116, 220, 150, 40));
+}
+
+#[test]
+fn tooltip_flips_to_the_other_side_near_the_canvas_edges()
+{
+    let (canvas_width, canvas_height) = (WINDOW_DEFAULT_SCALE.0 as i32, WINDOW_DEFAULT_SCALE.1 as i32);
+
+    assert_eq!(tooltip_rect((canvas_width - 10, canvas_height - 10), (150, 40)), Rect::new(canvas_width - 160, canvas_height - 50, 150, 40));
+    // Too wide to fit on either side, it is pushed back inside the canvas
+    assert_eq!(tooltip_rect((50, 10), (1900, 40)), Rect::new(0, 30, 1900, 40));
+    assert_eq!(tooltip_rect((0, 0), (5000, 40)).width(), WINDOW_DEFAULT_SCALE.0);
+}
+
+//
+// ==========================================================
+// Tooltip delay
+// ==========================================================
+//
+
+#[test]
+fn tooltip_shows_after_the_delay_and_restarts_on_another_button()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let clock = ManualClock::default();
+    input_handler.set_clock(clock.clone());
+
+    play(&mut input_handler, &mut app_state, &mut page_data, SAVE_BUTTON_CENTER, InputEvent::None);
+    clock.advance(TOOLTIP_DELAY - Duration::from_millis(1));
+    assert_eq!(input_handler.tooltip_button(TOOLTIP_DELAY), None);
+    clock.advance(Duration::from_millis(1));
+    assert_eq!(input_handler.tooltip_button(TOOLTIP_DELAY), Some(TestButton::Save));
+
+    // Moving to another button starts the wait again
+    play(&mut input_handler, &mut app_state, &mut page_data, LOAD_BUTTON_CENTER, InputEvent::None);
+    assert_eq!(input_handler.tooltip_button(TOOLTIP_DELAY), None);
+    clock.advance(TOOLTIP_DELAY);
+    assert_eq!(input_handler.tooltip_button(TOOLTIP_DELAY), Some(TestButton::Load));
+
+    play(&mut input_handler, &mut app_state, &mut page_data, (1000., 1000.), InputEvent::None);
+    assert_eq!(input_handler.tooltip_button(TOOLTIP_DELAY), None);
+}
+
+#[test]
+fn tooltip_hides_while_pressed_and_waits_again_after_the_release()
+{
+    let (mut app_state, mut page_data) = create_linked_state();
+    let mut input_handler = InputHandler::new(false);
+    let clock = ManualClock::default();
+    input_handler.set_clock(clock.clone());
+
+    play(&mut input_handler, &mut app_state, &mut page_data, SAVE_BUTTON_CENTER, InputEvent::None);
+    clock.advance(TOOLTIP_DELAY);
+    play(&mut input_handler, &mut app_state, &mut page_data, SAVE_BUTTON_CENTER, InputEvent::Click);
+    assert_eq!(input_handler.tooltip_button(TOOLTIP_DELAY), None);
+
+    play(&mut input_handler, &mut app_state, &mut page_data, SAVE_BUTTON_CENTER, InputEvent::MouseUp);
+    assert_eq!(input_handler.tooltip_button(TOOLTIP_DELAY), None);
+    clock.advance(TOOLTIP_DELAY);
+    assert_eq!(input_handler.tooltip_button(TOOLTIP_DELAY), Some(TestButton::Save));
+}
@@ -0,0 +1,177 @@
+use rust_page_system::system::{
+    input_handler::{InputEvent, InputHandler},
+    input_source::{MemoryClipboard, ScriptedInput, ScriptedStep},
+    page_system::{Button, Page, PageData},
+    state::AppState,
+    widgets::{Widget, WidgetKind, WidgetValue}
+};
+use sdl3::{pixels::Color, rect::Rect};
+use std::{cell::RefCell, rc::Rc};
+
+//
+// ==========================================================
+// Type scaffolding for tests
+// ==========================================================
+//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestPage
+{
+    Settings
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestButton
+{
+    Sound,
+    Fullscreen,
+    Easy,
+    Hard,
+    Volume,
+    Loading
+}
+
+const ACCENT: Color = Color::RGBA(0, 120, 255, 255);
+
+fn widget(kind: WidgetKind) -> Widget
+{
+    Widget { kind, accent_color: ACCENT }
+}
+
+fn button(id: TestButton, rect: Rect) -> Button<TestButton>
+{
+    Button::new(id, Color::RGB(60, 60, 60), rect)
+}
+
+/// Volume is a slider over x 100..300 going from 0 to 100 in steps of 10
+fn settings_page() -> Page<TestPage, TestButton>
+{
+    let buttons = vec![button(TestButton::Sound, Rect::new(100, 100, 30, 30)), button(TestButton::Fullscreen, Rect::new(100, 150, 60, 30)), button(TestButton::Easy, Rect::new(100, 200, 30, 30)), button(TestButton::Hard, Rect::new(150, 200, 30, 30)), button(TestButton::Volume, Rect::new(100, 250, 200, 20)), button(TestButton::Loading, Rect::new(100, 300, 200, 20))];
+    let has_widgets = vec![(TestPage::Settings, TestButton::Sound, widget(WidgetKind::Checkbox)), (TestPage::Settings, TestButton::Fullscreen, widget(WidgetKind::Toggle)), (TestPage::Settings, TestButton::Easy, widget(WidgetKind::Radio(0))), (TestPage::Settings, TestButton::Hard, widget(WidgetKind::Radio(0))), (TestPage::Settings, TestButton::Volume, widget(WidgetKind::Slider { min: 0., max: 100., step: 10. })), (TestPage::Settings, TestButton::Loading, widget(WidgetKind::ProgressBar))];
+    Page { has_widgets: Some(has_widgets), buttons: Some(buttons), ..Page::new(TestPage::Settings) }
+}
+
+fn settings_page_data() -> (AppState<TestPage, TestButton>, PageData<TestPage, TestButton>)
+{
+    let mut app_state = AppState::new(TestPage::Settings, (1920, 1080), true);
+    let mut page_data = PageData::new(&app_state);
+    page_data.populate_rps_data(Some(vec![Rc::new(settings_page)]), None);
+    page_data.create_current_page(&mut app_state);
+    (app_state, page_data)
+}
+
+fn at(mouse_position: (f32, f32), input_event: InputEvent) -> ScriptedStep
+{
+    ScriptedStep::new(0, mouse_position, input_event)
+}
+
+fn value(page_data: &PageData<TestPage, TestButton>, button_id: TestButton) -> WidgetValue
+{
+    page_data.widget_value(TestPage::Settings, button_id).unwrap()
+}
+
+//
+// ==========================================================
+// Widget values
+// ==========================================================
+//
+
+#[test]
+fn slider_values_are_clamped_snapped_and_nudged()
+{
+    let slider = widget(WidgetKind::Slider { min: 0., max: 100., step: 10. });
+
+    assert_eq!(slider.default_value(), WidgetValue::Number(0.));
+    assert_eq!(slider.normalize(WidgetValue::Number(44.)), WidgetValue::Number(40.));
+    assert_eq!(slider.normalize(WidgetValue::Number(250.)), WidgetValue::Number(100.));
+    assert_eq!(slider.value_at(Rect::new(100, 0, 200, 20), 175.), WidgetValue::Number(40.));
+    assert_eq!(slider.value_at(Rect::new(100, 0, 200, 20), 20.), WidgetValue::Number(0.));
+    assert_eq!(slider.nudged(WidgetValue::Number(100.), true), WidgetValue::Number(100.));
+    assert_eq!(slider.nudged(WidgetValue::Number(40.), false), WidgetValue::Number(30.));
+    assert_eq!(slider.fraction(WidgetValue::Number(30.)), 0.3);
+
+    // Continuous sliders move by 1% of their range
+    let continuous_slider = widget(WidgetKind::Slider { min: 0., max: 2., step: 0. });
+    assert_eq!(continuous_slider.nudged(WidgetValue::Number(1.), true), WidgetValue::Number(1.02));
+    assert_eq!(widget(WidgetKind::ProgressBar).normalize(WidgetValue::Number(1.5)), WidgetValue::Number(1.));
+}
+
+#[test]
+fn marks_show_the_check_knob_and_fill_of_the_value()
+{
+    let rect = Rect::new(100, 100, 40, 40);
+    assert_eq!(widget(WidgetKind::Checkbox).marks(rect, 0, WidgetValue::Checked(false)), Vec::new());
+    assert_eq!(widget(WidgetKind::Checkbox).marks(rect, 0, WidgetValue::Checked(true)), vec![(Rect::new(110, 110, 20, 20), 5, ACCENT)]);
+    assert_eq!(widget(WidgetKind::Radio(0)).marks(rect, 20, WidgetValue::Checked(true)), vec![(Rect::new(110, 110, 20, 20), 10, ACCENT)]);
+
+    let toggle_rect = Rect::new(100, 100, 80, 40);
+    assert_eq!(widget(WidgetKind::Toggle).marks(toggle_rect, 20, WidgetValue::Checked(false)), vec![(Rect::new(105, 105, 30, 30), 15, Color::RGBA(0, 120, 255, 85))]);
+    assert_eq!(widget(WidgetKind::Toggle).marks(toggle_rect, 20, WidgetValue::Checked(true)), vec![(Rect::new(145, 105, 30, 30), 15, ACCENT)]);
+
+    let bar_rect = Rect::new(100, 100, 200, 20);
+    assert_eq!(widget(WidgetKind::ProgressBar).marks(bar_rect, 4, WidgetValue::Number(0.25)), vec![(Rect::new(100, 100, 50, 20), 4, ACCENT)]);
+    assert_eq!(widget(WidgetKind::ProgressBar).marks(bar_rect, 4, WidgetValue::Number(0.)), Vec::new());
+    // The knob stays inside the track at either end
+    let slider = widget(WidgetKind::Slider { min: 0., max: 100., step: 0. });
+    assert_eq!(slider.marks(bar_rect, 0, WidgetValue::Number(0.)), vec![(Rect::new(100, 100, 10, 20), 0, ACCENT)]);
+    assert_eq!(slider.marks(bar_rect, 0, WidgetValue::Number(100.)).last(), Some(&(Rect::new(290, 100, 10, 20), 0, ACCENT)));
+}
+
+#[test]
+fn widgets_get_their_default_value_and_radios_exclude_each_other()
+{
+    let (_, mut page_data) = settings_page_data();
+    assert_eq!(value(&page_data, TestButton::Sound), WidgetValue::Checked(false));
+    assert_eq!(value(&page_data, TestButton::Volume), WidgetValue::Number(0.));
+
+    assert_eq!(page_data.set_widget_value(TestPage::Settings, TestButton::Easy, WidgetValue::Checked(true)), vec![(TestButton::Easy, WidgetValue::Checked(true))]);
+    // The radio unchecked by the other is reported too
+    assert_eq!(page_data.set_widget_value(TestPage::Settings, TestButton::Hard, WidgetValue::Checked(true)), vec![(TestButton::Hard, WidgetValue::Checked(true)), (TestButton::Easy, WidgetValue::Checked(false))]);
+    assert_eq!((value(&page_data, TestButton::Easy), value(&page_data, TestButton::Hard)), (WidgetValue::Checked(false), WidgetValue::Checked(true)));
+    // Unchanged and unregistered values report no change
+    assert_eq!(page_data.set_widget_value(TestPage::Settings, TestButton::Hard, WidgetValue::Checked(true)), Vec::new());
+    assert_eq!(page_data.set_widget_value(TestPage::Settings, TestButton::Volume, WidgetValue::Number(2.)), Vec::new());
+    page_data.widgets.clear();
+    assert_eq!(page_data.set_widget_value(TestPage::Settings, TestButton::Sound, WidgetValue::Checked(true)), Vec::new());
+
+    // Registering the page again keeps the values
+    let mut page = settings_page();
+    page_data.push_vec_user_input_per_page(&mut page);
+    assert_eq!(value(&page_data, TestButton::Hard), WidgetValue::Checked(true));
+}
+
+//
+// ==========================================================
+// Mouse and keyboard interaction
+// ==========================================================
+//
+
+#[test]
+fn clicks_and_keys_change_widgets_and_run_the_callback_instead_of_the_button_action()
+{
+    let (mut app_state, mut page_data) = settings_page_data();
+    let mut input_handler = InputHandler::new(false);
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let recorded_changes = changes.clone();
+    input_handler.on_widget_change(move |_, button_id, value, _| recorded_changes.borrow_mut().push((*button_id, value)));
+    let mut clipboard = MemoryClipboard::default();
+    let mut button_action = |_: &mut AppState<TestPage, TestButton>, button_id: &TestButton, _: &mut PageData<TestPage, TestButton>| panic!("button_action ran for {:?}", button_id);
+    let mut play = |input_handler: &mut InputHandler<TestPage, TestButton>, page_data: &mut PageData<TestPage, TestButton>, steps: Vec<ScriptedStep>| input_handler.play_script(&mut ScriptedInput::new(steps), &mut clipboard, page_data, &mut app_state, &mut button_action);
+
+    // Checking the checked radio again changes nothing, the progress bar ignores clicks and the unchecked radio gets the callback too
+    let clicks = [(110., 110.), (110., 160.), (160., 210.), (160., 210.), (110., 310.), (110., 210.)].into_iter().flat_map(|position| [at(position, InputEvent::Click), at(position, InputEvent::MouseUp)]).collect();
+    play(&mut input_handler, &mut page_data, clicks);
+    assert_eq!(*changes.borrow(), vec![(TestButton::Sound, WidgetValue::Checked(true)), (TestButton::Fullscreen, WidgetValue::Checked(true)), (TestButton::Hard, WidgetValue::Checked(true)), (TestButton::Easy, WidgetValue::Checked(true)), (TestButton::Hard, WidgetValue::Checked(false))]);
+
+    // The slider jumps to the mouse, follows it while dragged and then stays put
+    changes.borrow_mut().clear();
+    play(&mut input_handler, &mut page_data, vec![at((150., 255.), InputEvent::Click), at((500., 500.), InputEvent::Drag), at((500., 500.), InputEvent::MouseUp), at((100., 255.), InputEvent::Drag)]);
+    assert_eq!(*changes.borrow(), vec![(TestButton::Volume, WidgetValue::Number(30.)), (TestButton::Volume, WidgetValue::Number(100.))]);
+
+    // Left/Right move the focused slider, Space toggles the focused checkbox
+    play(&mut input_handler, &mut page_data, vec![at((0., 0.), InputEvent::CursorLeft(false))]);
+    assert_eq!(value(&page_data, TestButton::Volume), WidgetValue::Number(90.));
+    input_handler.focused_button = Some(TestButton::Sound);
+    play(&mut input_handler, &mut page_data, vec![at((0., 0.), InputEvent::CursorRight(false)), at((0., 0.), InputEvent::Activate)]);
+    assert_eq!((value(&page_data, TestButton::Sound), value(&page_data, TestButton::Volume)), (WidgetValue::Checked(false), WidgetValue::Number(90.)));
+}